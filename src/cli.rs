@@ -1,6 +1,7 @@
 //! Command-line interface for strictly_games.
 
 use clap::{Parser, Subcommand};
+use strictly_games::{Difficulty, GameKind};
 
 /// Strictly Games - Type-safe game server with MCP interface
 #[derive(Parser, Debug)]
@@ -28,6 +29,18 @@ pub enum Command {
         /// Host to bind to
         #[arg(long, default_value = "127.0.0.1")]
         host: String,
+
+        /// Directory to keep sessions in across restarts (kept in memory only if not set)
+        #[arg(long)]
+        data_dir: Option<std::path::PathBuf>,
+
+        /// Seconds without activity after which a session is closed as finished or abandoned
+        #[arg(long, default_value = "1800")]
+        idle_timeout_secs: u64,
+
+        /// Seconds a closed session stays listed before it is removed
+        #[arg(long, default_value = "300")]
+        retention_secs: u64,
     },
     
     /// Run the terminal UI client
@@ -43,6 +56,18 @@ pub enum Command {
         /// Path to agent config for standalone mode
         #[arg(long, default_value = "agent_config.toml")]
         agent_config: std::path::PathBuf,
+        
+        /// Game to play (tic-tac-toe, connect-four or gomoku)
+        #[arg(long, default_value_t = GameKind::default())]
+        game: GameKind,
+        
+        /// Play against a built-in bot (random, greedy, lookahead or perfect) instead of the agent
+        #[arg(long)]
+        bot: Option<Difficulty>,
+
+        /// Watch this session on the server instead of playing (needs --server-url)
+        #[arg(long, requires = "server_url", conflicts_with = "bot")]
+        spectate: Option<String>,
     },
     
     /// Run an MCP agent that plays games
@@ -63,8 +88,19 @@ pub enum Command {
         #[arg(long)]
         test_play: bool,
         
-        /// Session ID for test mode play_game (optional, auto-generates if not provided)
+        /// Session ID for test mode play_game (optional; without one, each game is paired through the matchmaking queue)
         #[arg(long)]
         test_session: Option<String>,
+        
+        /// Game to request in test mode play_game (tic-tac-toe, connect-four or gomoku)
+        #[arg(long, default_value_t = GameKind::default())]
+        test_game: GameKind,
+    },
+    
+    /// Regenerate the tic-tac-toe tablebase shipped with the crate
+    Tablebase {
+        /// File to write the tablebase to
+        #[arg(short, long, default_value = "data/tictactoe.tablebase")]
+        output: std::path::PathBuf,
     },
 }
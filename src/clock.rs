@@ -0,0 +1,274 @@
+//! Per-player game clocks.
+//!
+//! A session's [`GameClock`] charges each player for the time they spend
+//! on their turns, under the [`TimeControl`] chosen when the session was
+//! created. Time is read from an injectable [`Clock`], so tests can drive
+//! a [`ManualClock`] instead of waiting on the wall clock.
+
+use crate::games::Mark;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{debug, instrument};
+
+/// Source of time for game clocks.
+pub trait Clock: Debug + Send + Sync {
+    /// Time elapsed since a fixed, arbitrary starting point.
+    fn now(&self) -> Duration;
+}
+
+/// Wall-clock time, measured from the Unix epoch.
+///
+/// Ticks with a monotonic timer anchored to the system time at creation,
+/// so it never runs backwards within a process, and times logged before a
+/// restart stay comparable with times read after it.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    start: Instant,
+    /// Time since the Unix epoch when the clock was created.
+    epoch_offset: Duration,
+}
+
+impl SystemClock {
+    /// Creates a clock reading the current time.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            epoch_offset: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.epoch_offset + self.start.elapsed()
+    }
+}
+
+/// Clock that only moves when told to, for deterministic tests.
+///
+/// Clones share the same time, so a test can keep one handle and give
+/// another to the session under test.
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+}
+
+/// How much thinking time each player gets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case", tag = "kind")]
+pub enum TimeControl {
+    /// No time limit.
+    #[default]
+    Unlimited,
+    /// A bank of time for the whole game, topped up after every move.
+    Increment {
+        /// Starting time per player, in seconds.
+        initial_secs: u64,
+        /// Time added after each of the player's moves, in seconds.
+        increment_secs: u64,
+    },
+    /// A fixed limit per move; unused time is not carried over.
+    PerMove {
+        /// Time allowed for each move, in seconds.
+        secs: u64,
+    },
+}
+
+impl TimeControl {
+    /// Time each player starts with, or `None` if unlimited.
+    pub fn budget(&self) -> Option<Duration> {
+        match self {
+            TimeControl::Unlimited => None,
+            TimeControl::Increment { initial_secs, .. } => Some(Duration::from_secs(*initial_secs)),
+            TimeControl::PerMove { secs } => Some(Duration::from_secs(*secs)),
+        }
+    }
+}
+
+impl std::fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeControl::Unlimited => write!(f, "unlimited"),
+            TimeControl::Increment {
+                initial_secs,
+                increment_secs,
+            } => write!(f, "{}s + {}s per move", initial_secs, increment_secs),
+            TimeControl::PerMove { secs } => write!(f, "{}s per move", secs),
+        }
+    }
+}
+
+/// Both players' clocks for one game.
+///
+/// At most one clock runs at a time: the one of the player to move.
+#[derive(Debug, Clone)]
+pub struct GameClock {
+    control: TimeControl,
+    source: Arc<dyn Clock>,
+    /// Banked time of X and O, not counting the running turn.
+    banked: [Duration; 2],
+    /// Player whose clock is running, and when their turn started.
+    running: Option<(Mark, Duration)>,
+}
+
+impl GameClock {
+    /// Creates stopped clocks with a full budget for both players.
+    pub fn new(control: TimeControl, source: Arc<dyn Clock>) -> Self {
+        let budget = control.budget().unwrap_or_default();
+        Self {
+            control,
+            source,
+            banked: [budget; 2],
+            running: None,
+        }
+    }
+
+    /// Creates clocks without a time limit.
+    pub fn unlimited() -> Self {
+        Self::new(TimeControl::Unlimited, Arc::new(SystemClock::new()))
+    }
+
+    /// Returns the time control.
+    pub fn control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Returns the time source the clocks read.
+    pub fn source(&self) -> Arc<dyn Clock> {
+        self.source.clone()
+    }
+
+    /// Current time on the clocks' time source.
+    pub fn now(&self) -> Duration {
+        self.source.now()
+    }
+
+    /// Returns the player whose clock is running, if any.
+    pub fn running(&self) -> Option<Mark> {
+        self.running.map(|(mark, _)| mark)
+    }
+
+    /// Time the player has left, or `None` if unlimited.
+    ///
+    /// Counts down while the player's clock runs.
+    pub fn remaining(&self, mark: Mark) -> Option<Duration> {
+        self.control.budget()?;
+        let banked = self.banked[Self::index(mark)];
+        Some(match self.running {
+            Some((running, started)) if running == mark => {
+                banked.saturating_sub(self.source.now().saturating_sub(started))
+            }
+            _ => banked,
+        })
+    }
+
+    /// Returns the player to move if they have run out of time.
+    pub fn flagged(&self) -> Option<Mark> {
+        let mark = self.running()?;
+        (self.remaining(mark)? == Duration::ZERO).then_some(mark)
+    }
+
+    /// Starts the player's turn, stopping any other running clock.
+    ///
+    /// Time spent by the previous player is not charged; use
+    /// [`press`](Self::press) at the end of a move.
+    #[instrument(skip(self))]
+    pub fn start(&mut self, mark: Mark) {
+        self.start_at(mark, self.source.now());
+    }
+
+    /// Starts the player's turn as of the given time on the time source.
+    pub fn start_at(&mut self, mark: Mark, at: Duration) {
+        self.running = Some((mark, at));
+    }
+
+    /// Ends the player's move and starts the opponent's clock.
+    ///
+    /// Charges the player for their turn, then applies the time control:
+    /// an increment is added, or a per-move allowance is renewed.
+    #[instrument(skip(self))]
+    pub fn press(&mut self, mark: Mark) {
+        self.press_at(mark, self.source.now());
+    }
+
+    /// Ends the player's move as of the given time on the time source.
+    ///
+    /// Used to replay logged moves with the time they were made.
+    pub fn press_at(&mut self, mark: Mark, at: Duration) {
+        let used = match self.running {
+            Some((running, started)) if running == mark => at.saturating_sub(started),
+            _ => Duration::ZERO,
+        };
+
+        let banked = &mut self.banked[Self::index(mark)];
+        *banked = match self.control {
+            TimeControl::Unlimited => *banked,
+            TimeControl::Increment { increment_secs, .. } => {
+                banked.saturating_sub(used) + Duration::from_secs(increment_secs)
+            }
+            TimeControl::PerMove { secs } => Duration::from_secs(secs),
+        };
+
+        debug!(mark = ?mark, used_ms = used.as_millis() as u64, "Clock pressed");
+        self.start_at(mark.opponent(), at);
+    }
+
+    /// Stops both clocks, e.g. when the game ends.
+    pub fn stop(&mut self) {
+        self.running = None;
+    }
+
+    /// Stops both clocks and restores the full budget.
+    pub fn reset(&mut self) {
+        let budget = self.control.budget().unwrap_or_default();
+        self.banked = [budget; 2];
+        self.running = None;
+    }
+
+    /// Describes both clocks, e.g. `X 4:32 | O 5:00`, or `None` if unlimited.
+    pub fn summary(&self) -> Option<String> {
+        let x = self.remaining(Mark::X)?;
+        let o = self.remaining(Mark::O)?;
+        Some(format!("X {} | O {}", format_duration(x), format_duration(o)))
+    }
+
+    fn index(mark: Mark) -> usize {
+        match mark {
+            Mark::X => 0,
+            Mark::O => 1,
+        }
+    }
+}
+
+/// Formats a duration as `m:ss`.
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
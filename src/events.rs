@@ -0,0 +1,238 @@
+//! Append-only event log behind every game session.
+//!
+//! A [`GameSession`] never changes except by appending a
+//! [`SessionEvent`] to its [`EventLog`] and folding it into the current
+//! state. Replaying the log therefore rebuilds the session, or any state
+//! it passed through; snapshots taken every [`SNAPSHOT_EVERY`] events keep
+//! rebuilding a late state from folding the whole log.
+
+use crate::clock::TimeControl;
+use crate::games::analysis::Verdict;
+use crate::games::{Game, Mark};
+use crate::series::MatchFormat;
+use crate::session::{GameSession, Player, Spectator};
+use crate::turn_order::TurnOrder;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Number of events between two snapshots of the session state.
+pub const SNAPSHOT_EVERY: u64 = 32;
+
+/// Something that happened in a game session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", bound = "")]
+pub enum SessionEvent<G: Game> {
+    /// The session was created.
+    Created {
+        /// Time control of the session's games.
+        time_control: TimeControl,
+        /// Who opens each game, and whether seats swap between games.
+        turn_order: TurnOrder,
+    },
+    /// A player took a seat.
+    PlayerRegistered {
+        /// The player, with the mark they were given.
+        player: Player,
+    },
+    /// A player placed a piece.
+    MoveApplied {
+        /// Player who moved.
+        mark: Mark,
+        /// What they played.
+        choice: G::Choice,
+    },
+    /// A player resigned the game.
+    Resigned {
+        /// Player who resigned.
+        mark: Mark,
+        /// How the position stood for them.
+        verdict: Verdict,
+    },
+    /// A player offered a draw.
+    DrawOffered {
+        /// Player who offered.
+        mark: Mark,
+    },
+    /// A player answered the opponent's draw offer.
+    DrawAnswered {
+        /// Player who answered.
+        mark: Mark,
+        /// Whether they agreed to the draw.
+        accept: bool,
+    },
+    /// A player asked to take back their last move.
+    UndoRequested {
+        /// Player who asked.
+        mark: Mark,
+    },
+    /// A player answered the opponent's undo request.
+    UndoAnswered {
+        /// Player who answered.
+        mark: Mark,
+        /// Whether they allowed the take-back.
+        accept: bool,
+    },
+    /// A player ran out of time.
+    TimedOut {
+        /// Player whose clock ran out.
+        mark: Mark,
+    },
+    /// A match started.
+    MatchStarted {
+        /// How long the match lasts.
+        format: MatchFormat,
+    },
+    /// The next game started on a fresh board.
+    Restarted,
+    /// Both seats were emptied.
+    PlayersCleared,
+    /// Someone started watching the session.
+    SpectatorJoined {
+        /// The spectator.
+        spectator: Spectator,
+    },
+    /// A spectator stopped watching.
+    SpectatorLeft {
+        /// ID of the spectator.
+        id: String,
+    },
+    /// The session was closed; nothing more happens in it.
+    Closed,
+    /// Nobody acted in the session before its idle timeout.
+    Abandoned,
+}
+
+impl<G: Game> std::fmt::Display for SessionEvent<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionEvent::Created { time_control, turn_order } => {
+                write!(f, "Session created ({}, {})", time_control, turn_order)
+            }
+            SessionEvent::PlayerRegistered { player } => {
+                write!(f, "{} joined as {:?}", player.name, player.mark)
+            }
+            SessionEvent::MoveApplied { mark, choice } => write!(f, "{:?} played {}", mark, choice),
+            SessionEvent::Resigned { mark, .. } => write!(f, "{:?} resigned", mark),
+            SessionEvent::DrawOffered { mark } => write!(f, "{:?} offered a draw", mark),
+            SessionEvent::DrawAnswered { mark, accept: true } => {
+                write!(f, "{:?} accepted the draw", mark)
+            }
+            SessionEvent::DrawAnswered { mark, accept: false } => {
+                write!(f, "{:?} declined the draw", mark)
+            }
+            SessionEvent::UndoRequested { mark } => write!(f, "{:?} asked to undo", mark),
+            SessionEvent::UndoAnswered { mark, accept: true } => {
+                write!(f, "{:?} allowed the undo", mark)
+            }
+            SessionEvent::UndoAnswered { mark, accept: false } => {
+                write!(f, "{:?} refused the undo", mark)
+            }
+            SessionEvent::TimedOut { mark } => write!(f, "{:?} ran out of time", mark),
+            SessionEvent::MatchStarted { format } => write!(f, "Match started ({})", format),
+            SessionEvent::Restarted => write!(f, "Game restarted"),
+            SessionEvent::PlayersCleared => write!(f, "Players cleared"),
+            SessionEvent::SpectatorJoined { spectator } => {
+                write!(f, "{} started watching", spectator.name)
+            }
+            SessionEvent::SpectatorLeft { id } => write!(f, "{} stopped watching", id),
+            SessionEvent::Closed => write!(f, "Session closed"),
+            SessionEvent::Abandoned => write!(f, "Session abandoned"),
+        }
+    }
+}
+
+/// An event with its place in the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct LoggedEvent<G: Game> {
+    /// Position in the log, starting at 0.
+    pub seq: u64,
+    /// Time of the event on the session's clock source.
+    pub at: Duration,
+    /// What happened.
+    #[serde(flatten)]
+    pub event: SessionEvent<G>,
+}
+
+impl<G: Game> LoggedEvent<G> {
+    /// Returns the event with player and spectator tokens removed, for
+    /// showing to others.
+    pub fn redacted(&self) -> Self {
+        let mut redacted = self.clone();
+        match &mut redacted.event {
+            SessionEvent::PlayerRegistered { player } => player.token = None,
+            SessionEvent::SpectatorJoined { spectator } => spectator.token = None,
+            _ => {}
+        }
+        redacted
+    }
+}
+
+/// Append-only log of a session's events, with periodic snapshots.
+#[derive(Debug, Clone)]
+pub struct EventLog<G: Game> {
+    events: Vec<LoggedEvent<G>>,
+    /// Session state right after the event with the given sequence number.
+    snapshots: Vec<(u64, GameSession<G>)>,
+}
+
+impl<G: Game> EventLog<G> {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            snapshots: Vec::new(),
+        }
+    }
+
+    /// Returns every event, oldest first.
+    pub fn events(&self) -> &[LoggedEvent<G>] {
+        &self.events
+    }
+
+    /// Returns the number of events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if nothing has happened yet.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Appends an event and returns its sequence number.
+    pub(crate) fn append(&mut self, at: Duration, event: SessionEvent<G>) -> u64 {
+        let seq = self.events.len() as u64;
+        self.events.push(LoggedEvent { seq, at, event });
+        seq
+    }
+
+    /// Returns the log up to and including the given event.
+    pub(crate) fn truncated(&self, seq: u64) -> Self {
+        Self {
+            events: self.events.iter().take_while(|e| e.seq <= seq).cloned().collect(),
+            snapshots: self.snapshots.iter().filter(|(at, _)| *at <= seq).cloned().collect(),
+        }
+    }
+
+    /// Returns true if a snapshot is due after the given event.
+    pub(crate) fn snapshot_due(seq: u64) -> bool {
+        (seq + 1) % SNAPSHOT_EVERY == 0
+    }
+
+    /// Stores the session state as of the given event.
+    pub(crate) fn store_snapshot(&mut self, seq: u64, state: GameSession<G>) {
+        self.snapshots.push((seq, state));
+    }
+
+    /// Returns the latest snapshot taken at or before the given event.
+    pub(crate) fn snapshot_before(&self, seq: u64) -> Option<&(u64, GameSession<G>)> {
+        self.snapshots.iter().rev().find(|(at, _)| *at <= seq)
+    }
+}
+
+impl<G: Game> Default for EventLog<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
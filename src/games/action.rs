@@ -0,0 +1,56 @@
+//! Game-agnostic domain actions.
+//!
+//! Placing a piece is game-specific (tic-tac-toe's `Move`, a Connect Four
+//! drop, ...). Resigning and agreeing a draw work the same in every game,
+//! so [`Action`] wraps the game's placement together with them.
+
+use super::Player;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A first-class domain action in a game whose placements are `M`.
+///
+/// Every action names the player taking it, so its contracts can check
+/// it against the game state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action<M> {
+    /// Place a piece.
+    Place(M),
+    /// The player concedes the game.
+    Resign(Player),
+    /// The player offers a draw.
+    OfferDraw(Player),
+    /// The player accepts the opponent's draw offer.
+    AcceptDraw(Player),
+    /// The player declines the opponent's draw offer.
+    DeclineDraw(Player),
+    /// The player's clock ran out.
+    TimeOut(Player),
+}
+
+impl<M: fmt::Display> fmt::Display for Action<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Place(mov) => write!(f, "{}", mov),
+            Action::Resign(player) => write!(f, "{:?} resigns", player),
+            Action::OfferDraw(player) => write!(f, "{:?} offers a draw", player),
+            Action::AcceptDraw(player) => write!(f, "{:?} accepts the draw", player),
+            Action::DeclineDraw(player) => write!(f, "{:?} declines the draw", player),
+            Action::TimeOut(player) => write!(f, "{:?} runs out of time", player),
+        }
+    }
+}
+
+/// Error that can occur when offering or answering a draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum DrawError {
+    /// A draw offer is already waiting for an answer.
+    #[display("Player {:?} has already offered a draw", _0)]
+    AlreadyOffered(Player),
+
+    /// The opponent has not offered a draw.
+    #[display("Player {:?} has no draw offer to answer", _0)]
+    NotOffered(Player),
+}
+
+impl std::error::Error for DrawError {}
@@ -0,0 +1,128 @@
+//! Game-theoretic evaluation of every legal choice in a position.
+//!
+//! Built on the [`Solver`]: each choice is searched exactly and its score
+//! is turned back into a win/draw/loss verdict plus the number of plies
+//! until that result.
+
+use super::{Game, Player, Score, Solver, WIN_SCORE};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tracing::{debug, instrument};
+
+/// Value of a choice for the player making it.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, strum::Display,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Verdict {
+    /// Forced win.
+    Win,
+    /// Draw under best play from both sides.
+    Draw,
+    /// Forced loss.
+    Loss,
+    /// No forced result within the search horizon.
+    Unknown,
+}
+
+/// Evaluation of one legal choice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChoiceAnalysis<C> {
+    /// The choice evaluated.
+    pub choice: C,
+    /// Result of the choice under best play.
+    pub verdict: Verdict,
+    /// Plies until the result, counting this move (wins and losses only).
+    pub distance: Option<usize>,
+    /// Raw solver score from the mover's point of view.
+    pub score: Score,
+    /// Whether no other choice scores better.
+    pub optimal: bool,
+}
+
+impl<C> ChoiceAnalysis<C> {
+    /// Interprets a solver score for `choice`.
+    ///
+    /// A zero score is a proven draw only when the search was exhaustive.
+    fn from_score(choice: C, score: Score, best: Score, exhaustive: bool) -> Self {
+        let distance = (score != 0).then(|| (WIN_SCORE - score.abs()) as usize + 1);
+        let verdict = match score {
+            s if s > 0 => Verdict::Win,
+            s if s < 0 => Verdict::Loss,
+            _ if exhaustive => Verdict::Draw,
+            _ => Verdict::Unknown,
+        };
+
+        Self {
+            choice,
+            verdict,
+            distance,
+            score,
+            optimal: score == best,
+        }
+    }
+
+    /// Describes the result, e.g. `win in 3` or `draw`.
+    pub fn result(&self) -> String {
+        match self.distance {
+            Some(distance) => format!("{} in {}", self.verdict, distance),
+            None => self.verdict.to_string(),
+        }
+    }
+}
+
+impl<C: fmt::Display> fmt::Display for ChoiceAnalysis<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.choice, self.result())?;
+        if self.optimal {
+            write!(f, " (optimal)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Evaluates every legal choice for the player to move.
+///
+/// Searches with the built-in bot's solver for `G` (see
+/// [`Game::BOT_DEPTH`]), so verdicts are exact for games solved to the
+/// end and [`Verdict::Unknown`] beyond the horizon otherwise.
+#[instrument(skip(game), fields(game = G::NAME))]
+pub fn analyze<G: Game>(game: &G::InProgress) -> Vec<ChoiceAnalysis<G::Choice>> {
+    let solver = Solver::for_game::<G>();
+    let exhaustive = solver.max_depth().is_none();
+
+    let scored = solver.score_choices::<G>(game);
+    let best = scored.iter().map(|&(_, score)| score).max().unwrap_or(0);
+
+    let analysis: Vec<_> = scored
+        .into_iter()
+        .map(|(choice, score)| ChoiceAnalysis::from_score(choice, score, best, exhaustive))
+        .collect();
+
+    debug!(choices = analysis.len(), best, "Analysis complete");
+    analysis
+}
+
+/// Value of the position for `player`, whether or not it is their move.
+///
+/// The best verdict available to the player to move, seen from
+/// `player`'s side. Used to judge resignations and draw offers.
+#[instrument(skip(game), fields(game = G::NAME))]
+pub fn position_verdict<G: Game>(game: &G::InProgress, player: Player) -> Verdict {
+    let best = analyze::<G>(game)
+        .into_iter()
+        .find(|choice| choice.optimal)
+        .map_or(Verdict::Unknown, |choice| choice.verdict);
+
+    if G::to_move(game) == player {
+        best
+    } else {
+        match best {
+            Verdict::Win => Verdict::Loss,
+            Verdict::Loss => Verdict::Win,
+            other => other,
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Move-by-move annotation of played games.
+//!
+//! Replays a game's history through the [`Game`] trait and compares each
+//! played choice with the [analysis](super::analysis) of the position it
+//! was played in.
+
+use super::analysis::{self, ChoiceAnalysis, Verdict};
+use super::{Game, Player, Transition};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tracing::{debug, instrument, warn};
+
+/// How a played move compares with the best available one.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, strum::Display,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum MoveQuality {
+    /// As good as any other move.
+    Best,
+    /// Worse than the best move but keeps the theoretical result
+    /// (e.g. a slower win), or worse by a margin the search can't prove
+    /// changes it.
+    Inaccuracy,
+    /// Changes the theoretical result (e.g. a won position into a draw).
+    Blunder,
+}
+
+impl MoveQuality {
+    /// Judges a played move against the best one available.
+    ///
+    /// A move is a blunder only when both verdicts are proven: in games
+    /// searched to a horizon, a move that is merely [`Verdict::Unknown`]
+    /// may still hold the result the best move forces.
+    pub fn of<C>(played: &ChoiceAnalysis<C>, best: &ChoiceAnalysis<C>) -> Self {
+        let proven = played.verdict != Verdict::Unknown && best.verdict != Verdict::Unknown;
+        if played.optimal {
+            MoveQuality::Best
+        } else if proven && played.verdict != best.verdict {
+            MoveQuality::Blunder
+        } else {
+            MoveQuality::Inaccuracy
+        }
+    }
+}
+
+/// One played move with its evaluation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnotatedMove<C> {
+    /// 1-based move number.
+    pub ply: usize,
+    /// Player who made the move.
+    pub player: Player,
+    /// Evaluation of the move that was played.
+    pub played: ChoiceAnalysis<C>,
+    /// Evaluation of the best move available.
+    pub best: ChoiceAnalysis<C>,
+    /// Verdict on the move.
+    pub quality: MoveQuality,
+}
+
+impl<C: fmt::Display> fmt::Display for AnnotatedMove<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}. {:?} {}: {} ({}",
+            self.ply,
+            self.player,
+            self.played.choice,
+            self.quality,
+            self.played.result()
+        )?;
+        if self.quality != MoveQuality::Best {
+            write!(f, "; best {}, {}", self.best.choice, self.best.result())?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Annotates every move of a game played from the start.
+///
+/// `moves` lists each move's player and choice in order. Stops early (with
+/// a warning) if a move fails to replay, so a corrupt history yields a
+/// partial record rather than an error.
+#[instrument(skip(moves), fields(game = G::NAME, moves = moves.len()))]
+pub fn annotate<G: Game>(moves: &[(Player, G::Choice)]) -> Vec<AnnotatedMove<G::Choice>> {
+    let Some(&(first_player, _)) = moves.first() else {
+        return Vec::new();
+    };
+
+    let mut game = G::new_game(first_player);
+    let mut annotated = Vec::with_capacity(moves.len());
+
+    for (index, &(player, choice)) in moves.iter().enumerate() {
+        let analysis = analysis::analyze::<G>(&game);
+        let played = analysis.iter().find(|c| c.choice == choice).cloned();
+        let best = analysis.iter().find(|c| c.optimal).cloned();
+        let (Some(played), Some(best)) = (played, best) else {
+            warn!(ply = index + 1, choice = %choice, "Played choice was not legal on replay");
+            break;
+        };
+
+        let quality = MoveQuality::of(&played, &best);
+
+        annotated.push(AnnotatedMove {
+            ply: index + 1,
+            player,
+            played,
+            best,
+            quality,
+        });
+
+        game = match G::make_move(game, G::action(player, choice)) {
+            Ok(Transition::InProgress(next)) => next,
+            Ok(Transition::Finished(_)) => break,
+            Err(e) => {
+                warn!(ply = index + 1, error = %e, "Move failed on replay");
+                break;
+            }
+        };
+    }
+
+    debug!(
+        annotated = annotated.len(),
+        blunders = annotated.iter().filter(|m| m.quality == MoveQuality::Blunder).count(),
+        "Annotation complete"
+    );
+    annotated
+}
@@ -0,0 +1,82 @@
+//! First-class action types for Connect Four.
+//!
+//! Moves are domain events, not side effects. They represent
+//! the player's intent and can be validated independently of execution.
+
+use super::{Column, Player};
+use crate::games::DrawError;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// A move in Connect Four: a player dropping a piece into a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Move {
+    /// The player making the move.
+    pub player: Player,
+    /// The column the piece is dropped into.
+    pub column: Column,
+}
+
+impl Move {
+    /// Creates a new move.
+    #[instrument]
+    pub fn new(player: Player, column: Column) -> Self {
+        Self { player, column }
+    }
+    
+    /// Returns the player making this move.
+    pub fn player(&self) -> Player {
+        self.player
+    }
+    
+    /// Returns the column of this move.
+    pub fn column(&self) -> Column {
+        self.column
+    }
+}
+
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} -> {}", self.player, self.column.label())
+    }
+}
+
+/// Error that can occur when validating or applying a move.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum MoveError {
+    /// The column has no room left.
+    #[display("Column {:?} is full", _0)]
+    ColumnFull(Column),
+    
+    /// The game is already over.
+    #[display("Game is already over")]
+    GameOver,
+    
+    /// The game has not started yet.
+    #[display("Game hasn't started yet")]
+    NotStarted,
+    
+    /// It's not this player's turn.
+    #[display("It's not {:?}'s turn", _0)]
+    WrongPlayer(Player),
+    
+    /// A draw offer or answer was rejected.
+    #[display("{}", _0)]
+    Draw(DrawError),
+    
+    /// A stored game snapshot contradicts itself.
+    #[display("Stored game is inconsistent: {}", _0)]
+    InconsistentState(String),
+    
+    /// An invariant was violated (postcondition failure).
+    #[display("Invariant violation: {}", _0)]
+    InvariantViolation(String),
+}
+
+impl std::error::Error for MoveError {}
+
+impl From<DrawError> for MoveError {
+    fn from(error: DrawError) -> Self {
+        MoveError::Draw(error)
+    }
+}
@@ -0,0 +1,173 @@
+//! Column enum with Select paradigm for Connect Four moves.
+
+use super::types::Board;
+use elicitation::{ElicitCommunicator, ElicitError, ElicitErrorKind, ElicitServer, Prompt, Select};
+use rmcp::{Peer, RoleServer};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// A column on the Connect Four board (0-6, left to right).
+///
+/// Players only choose a column; gravity decides the row.
+/// The game server filters out full columns before elicitation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, elicitation::Elicit, strum::EnumIter)]
+pub enum Column {
+    /// Leftmost column (column 0)
+    One,
+    /// Column 1
+    Two,
+    /// Column 2
+    Three,
+    /// Center column (column 3)
+    Four,
+    /// Column 4
+    Five,
+    /// Column 5
+    Six,
+    /// Rightmost column (column 6)
+    Seven,
+}
+
+/// View struct for filtered column selection.
+///
+/// Wraps a dynamic list of non-full columns to enable
+/// context-aware selection through the framework.
+#[derive(Debug, Clone)]
+pub struct ValidColumns {
+    /// Filtered list of valid columns
+    pub columns: Vec<Column>,
+}
+
+impl Prompt for ValidColumns {}
+
+impl Select for ValidColumns {
+    fn options() -> &'static [Self] {
+        // This will never be called - we override elicit_column() instead
+        &[]
+    }
+    
+    fn labels() -> &'static [&'static str] {
+        // This will never be called - we override elicit_column() instead
+        &[]
+    }
+    
+    fn from_label(_label: &str) -> Option<Self> {
+        // This will never be called - we override elicit_column() instead
+        None
+    }
+}
+
+impl ValidColumns {
+    /// Elicit a column from the filtered list.
+    pub async fn elicit_column(
+        self,
+        peer: Peer<RoleServer>,
+    ) -> Result<Column, ElicitError> {
+        // Build prompt with filtered options
+        let mut prompt = String::from("Please select a Column to drop your piece into:\n\nOptions:\n");
+        for (idx, column) in self.columns.iter().enumerate() {
+            prompt.push_str(&format!("{}. {}\n", idx + 1, column.label()));
+        }
+        prompt.push_str(&format!("\nRespond with the number (1-{}) or exact label:", self.columns.len()));
+        
+        // Use framework's ElicitServer
+        let server = ElicitServer::new(peer);
+        let response: String = server.send_prompt(&prompt).await?;
+        
+        // Parse response
+        let selected = if let Ok(num) = response.trim().parse::<usize>() {
+            if num >= 1 && num <= self.columns.len() {
+                self.columns[num - 1]
+            } else {
+                return Err(ElicitError::new(ElicitErrorKind::ParseError(
+                    format!("Invalid number: {}", num)
+                )));
+            }
+        } else {
+            Column::from_label_or_number(response.trim())
+                .filter(|column| self.columns.contains(column))
+                .ok_or_else(|| ElicitError::new(ElicitErrorKind::ParseError(
+                    format!("Invalid column: {}", response)
+                )))?
+        };
+        
+        Ok(selected)
+    }
+}
+
+impl Column {
+    /// All 7 columns, left to right.
+    pub const ALL: [Column; 7] = [
+        Column::One,
+        Column::Two,
+        Column::Three,
+        Column::Four,
+        Column::Five,
+        Column::Six,
+        Column::Seven,
+    ];
+
+    /// Get label for this column (for display).
+    #[instrument]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Column::One => "Column 1",
+            Column::Two => "Column 2",
+            Column::Three => "Column 3",
+            Column::Four => "Column 4",
+            Column::Five => "Column 5",
+            Column::Six => "Column 6",
+            Column::Seven => "Column 7",
+        }
+    }
+
+    /// Parse from label or 1-based column number.
+    #[instrument]
+    pub fn from_label_or_number(s: &str) -> Option<Column> {
+        // Try as 1-based column number first (as displayed on the board)
+        if let Ok(num) = s.trim().parse::<usize>() {
+            return num.checked_sub(1).and_then(Self::from_index);
+        }
+
+        // Try as label (case-insensitive)
+        let s_lower = s.trim().to_lowercase();
+        <Column as strum::IntoEnumIterator>::iter().find(|column| {
+            column.label().to_lowercase() == s_lower
+                || format!("{:?}", column).to_lowercase() == s_lower
+        })
+    }
+
+    /// Converts column to board index (0-6).
+    pub fn to_index(self) -> usize {
+        match self {
+            Column::One => 0,
+            Column::Two => 1,
+            Column::Three => 2,
+            Column::Four => 3,
+            Column::Five => 4,
+            Column::Six => 5,
+            Column::Seven => 6,
+        }
+    }
+
+    /// Creates column from board index.
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
+
+    /// Filters columns by board state - returns only columns with room.
+    pub fn valid_moves(board: &Board) -> Vec<Column> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|column| !board.is_column_full(*column))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Column {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
@@ -0,0 +1,94 @@
+//! Proof-carrying validation for Connect Four using elicitation contracts.
+//!
+//! Mirrors the tic-tac-toe contracts: a move may only be executed with
+//! proof that its column has room and that it is the player's turn.
+
+use super::action::{Move, MoveError};
+use super::typestate::GameInProgress;
+use elicitation::contracts::{And, Established, Prop, both};
+use tracing::instrument;
+
+// ─────────────────────────────────────────────────────────────
+//  Propositions (Type-Level Statements)
+// ─────────────────────────────────────────────────────────────
+
+/// Proposition: The move's column has at least one empty cell.
+pub struct ColumnNotFull;
+impl Prop for ColumnNotFull {}
+
+/// Proposition: It is the player's turn.
+pub struct PlayerTurn;
+impl Prop for PlayerTurn {}
+
+/// Composite proposition: A move is legal (column not full AND player's turn).
+pub type LegalMove = And<ColumnNotFull, PlayerTurn>;
+
+// ─────────────────────────────────────────────────────────────
+//  Validation Functions (Establish Proofs)
+// ─────────────────────────────────────────────────────────────
+
+/// Validates that the column has room.
+///
+/// Returns proof if valid, error otherwise.
+#[instrument(skip(game))]
+pub fn validate_column_not_full(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<ColumnNotFull>, MoveError> {
+    if game.board().is_column_full(mov.column) {
+        Err(MoveError::ColumnFull(mov.column))
+    } else {
+        Ok(Established::assert())
+    }
+}
+
+/// Validates that it's the player's turn.
+///
+/// Returns proof if valid, error otherwise.
+#[instrument(skip(game))]
+pub fn validate_player_turn(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<PlayerTurn>, MoveError> {
+    if mov.player != game.to_move() {
+        Err(MoveError::WrongPlayer(mov.player))
+    } else {
+        Ok(Established::assert())
+    }
+}
+
+/// Validates all preconditions for a move.
+///
+/// Returns composite proof (ColumnNotFull AND PlayerTurn) if valid.
+#[instrument(skip(game))]
+pub fn validate_move(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<LegalMove>, MoveError> {
+    let column_proof = validate_column_not_full(mov, game)?;
+    let turn_proof = validate_player_turn(mov, game)?;
+    Ok(both(column_proof, turn_proof))
+}
+
+// ─────────────────────────────────────────────────────────────
+//  Proof-Carrying Execution
+// ─────────────────────────────────────────────────────────────
+
+/// Executes a move with proof that preconditions hold.
+///
+/// Returns the row the piece landed on. The proof guarantees the
+/// column has room, so the drop cannot fail.
+#[instrument(skip(game, _proof))]
+pub fn execute_move(
+    mov: &Move,
+    game: &mut GameInProgress,
+    _proof: Established<LegalMove>,
+) -> usize {
+    // Proof guarantees: column not full AND player's turn
+    let row = game
+        .board
+        .drop_piece(mov.column, mov.player)
+        .expect("ColumnNotFull proof guarantees room in the column");
+    game.history.push(*mov);
+    row
+}
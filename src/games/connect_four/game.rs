@@ -0,0 +1,127 @@
+//! [`Game`] implementation for Connect Four.
+
+use super::action::{Move, MoveError};
+use super::column::{Column, ValidColumns};
+use super::typestate::{GameFinished, GameInProgress, GameResult, GameSetup};
+use super::wrapper::AnyGame;
+use crate::games::{Action, Game, GameState, Outcome, Player, Transition};
+use elicitation::ElicitError;
+use rmcp::{Peer, RoleServer};
+use std::future::Future;
+
+/// Marker type for Connect Four.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConnectFour;
+
+impl Game for ConnectFour {
+    const NAME: &'static str = "connect-four";
+
+    type Choice = Column;
+    type Action = Move;
+    type Error = MoveError;
+    type Setup = GameSetup;
+    type InProgress = GameInProgress;
+    type Finished = GameFinished;
+    type State = AnyGame;
+
+    // Alpha-beta search to the end is too slow for a 7x6 board
+    const BOT_DEPTH: Option<usize> = Some(7);
+
+    fn setup() -> GameSetup {
+        GameSetup::new()
+    }
+
+    fn start(setup: GameSetup, first_player: Player) -> GameInProgress {
+        setup.start(first_player)
+    }
+
+    fn action(player: Player, choice: Column) -> Move {
+        Move::new(player, choice)
+    }
+
+    fn make_move(game: GameInProgress, action: Move) -> Result<Transition<Self>, MoveError> {
+        Ok(game.make_move(action)?.into())
+    }
+
+    fn to_move(game: &GameInProgress) -> Player {
+        game.to_move()
+    }
+
+    fn legal_choices(game: &GameInProgress) -> Vec<Column> {
+        game.valid_moves()
+    }
+
+    fn outcome(game: &GameFinished) -> Outcome {
+        *game.outcome()
+    }
+
+    fn elicit_choice(
+        peer: Peer<RoleServer>,
+        choices: Vec<Column>,
+    ) -> impl Future<Output = Result<Column, ElicitError>> + Send {
+        ValidColumns { columns: choices }.elicit_column(peer)
+    }
+}
+
+impl From<GameResult> for Transition<ConnectFour> {
+    fn from(result: GameResult) -> Self {
+        match result {
+            GameResult::InProgress(g) => Transition::InProgress(g),
+            GameResult::Finished(g) => Transition::Finished(g),
+        }
+    }
+}
+
+impl GameState<ConnectFour> for AnyGame {
+    fn is_over(&self) -> bool {
+        AnyGame::is_over(self)
+    }
+
+    fn to_move(&self) -> Option<Player> {
+        AnyGame::to_move(self)
+    }
+
+    fn winner(&self) -> Option<Player> {
+        AnyGame::winner(self)
+    }
+
+    fn status_string(&self) -> String {
+        AnyGame::status_string(self)
+    }
+
+    fn render(&self) -> String {
+        self.board().display()
+    }
+
+    fn move_count(&self) -> usize {
+        self.history().len()
+    }
+
+    fn legal_choices(&self) -> Vec<Column> {
+        self.valid_moves()
+    }
+
+    fn apply(self, action: Move) -> Result<Self, String> {
+        self.make_move_action(action)
+    }
+
+    fn act(self, action: Action<Move>) -> Result<Self, String> {
+        AnyGame::act(self, action)
+    }
+
+    fn undo(self) -> Result<Self, String> {
+        AnyGame::undo(self)
+    }
+
+    fn draw_offer(&self) -> Option<Player> {
+        AnyGame::draw_offer(self)
+    }
+
+    fn in_progress(&self) -> Option<GameInProgress> {
+        AnyGame::in_progress(self)
+    }
+
+    fn moves(&self) -> Vec<(Player, Column)> {
+        self.history().iter().map(|m| (m.player, m.column)).collect()
+    }
+}
@@ -0,0 +1,53 @@
+//! Connect Four game implementation.
+//!
+//! Follows the same three-layer architecture as tic-tac-toe:
+//!
+//! ## 1. Elicitation (Type-Safe Construction)
+//!
+//! `Column` uses `#[derive(Elicit)]`; the server filters out full columns
+//! before elicitation via `ValidColumns`:
+//! ```ignore
+//! let column = ValidColumns { columns }.elicit_column(peer).await?;
+//! ```
+//!
+//! ## 2. Contracts (Proof-Carrying Validation)
+//!
+//! ```ignore
+//! let proof = validate_move(&action, &game)?;  // ColumnNotFull AND PlayerTurn
+//! execute_move(&action, &mut game, proof);     // Gravity drop, type-enforced
+//! ```
+//!
+//! ## 3. Typestate (Phase Enforcement)
+//!
+//! - `GameSetup` - initial state, can be started
+//! - `GameInProgress` - active game, can accept moves
+//! - `GameFinished` - terminal state, outcome determined
+//!
+//! Pieces fall to the lowest empty row of the chosen column; four in a row
+//! horizontally, vertically or diagonally wins.
+
+// Core domain types
+pub mod column;
+pub mod types;
+
+// Game rules (pure functions)
+pub mod rules;
+
+// Typestate architecture
+pub mod action;
+pub mod contracts;
+pub mod typestate;
+
+// Wrapper for session management
+pub mod wrapper;
+
+// Game trait implementation
+pub mod game;
+
+// Primary API - typestate architecture
+pub use action::{Move, MoveError};
+pub use column::{Column, ValidColumns};
+pub use game::ConnectFour;
+pub use typestate::{GameFinished, GameInProgress, GameResult, GameSetup};
+pub use types::{Board, Player, Square};
+pub use wrapper::AnyGame;
@@ -0,0 +1,32 @@
+//! Draw detection logic for Connect Four.
+
+use super::super::{Board, Column};
+
+/// Checks if the board is full (every column has been filled).
+///
+/// A full board with no winner indicates a draw.
+pub fn is_full(board: &Board) -> bool {
+    Column::ALL.iter().all(|&column| board.is_column_full(column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::Player;
+
+    #[test]
+    fn test_empty_board_not_full() {
+        assert!(!is_full(&Board::new()));
+    }
+
+    #[test]
+    fn test_full_board() {
+        let mut board = Board::new();
+        for column in Column::ALL {
+            for _ in 0..Board::ROWS {
+                board.drop_piece(column, Player::X);
+            }
+        }
+        assert!(is_full(&board));
+    }
+}
@@ -0,0 +1,94 @@
+//! Four-in-a-row detection for Connect Four.
+
+use super::super::{Board, Column, Player, Square};
+
+/// Number of pieces in a row needed to win.
+pub const WIN_LENGTH: usize = 4;
+
+/// Bit shifts between neighbouring cells along each line direction:
+/// vertical, horizontal, falling diagonal and rising diagonal.
+const DIRECTIONS: [usize; 4] = [1, Board::COLUMN_BITS, Board::COLUMN_BITS - 1, Board::COLUMN_BITS + 1];
+
+/// Returns the cells of `mask` that are part of four in a row.
+fn four_in_a_row(mask: u64) -> u64 {
+    DIRECTIONS.iter().fold(0, |cells, &shift| {
+        // Bits that start a run of two, then of four
+        let pairs = mask & (mask >> shift);
+        let starts = pairs & (pairs >> (2 * shift));
+        cells | starts | (starts << shift) | (starts << (2 * shift)) | (starts << (3 * shift))
+    })
+}
+
+/// Checks whether the piece at (row, column) completes four in a row.
+///
+/// Only lines through the given cell count, which makes this the check
+/// to run after each move.
+pub fn check_winner_at(board: &Board, row: usize, column: Column) -> Option<Player> {
+    let Square::Occupied(player) = board.get(row, column) else {
+        return None;
+    };
+    (four_in_a_row(board.mask(player)) & Board::bit(row, column) != 0).then_some(player)
+}
+
+/// Checks if there is a winner anywhere on the board.
+///
+/// Returns `Some(player)` if the player has four in a row,
+/// `None` otherwise.
+pub fn check_winner(board: &Board) -> Option<Player> {
+    [Player::X, Player::O]
+        .into_iter()
+        .find(|&player| four_in_a_row(board.mask(player)) != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_winner_empty_board() {
+        let board = Board::new();
+        assert_eq!(check_winner(&board), None);
+    }
+
+    #[test]
+    fn test_horizontal_win() {
+        let mut board = Board::new();
+        for column in [Column::Two, Column::Three, Column::Four, Column::Five] {
+            board.drop_piece(column, Player::X);
+        }
+        assert_eq!(check_winner(&board), Some(Player::X));
+        assert_eq!(check_winner_at(&board, 0, Column::Three), Some(Player::X));
+    }
+
+    #[test]
+    fn test_vertical_win() {
+        let mut board = Board::new();
+        for _ in 0..4 {
+            board.drop_piece(Column::Seven, Player::O);
+        }
+        assert_eq!(check_winner(&board), Some(Player::O));
+    }
+
+    #[test]
+    fn test_diagonal_win() {
+        let mut board = Board::new();
+        // Build a staircase so O lands on the rising diagonal
+        for (column, fillers) in [(Column::One, 0), (Column::Two, 1), (Column::Three, 2), (Column::Four, 3)] {
+            for _ in 0..fillers {
+                board.drop_piece(column, Player::X);
+            }
+            board.drop_piece(column, Player::O);
+        }
+        assert_eq!(check_winner(&board), Some(Player::O));
+        assert_eq!(check_winner_at(&board, 3, Column::Four), Some(Player::O));
+    }
+
+    #[test]
+    fn test_three_in_a_row_is_not_a_win() {
+        let mut board = Board::new();
+        for column in [Column::One, Column::Two, Column::Three] {
+            board.drop_piece(column, Player::X);
+        }
+        assert_eq!(check_winner(&board), None);
+    }
+}
@@ -0,0 +1,181 @@
+//! Core domain types for Connect Four.
+
+use super::Column;
+use elicitation::{Elicit, Prompt, Select};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// Player mark (shared across all games).
+pub use crate::games::player::Player;
+
+/// A cell on the Connect Four board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Elicit)]
+pub enum Square {
+    /// Empty cell.
+    Empty,
+    /// Cell occupied by a player's piece.
+    Occupied(Player),
+}
+
+/// 7-column, 6-row Connect Four board.
+///
+/// Row 0 is the bottom row; pieces fall towards it.
+///
+/// Stored as one mask per player. Each column takes 7 bits (bit
+/// `column * 7 + row`): 6 for the rows plus an always-empty sentinel on
+/// top, which keeps shifted masks from wrapping between columns during
+/// win detection. Serializes as rows of cells, same as the array-backed
+/// board did.
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "BoardRepr", into = "BoardRepr")]
+pub struct Board {
+    /// Cells held by X.
+    x: u64,
+    /// Cells held by O.
+    o: u64,
+}
+
+/// Serialized form of [`Board`].
+#[derive(Serialize, Deserialize)]
+struct BoardRepr {
+    /// Rows of cells, bottom row first.
+    rows: [[Square; Board::COLUMNS]; Board::ROWS],
+}
+
+impl From<BoardRepr> for Board {
+    fn from(repr: BoardRepr) -> Self {
+        let mut board = Board::new();
+        for (row, cells) in repr.rows.iter().enumerate() {
+            for (column, square) in Column::ALL.into_iter().zip(cells) {
+                if let Square::Occupied(player) = square {
+                    *board.mask_mut(*player) |= Board::bit(row, column);
+                }
+            }
+        }
+        board
+    }
+}
+
+impl From<Board> for BoardRepr {
+    fn from(board: Board) -> Self {
+        Self { rows: board.rows() }
+    }
+}
+
+impl Board {
+    /// Number of rows.
+    pub const ROWS: usize = 6;
+
+    /// Number of columns.
+    pub const COLUMNS: usize = 7;
+
+    /// Bits per column: the rows plus the sentinel.
+    pub const COLUMN_BITS: usize = Self::ROWS + 1;
+
+    /// Mask of the playable cells of one column (at column 0).
+    const COLUMN_MASK: u64 = (1 << Self::ROWS) - 1;
+
+    /// Creates a new empty board.
+    pub const fn new() -> Self {
+        Self { x: 0, o: 0 }
+    }
+
+    /// Returns the bit of the cell at the given row (0 = bottom) and column.
+    pub fn bit(row: usize, column: Column) -> u64 {
+        1 << (column.to_index() * Self::COLUMN_BITS + row)
+    }
+
+    /// Gets the cell at the given row (0 = bottom) and column.
+    pub fn get(&self, row: usize, column: Column) -> Square {
+        let bit = Self::bit(row, column);
+        if self.x & bit != 0 {
+            Square::Occupied(Player::X)
+        } else if self.o & bit != 0 {
+            Square::Occupied(Player::O)
+        } else {
+            Square::Empty
+        }
+    }
+
+    /// Returns the row a piece dropped into `column` would land on.
+    ///
+    /// Returns `None` if the column is full.
+    pub fn drop_row(&self, column: Column) -> Option<usize> {
+        // Pieces stack from the bottom, so the height is the piece count
+        let cells = (self.occupied() >> (column.to_index() * Self::COLUMN_BITS)) & Self::COLUMN_MASK;
+        let height = cells.count_ones() as usize;
+        (height < Self::ROWS).then_some(height)
+    }
+
+    /// Checks if a column has no room left.
+    pub fn is_column_full(&self, column: Column) -> bool {
+        self.drop_row(column).is_none()
+    }
+
+    /// Drops a piece into a column, returning the row it landed on.
+    ///
+    /// Returns `None` (leaving the board unchanged) if the column is full.
+    pub fn drop_piece(&mut self, column: Column, player: Player) -> Option<usize> {
+        let row = self.drop_row(column)?;
+        *self.mask_mut(player) |= Self::bit(row, column);
+        Some(row)
+    }
+
+    /// Returns all rows of cells (bottom row first).
+    pub fn rows(&self) -> [[Square; Board::COLUMNS]; Board::ROWS] {
+        std::array::from_fn(|row| Column::ALL.map(|column| self.get(row, column)))
+    }
+
+    /// Returns the mask of cells held by a player.
+    pub fn mask(&self, player: Player) -> u64 {
+        match player {
+            Player::X => self.x,
+            Player::O => self.o,
+        }
+    }
+
+    /// Returns the mask of occupied cells.
+    pub fn occupied(&self) -> u64 {
+        self.x | self.o
+    }
+
+    /// Returns the mask of a player's cells for writing.
+    fn mask_mut(&mut self, player: Player) -> &mut u64 {
+        match player {
+            Player::X => &mut self.x,
+            Player::O => &mut self.o,
+        }
+    }
+
+    /// Formats the board as a human-readable string (top row first).
+    #[instrument]
+    pub fn display(&self) -> String {
+        let mut result = String::new();
+        for row in (0..Self::ROWS).rev() {
+            let cells: Vec<&str> = Column::ALL
+                .iter()
+                .map(|&column| match self.get(row, column) {
+                    Square::Empty => ".",
+                    Square::Occupied(Player::X) => "X",
+                    Square::Occupied(Player::O) => "O",
+                })
+                .collect();
+            result.push_str(&cells.join(" "));
+            result.push('\n');
+        }
+        result.push_str("1 2 3 4 5 6 7");
+        result
+    }
+}
+
+impl std::fmt::Debug for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Board").field("rows", &self.rows()).finish()
+    }
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,241 @@
+//! Phase-specific typestate structs for Connect Four.
+//!
+//! Each phase is its own distinct type with phase-specific fields,
+//! exactly as in tic-tac-toe: a `GameFinished` ALWAYS has an outcome.
+
+use super::action::{Move, MoveError};
+use super::contracts::{execute_move, validate_move};
+use super::{Board, Column, Player};
+use crate::games::contracts::{validate_draw_answer, validate_draw_offer};
+use crate::games::{Action, Outcome};
+use tracing::instrument;
+
+// ─────────────────────────────────────────────────────────────
+//  Setup Phase
+// ─────────────────────────────────────────────────────────────
+
+/// Game in setup phase - ready to start.
+///
+/// The board is always empty.
+#[derive(Debug, Clone)]
+pub struct GameSetup {
+    board: Board,
+}
+
+impl GameSetup {
+    /// Creates a new game in setup phase.
+    #[instrument]
+    pub fn new() -> Self {
+        Self {
+            board: Board::new(),
+        }
+    }
+    
+    /// Returns the board.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+    
+    /// Starts the game with the first player (consumes setup, returns in-progress).
+    #[instrument(skip(self))]
+    pub fn start(self, first_player: Player) -> GameInProgress {
+        GameInProgress {
+            board: self.board,
+            history: Vec::new(),
+            to_move: first_player,
+            draw_offer: None,
+        }
+    }
+}
+
+impl Default for GameSetup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+//  InProgress Phase
+// ─────────────────────────────────────────────────────────────
+
+/// Game in progress - can accept moves.
+#[derive(Debug, Clone)]
+pub struct GameInProgress {
+    pub(super) board: Board,
+    pub(super) history: Vec<Move>,
+    pub(super) to_move: Player,
+    pub(super) draw_offer: Option<Player>,
+}
+
+impl GameInProgress {
+    /// Makes a move, consuming self and transitioning to next state.
+    ///
+    /// Proof-carrying validation establishes ColumnNotFull AND PlayerTurn
+    /// before the piece is dropped. Only lines through the landing cell
+    /// are scanned for a win.
+    #[instrument(skip(self))]
+    pub fn make_move(self, action: Move) -> Result<GameResult, MoveError> {
+        // Establish proof that preconditions hold
+        let proof = validate_move(&action, &self)?;
+        
+        // Execute with proof (zero-cost, enforced by type system)
+        let mut game = self;
+        let row = execute_move(&action, &mut game, proof);
+        // Playing on declines the opponent's draw offer
+        game.draw_offer = game.draw_offer.filter(|&offerer| offerer == action.player);
+        
+        // Check for winner through the landing cell
+        if let Some(winner) = super::rules::check_winner_at(&game.board, row, action.column) {
+            return Ok(GameResult::Finished(GameFinished {
+                board: game.board,
+                history: game.history,
+                outcome: Outcome::Winner(winner),
+            }));
+        }
+        
+        // Check for draw
+        if super::rules::is_full(&game.board) {
+            return Ok(GameResult::Finished(GameFinished {
+                board: game.board,
+                history: game.history,
+                outcome: Outcome::Draw,
+            }));
+        }
+        
+        // Continue game
+        game.to_move = game.to_move.opponent();
+        
+        Ok(GameResult::InProgress(game))
+    }
+    
+    /// Applies any domain action, consuming self.
+    ///
+    /// Placements go through [`make_move`](Self::make_move). Resigning,
+    /// timing out or accepting a draw finishes the game; a draw offer
+    /// waits for the opponent's answer, and the opponent placing a piece
+    /// declines it.
+    #[instrument(skip(self))]
+    pub fn apply(self, action: Action<Move>) -> Result<GameResult, MoveError> {
+        let mut game = self;
+        match action {
+            Action::Place(mov) => return game.make_move(mov),
+            Action::Resign(player) => return Ok(game.finish(Outcome::Resigned(player))),
+            Action::TimeOut(player) => return Ok(game.finish(Outcome::TimedOut(player))),
+            Action::OfferDraw(player) => {
+                let _proof = validate_draw_offer(game.draw_offer)?;
+                game.draw_offer = Some(player);
+            }
+            Action::AcceptDraw(player) => {
+                let _proof = validate_draw_answer(player, game.draw_offer)?;
+                return Ok(game.finish(Outcome::AgreedDraw));
+            }
+            Action::DeclineDraw(player) => {
+                let _proof = validate_draw_answer(player, game.draw_offer)?;
+                game.draw_offer = None;
+            }
+        }
+        Ok(GameResult::InProgress(game))
+    }
+    
+    /// Ends the game off the board with the given outcome.
+    fn finish(self, outcome: Outcome) -> GameResult {
+        GameResult::Finished(GameFinished {
+            board: self.board,
+            history: self.history,
+            outcome,
+        })
+    }
+    
+    /// Returns the player whose draw offer awaits an answer, if any.
+    pub fn draw_offer(&self) -> Option<Player> {
+        self.draw_offer
+    }
+    
+    /// Returns the current player to move.
+    pub fn to_move(&self) -> Player {
+        self.to_move
+    }
+    
+    /// Returns the board.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+    
+    /// Returns move history.
+    pub fn history(&self) -> &[Move] {
+        &self.history
+    }
+    
+    /// Returns columns that still have room.
+    #[instrument(skip(self))]
+    pub fn valid_moves(&self) -> Vec<Column> {
+        Column::valid_moves(&self.board)
+    }
+    
+    /// Replays moves from an empty board, starting with `first_player`.
+    #[instrument]
+    pub fn replay(first_player: Player, moves: &[Move]) -> Result<GameResult, MoveError> {
+        let mut game = GameSetup::new().start(first_player);
+        
+        for (idx, action) in moves.iter().enumerate() {
+            match game.make_move(*action)? {
+                GameResult::InProgress(g) => game = g,
+                GameResult::Finished(g) if idx + 1 == moves.len() => {
+                    return Ok(GameResult::Finished(g));
+                }
+                // Moves remain after the game ended
+                GameResult::Finished(_) => return Err(MoveError::GameOver),
+            }
+        }
+        
+        Ok(GameResult::InProgress(game))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+//  Finished Phase
+// ─────────────────────────────────────────────────────────────
+
+/// Game finished - outcome determined.
+#[derive(Debug, Clone)]
+pub struct GameFinished {
+    pub(super) board: Board,
+    pub(super) history: Vec<Move>,
+    pub(super) outcome: Outcome,
+}
+
+impl GameFinished {
+    /// Returns the outcome.
+    pub fn outcome(&self) -> &Outcome {
+        &self.outcome
+    }
+    
+    /// Returns the board.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+    
+    /// Returns move history.
+    pub fn history(&self) -> &[Move] {
+        &self.history
+    }
+    
+    /// Restarts the game (consumes finished, returns setup).
+    #[instrument(skip(self))]
+    pub fn restart(self) -> GameSetup {
+        GameSetup::new()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+//  Result Type
+// ─────────────────────────────────────────────────────────────
+
+/// Result of making a move.
+#[derive(Debug)]
+pub enum GameResult {
+    /// Game continues.
+    InProgress(GameInProgress),
+    /// Game finished.
+    Finished(GameFinished),
+}
@@ -0,0 +1,55 @@
+//! Proof-carrying validation for the game-agnostic actions.
+//!
+//! Draw offers follow the same pattern as each game's move contracts: an
+//! offer may only be recorded, and answered, with proof that the pending
+//! offer allows it. Resigning needs no proof beyond the typestate itself,
+//! since any player may concede a game in progress.
+
+use super::Player;
+use super::action::DrawError;
+use elicitation::contracts::{Established, Prop};
+use tracing::instrument;
+
+// ─────────────────────────────────────────────────────────────
+//  Propositions (Type-Level Statements)
+// ─────────────────────────────────────────────────────────────
+
+/// Proposition: No draw offer is waiting for an answer.
+pub struct NoDrawPending;
+impl Prop for NoDrawPending {}
+
+/// Proposition: The player's opponent has offered a draw.
+pub struct DrawOffered;
+impl Prop for DrawOffered {}
+
+// ─────────────────────────────────────────────────────────────
+//  Validation Functions (Establish Proofs)
+// ─────────────────────────────────────────────────────────────
+
+/// Validates that a new draw offer can be made.
+///
+/// Returns proof if no offer is pending, error otherwise.
+#[instrument]
+pub fn validate_draw_offer(
+    pending: Option<Player>,
+) -> Result<Established<NoDrawPending>, DrawError> {
+    match pending {
+        Some(offerer) => Err(DrawError::AlreadyOffered(offerer)),
+        None => Ok(Established::assert()),
+    }
+}
+
+/// Validates that the player has a draw offer to accept or decline.
+///
+/// Returns proof if the opponent's offer is pending, error otherwise.
+#[instrument]
+pub fn validate_draw_answer(
+    player: Player,
+    pending: Option<Player>,
+) -> Result<Established<DrawOffered>, DrawError> {
+    if pending == Some(player.opponent()) {
+        Ok(Established::assert())
+    } else {
+        Err(DrawError::NotOffered(player))
+    }
+}
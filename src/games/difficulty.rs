@@ -0,0 +1,87 @@
+//! Difficulty tiers for the built-in bot.
+
+use super::{Game, Score, Solver};
+use rand::seq::IndexedRandom;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument};
+
+/// How strongly a bot plays.
+///
+/// The tiers form a ladder for benchmarking agents: each one beats the
+/// tier below it more often than not. Each game sets how deep its tiers
+/// search ([`Game::GREEDY_DEPTH`], [`Game::LOOKAHEAD_DEPTH`],
+/// [`Game::BOT_DEPTH`]), and every tier searches deeper than the one
+/// below it.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum Difficulty {
+    /// Uniformly random legal move.
+    Random,
+    /// Shallow search (see [`Game::GREEDY_DEPTH`]): takes a win, blocks an
+    /// immediate loss where it sees two plies, otherwise random.
+    Greedy,
+    /// Depth-limited minimax (see [`Game::LOOKAHEAD_DEPTH`]); misses deep tactics.
+    Lookahead,
+    /// The game's deepest search (see [`Game::BOT_DEPTH`]).
+    ///
+    /// Only truly perfect where the game can be solved, i.e. tic-tac-toe;
+    /// in Connect Four and Gomoku it is the strongest tier, not a solver.
+    #[default]
+    Perfect,
+}
+
+impl Difficulty {
+    /// Returns the solver this tier searches with, if any.
+    pub fn solver<G: Game>(self) -> Option<Solver> {
+        match self {
+            Difficulty::Random => None,
+            Difficulty::Greedy => Some(Solver::with_depth(G::GREEDY_DEPTH)),
+            Difficulty::Lookahead => Some(Solver::with_depth(G::LOOKAHEAD_DEPTH)),
+            Difficulty::Perfect => Some(Solver::for_game::<G>()),
+        }
+    }
+
+    /// Picks a move for the player to move.
+    ///
+    /// Ties between equally scored moves are broken at random, so weaker
+    /// tiers don't replay the same game every time. Returns `None` if
+    /// there are no legal choices.
+    #[instrument(skip(game), fields(game = G::NAME))]
+    pub fn choose<G: Game>(self, game: &G::InProgress) -> Option<G::Choice> {
+        let mut rng = rand::rng();
+
+        let choice = match self.solver::<G>() {
+            None => G::legal_choices(game).choose(&mut rng).copied(),
+            // Perfect play keeps the pruned search; any best move will do
+            Some(solver) if self == Difficulty::Perfect => solver.best_choice::<G>(game),
+            Some(solver) => {
+                let scored = solver.score_choices::<G>(game);
+                let best: Option<Score> = scored.iter().map(|&(_, score)| score).max();
+                let candidates: Vec<G::Choice> = scored
+                    .iter()
+                    .filter(|&&(_, score)| Some(score) == best)
+                    .map(|&(choice, _)| choice)
+                    .collect();
+                candidates.choose(&mut rng).copied()
+            }
+        };
+
+        debug!(difficulty = %self, choice = ?choice, "Bot chose move");
+        choice
+    }
+}
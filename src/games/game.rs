@@ -0,0 +1,182 @@
+//! Game-agnostic traits shared by all hosted games.
+//!
+//! A game is described by a zero-sized marker type implementing [`Game`].
+//! The marker ties together:
+//!
+//! - the first-class action type (e.g. tic-tac-toe's `Move`)
+//! - the per-phase typestates (`Setup`, `InProgress`, `Finished`)
+//! - the serializable wrapper used by sessions ([`GameState`])
+//! - legal-move enumeration and outcome
+//!
+//! Session management and the server are written against these traits,
+//! so new games can be added without forking the server.
+
+use super::{Action, Outcome, Player, Score};
+use elicitation::ElicitError;
+use rmcp::{Peer, RoleServer};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fmt::{Debug, Display};
+use std::future::Future;
+
+/// A two-player, turn-based game built from typestate phases.
+pub trait Game: Debug + Clone + Copy + Send + Sync + 'static {
+    /// Human-readable game name.
+    const NAME: &'static str;
+
+    /// What a player picks on their turn (e.g. a board position).
+    type Choice: Debug + Display + Clone + Copy + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync;
+
+    /// First-class action applied to an in-progress game.
+    type Action: Debug + Display + Clone + Copy + PartialEq + Eq + Send + Sync;
+
+    /// Error raised when an action fails contract validation.
+    type Error: std::error::Error + Send + Sync;
+
+    /// Setup phase typestate.
+    type Setup: Debug + Clone + Send + Sync;
+
+    /// In-progress phase typestate.
+    type InProgress: Debug + Clone + Send + Sync;
+
+    /// Finished phase typestate.
+    type Finished: Debug + Clone + Send + Sync;
+
+    /// Serializable wrapper over every phase.
+    type State: GameState<Self>;
+
+    /// Search depth used by the built-in bot at its strongest tier.
+    ///
+    /// `None` searches to the end of the game (perfect play). Games whose
+    /// tree is too large to solve on every move set a horizon instead.
+    const BOT_DEPTH: Option<usize> = None;
+
+    /// Search depth of the greedy bot tier.
+    ///
+    /// Two plies see our win and the opponent's reply. Must be shallower
+    /// than [`LOOKAHEAD_DEPTH`](Self::LOOKAHEAD_DEPTH).
+    const GREEDY_DEPTH: usize = 2;
+
+    /// Search depth of the lookahead bot tier.
+    ///
+    /// Must be shallower than [`BOT_DEPTH`](Self::BOT_DEPTH), so every
+    /// tier searches deeper than the one below it.
+    const LOOKAHEAD_DEPTH: usize = 4;
+
+    /// Creates a game in setup phase.
+    fn setup() -> Self::Setup;
+
+    /// Starts the game (consumes setup, returns in-progress).
+    fn start(setup: Self::Setup, first_player: Player) -> Self::InProgress;
+
+    /// Builds the action for a player's choice.
+    fn action(player: Player, choice: Self::Choice) -> Self::Action;
+
+    /// Applies an action, consuming the in-progress game.
+    fn make_move(
+        game: Self::InProgress,
+        action: Self::Action,
+    ) -> Result<Transition<Self>, Self::Error>;
+
+    /// Returns the player to move.
+    fn to_move(game: &Self::InProgress) -> Player;
+
+    /// Returns every legal choice for the player to move.
+    fn legal_choices(game: &Self::InProgress) -> Vec<Self::Choice>;
+
+    /// Returns the outcome of a finished game.
+    fn outcome(game: &Self::Finished) -> Outcome;
+
+    /// Elicits one of the given choices from the peer.
+    fn elicit_choice(
+        peer: Peer<RoleServer>,
+        choices: Vec<Self::Choice>,
+    ) -> impl Future<Output = Result<Self::Choice, ElicitError>> + Send;
+
+    /// Key identifying a position for the solver's transposition table.
+    ///
+    /// Positions with equal keys must have the same value for the player
+    /// to move; games may fold symmetric positions onto one key. `None`
+    /// (the default) disables the table for the game.
+    fn position_key(_game: &Self::InProgress) -> Option<u64> {
+        None
+    }
+
+    /// Precomputed perfect-play scores of every legal choice, if known.
+    ///
+    /// Scores must match what an exhaustive [`Solver`](super::Solver)
+    /// search would return. The solver uses them instead of searching;
+    /// `None` (the default) means the position must be searched.
+    fn tablebase_scores(_game: &Self::InProgress) -> Option<Vec<(Self::Choice, Score)>> {
+        None
+    }
+
+    /// Creates a fresh in-progress game.
+    fn new_game(first_player: Player) -> Self::InProgress {
+        Self::start(Self::setup(), first_player)
+    }
+}
+
+/// Result of applying an action to an in-progress game.
+#[derive(Debug)]
+pub enum Transition<G: Game> {
+    /// Game continues.
+    InProgress(G::InProgress),
+    /// Game finished.
+    Finished(G::Finished),
+}
+
+/// Serializable view of a game in any phase.
+///
+/// This is what sessions store and what REST clients receive.
+pub trait GameState<G: Game>:
+    Debug
+    + Clone
+    + Serialize
+    + DeserializeOwned
+    + Send
+    + Sync
+    + From<G::Setup>
+    + From<G::InProgress>
+    + From<G::Finished>
+{
+    /// Returns true if the game is over.
+    fn is_over(&self) -> bool;
+
+    /// Returns the current player to move, if the game is in progress.
+    fn to_move(&self) -> Option<Player>;
+
+    /// Returns the winner, if the game is won.
+    fn winner(&self) -> Option<Player>;
+
+    /// Returns a status string for display.
+    fn status_string(&self) -> String;
+
+    /// Renders the board as a human-readable string.
+    fn render(&self) -> String;
+
+    /// Returns the number of moves played.
+    fn move_count(&self) -> usize;
+
+    /// Returns the legal choices for the player to move.
+    fn legal_choices(&self) -> Vec<G::Choice>;
+
+    /// Applies an action with contract validation.
+    fn apply(self, action: G::Action) -> Result<Self, String>;
+
+    /// Applies any domain action (placement, resignation or draw offer)
+    /// with contract validation.
+    fn act(self, action: Action<G::Action>) -> Result<Self, String>;
+
+    /// Takes back the last move, reopening a finished game.
+    fn undo(self) -> Result<Self, String>;
+
+    /// Returns the player whose draw offer awaits an answer, if any.
+    fn draw_offer(&self) -> Option<Player>;
+
+    /// Rebuilds the in-progress typestate, if the game is in progress.
+    fn in_progress(&self) -> Option<G::InProgress>;
+
+    /// Returns every move played so far as `(player, choice)`, in order.
+    fn moves(&self) -> Vec<(Player, G::Choice)>;
+}
@@ -0,0 +1,35 @@
+//! Runtime selector for the hosted games.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Which game a session hosts.
+///
+/// Used where the game is picked at runtime (MCP requests, CLI flags);
+/// everything downstream dispatches to the matching [`Game`](super::Game)
+/// marker type.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum GameKind {
+    /// Tic-tac-toe on a 3x3 board.
+    #[default]
+    TicTacToe,
+    /// Connect Four on a 7x6 board with gravity.
+    ConnectFour,
+    /// Gomoku: the 15,15,5 m,n,k game.
+    Gomoku,
+}
@@ -0,0 +1,110 @@
+//! Proof-carrying validation for m,n,k games using elicitation contracts.
+//!
+//! Mirrors the tic-tac-toe contracts, with an extra bounds proof since
+//! coordinates are not a closed enum.
+
+use super::action::{Move, MoveError};
+use super::typestate::GameInProgress;
+use super::Square;
+use elicitation::contracts::{And, Established, Prop, both};
+use tracing::instrument;
+
+// ─────────────────────────────────────────────────────────────
+//  Propositions (Type-Level Statements)
+// ─────────────────────────────────────────────────────────────
+
+/// Proposition: The move's cell lies on the board.
+pub struct InBounds;
+impl Prop for InBounds {}
+
+/// Proposition: The move's cell is empty.
+pub struct SquareEmpty;
+impl Prop for SquareEmpty {}
+
+/// Proposition: It is the player's turn.
+pub struct PlayerTurn;
+impl Prop for PlayerTurn {}
+
+/// Composite proposition: A move is legal (in bounds AND square empty AND player's turn).
+pub type LegalMove = And<InBounds, And<SquareEmpty, PlayerTurn>>;
+
+// ─────────────────────────────────────────────────────────────
+//  Validation Functions (Establish Proofs)
+// ─────────────────────────────────────────────────────────────
+
+/// Validates that the cell is on the board.
+///
+/// Returns proof if valid, error otherwise.
+#[instrument(skip(game))]
+pub fn validate_in_bounds(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<InBounds>, MoveError> {
+    if !game.board().contains(mov.coord) {
+        Err(MoveError::OutOfBounds(mov.coord))
+    } else {
+        Ok(Established::assert())
+    }
+}
+
+/// Validates that the cell is empty.
+///
+/// Returns proof if valid, error otherwise.
+#[instrument(skip(game))]
+pub fn validate_square_empty(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<SquareEmpty>, MoveError> {
+    if !game.board().is_empty(mov.coord) {
+        Err(MoveError::SquareOccupied(mov.coord))
+    } else {
+        Ok(Established::assert())
+    }
+}
+
+/// Validates that it's the player's turn.
+///
+/// Returns proof if valid, error otherwise.
+#[instrument(skip(game))]
+pub fn validate_player_turn(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<PlayerTurn>, MoveError> {
+    if mov.player != game.to_move() {
+        Err(MoveError::WrongPlayer(mov.player))
+    } else {
+        Ok(Established::assert())
+    }
+}
+
+/// Validates all preconditions for a move.
+///
+/// Returns composite proof (InBounds AND SquareEmpty AND PlayerTurn) if valid.
+#[instrument(skip(game))]
+pub fn validate_move(
+    mov: &Move,
+    game: &GameInProgress,
+) -> Result<Established<LegalMove>, MoveError> {
+    let bounds_proof = validate_in_bounds(mov, game)?;
+    let square_proof = validate_square_empty(mov, game)?;
+    let turn_proof = validate_player_turn(mov, game)?;
+    Ok(both(bounds_proof, both(square_proof, turn_proof)))
+}
+
+// ─────────────────────────────────────────────────────────────
+//  Proof-Carrying Execution
+// ─────────────────────────────────────────────────────────────
+
+/// Executes a move with proof that preconditions hold.
+///
+/// Cannot be called without first obtaining proof via `validate_move()`.
+#[instrument(skip(game, _proof))]
+pub fn execute_move(
+    mov: &Move,
+    game: &mut GameInProgress,
+    _proof: Established<LegalMove>,
+) {
+    // Proof guarantees: in bounds AND square empty AND player's turn
+    game.board.set(mov.coord, Square::Occupied(mov.player));
+    game.history.push(*mov);
+}
@@ -0,0 +1,133 @@
+//! Board coordinates with filtered selection for m,n,k moves.
+
+use super::types::Board;
+use elicitation::{ElicitCommunicator, ElicitError, ElicitErrorKind, ElicitServer, Prompt, Select};
+use rmcp::{Peer, RoleServer};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// A cell on an m,n,k board.
+///
+/// Rows count from the top, columns from the left, both 0-based.
+/// Displayed in board notation: column letter then 1-based row
+/// (`A1` is the top-left cell).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct Coord {
+    /// Row index (0 = top).
+    pub row: usize,
+    /// Column index (0 = left).
+    pub column: usize,
+}
+
+/// View struct for filtered coordinate selection.
+///
+/// Wraps the list of empty cells so elicitation only
+/// ever offers legal moves, like `ValidPositions` does for tic-tac-toe.
+#[derive(Debug, Clone)]
+pub struct ValidCoords {
+    /// Filtered list of empty cells
+    pub coords: Vec<Coord>,
+}
+
+impl Prompt for ValidCoords {}
+
+impl Select for ValidCoords {
+    fn options() -> &'static [Self] {
+        // This will never be called - we override elicit_coord() instead
+        &[]
+    }
+    
+    fn labels() -> &'static [&'static str] {
+        // This will never be called - we override elicit_coord() instead
+        &[]
+    }
+    
+    fn from_label(_label: &str) -> Option<Self> {
+        // This will never be called - we override elicit_coord() instead
+        None
+    }
+}
+
+impl ValidCoords {
+    /// Elicit a coordinate from the filtered list.
+    pub async fn elicit_coord(
+        self,
+        peer: Peer<RoleServer>,
+    ) -> Result<Coord, ElicitError> {
+        // Build prompt with filtered options
+        let mut prompt = String::from("Please select a cell to place your mark on:\n\nOptions:\n");
+        for (idx, coord) in self.coords.iter().enumerate() {
+            prompt.push_str(&format!("{}. {}\n", idx + 1, coord));
+        }
+        prompt.push_str(&format!(
+            "\nRespond with the number (1-{}) or the cell label (e.g. H8):",
+            self.coords.len()
+        ));
+        
+        // Use framework's ElicitServer
+        let server = ElicitServer::new(peer);
+        let response: String = server.send_prompt(&prompt).await?;
+        
+        // Parse response
+        let selected = if let Ok(num) = response.trim().parse::<usize>() {
+            if num >= 1 && num <= self.coords.len() {
+                self.coords[num - 1]
+            } else {
+                return Err(ElicitError::new(ElicitErrorKind::ParseError(
+                    format!("Invalid number: {}", num)
+                )));
+            }
+        } else {
+            Coord::from_label(response.trim())
+                .filter(|coord| self.coords.contains(coord))
+                .ok_or_else(|| ElicitError::new(ElicitErrorKind::ParseError(
+                    format!("Invalid cell: {}", response)
+                )))?
+        };
+        
+        Ok(selected)
+    }
+}
+
+impl Coord {
+    /// Creates a coordinate from 0-based row and column.
+    pub fn new(row: usize, column: usize) -> Self {
+        Self { row, column }
+    }
+
+    /// Returns the letter naming a column (`A` for column 0).
+    pub fn column_letter(column: usize) -> char {
+        (b'A' + column as u8) as char
+    }
+
+    /// Parses board notation such as `H8` (case-insensitive).
+    #[instrument]
+    pub fn from_label(s: &str) -> Option<Coord> {
+        let s = s.trim();
+        let mut chars = s.chars();
+        let letter = chars.next()?.to_ascii_uppercase();
+        if !letter.is_ascii_uppercase() {
+            return None;
+        }
+        let row: usize = chars.as_str().trim().parse().ok()?;
+        Some(Coord {
+            row: row.checked_sub(1)?,
+            column: (letter as u8 - b'A') as usize,
+        })
+    }
+
+    /// Filters cells by board state - returns only empty cells.
+    pub fn valid_moves(board: &Board) -> Vec<Coord> {
+        board
+            .coords()
+            .filter(|&coord| board.is_empty(coord))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Coord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", Self::column_letter(self.column), self.row + 1)
+    }
+}
@@ -0,0 +1,147 @@
+//! [`Game`] implementation for m,n,k games.
+
+use super::action::{Move, MoveError};
+use super::coord::{Coord, ValidCoords};
+use super::typestate::{GameFinished, GameInProgress, GameResult, GameSetup};
+use super::types::MnkConfig;
+use super::wrapper::AnyGame;
+use crate::games::{Action, Game, GameState, Outcome, Player, Transition};
+use elicitation::ElicitError;
+use rmcp::{Peer, RoleServer};
+use std::future::Future;
+
+/// Marker type for the m,n,k-game on an `M`×`N` board with `K` in a row.
+///
+/// The parameters are checked at compile time: `Mnk<3, 3, 4>` fails to
+/// build as soon as a game is created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Mnk<const M: usize, const N: usize, const K: usize>;
+
+/// Gomoku (free-style): 15x15 board, five in a row.
+pub type Gomoku = Mnk<15, 15, 5>;
+
+impl<const M: usize, const N: usize, const K: usize> Mnk<M, N, K> {
+    /// The validated board configuration.
+    pub const CONFIG: MnkConfig = match MnkConfig::new(M, N, K) {
+        Ok(config) => config,
+        Err(_) => panic!("invalid m,n,k parameters"),
+    };
+}
+
+impl<const M: usize, const N: usize, const K: usize> Game for Mnk<M, N, K> {
+    // Gomoku is named like its `GameKind`, so tracing fields match the API
+    const NAME: &'static str = if M == 15 && N == 15 && K == 5 { "gomoku" } else { "mnk" };
+
+    type Choice = Coord;
+    type Action = Move;
+    type Error = MoveError;
+    type Setup = GameSetup;
+    type InProgress = GameInProgress;
+    type Finished = GameFinished;
+    type State = AnyGame;
+
+    // Only boards up to tic-tac-toe size are small enough to solve; on
+    // larger ones every ply multiplies the work by the number of empty
+    // cells, so the tiers step up one ply at a time
+    const BOT_DEPTH: Option<usize> = if M * N <= 9 { None } else { Some(3) };
+    const GREEDY_DEPTH: usize = if M * N <= 9 { 2 } else { 1 };
+    const LOOKAHEAD_DEPTH: usize = if M * N <= 9 { 4 } else { 2 };
+
+    fn setup() -> GameSetup {
+        GameSetup::new(Self::CONFIG)
+    }
+
+    fn start(setup: GameSetup, first_player: Player) -> GameInProgress {
+        setup.start(first_player)
+    }
+
+    fn action(player: Player, choice: Coord) -> Move {
+        Move::new(player, choice)
+    }
+
+    fn make_move(game: GameInProgress, action: Move) -> Result<Transition<Self>, MoveError> {
+        Ok(game.make_move(action)?.into())
+    }
+
+    fn to_move(game: &GameInProgress) -> Player {
+        game.to_move()
+    }
+
+    fn legal_choices(game: &GameInProgress) -> Vec<Coord> {
+        game.valid_moves()
+    }
+
+    fn outcome(game: &GameFinished) -> Outcome {
+        *game.outcome()
+    }
+
+    fn elicit_choice(
+        peer: Peer<RoleServer>,
+        choices: Vec<Coord>,
+    ) -> impl Future<Output = Result<Coord, ElicitError>> + Send {
+        ValidCoords { coords: choices }.elicit_coord(peer)
+    }
+}
+
+impl<const M: usize, const N: usize, const K: usize> From<GameResult> for Transition<Mnk<M, N, K>> {
+    fn from(result: GameResult) -> Self {
+        match result {
+            GameResult::InProgress(g) => Transition::InProgress(g),
+            GameResult::Finished(g) => Transition::Finished(g),
+        }
+    }
+}
+
+impl<const M: usize, const N: usize, const K: usize> GameState<Mnk<M, N, K>> for AnyGame {
+    fn is_over(&self) -> bool {
+        AnyGame::is_over(self)
+    }
+
+    fn to_move(&self) -> Option<Player> {
+        AnyGame::to_move(self)
+    }
+
+    fn winner(&self) -> Option<Player> {
+        AnyGame::winner(self)
+    }
+
+    fn status_string(&self) -> String {
+        AnyGame::status_string(self)
+    }
+
+    fn render(&self) -> String {
+        self.board().display()
+    }
+
+    fn move_count(&self) -> usize {
+        self.history().len()
+    }
+
+    fn legal_choices(&self) -> Vec<Coord> {
+        self.valid_moves()
+    }
+
+    fn apply(self, action: Move) -> Result<Self, String> {
+        self.make_move_action(action)
+    }
+
+    fn act(self, action: Action<Move>) -> Result<Self, String> {
+        AnyGame::act(self, action)
+    }
+
+    fn undo(self) -> Result<Self, String> {
+        AnyGame::undo(self)
+    }
+
+    fn draw_offer(&self) -> Option<Player> {
+        AnyGame::draw_offer(self)
+    }
+
+    fn in_progress(&self) -> Option<GameInProgress> {
+        AnyGame::in_progress(self)
+    }
+
+    fn moves(&self) -> Vec<(Player, Coord)> {
+        self.history().iter().map(|m| (m.player, m.coord)).collect()
+    }
+}
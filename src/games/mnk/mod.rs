@@ -0,0 +1,57 @@
+//! Generalized m,n,k game implementation.
+//!
+//! An m×n board where the first player to get k marks in a row
+//! (horizontally, vertically or diagonally) wins. Tic-tac-toe is the
+//! 3,3,3-game; Gomoku is the 15,15,5-game.
+//!
+//! Follows the same three-layer architecture as tic-tac-toe:
+//!
+//! ## 1. Elicitation (Type-Safe Construction)
+//!
+//! Coordinates are not a finite enum, so the server always elicits
+//! through the filtered `ValidCoords` view of empty cells:
+//! ```ignore
+//! let coord = ValidCoords { coords }.elicit_coord(peer).await?;
+//! ```
+//!
+//! ## 2. Contracts (Proof-Carrying Validation)
+//!
+//! ```ignore
+//! let proof = validate_move(&action, &game)?;  // InBounds AND SquareEmpty AND PlayerTurn
+//! execute_move(&action, &mut game, proof);
+//! ```
+//!
+//! ## 3. Typestate (Phase Enforcement)
+//!
+//! - `GameSetup` - initial state for a given `MnkConfig`, can be started
+//! - `GameInProgress` - active game, can accept moves
+//! - `GameFinished` - terminal state, outcome determined
+//!
+//! Win detection only scans the lines through the last move, so the
+//! cost per move is O(k) regardless of board size.
+
+// Core domain types
+pub mod coord;
+pub mod types;
+
+// Game rules (pure functions)
+pub mod rules;
+
+// Typestate architecture
+pub mod action;
+pub mod contracts;
+pub mod typestate;
+
+// Wrapper for session management
+pub mod wrapper;
+
+// Game trait implementation
+pub mod game;
+
+// Primary API - typestate architecture
+pub use action::{Move, MoveError};
+pub use coord::{Coord, ValidCoords};
+pub use game::{Gomoku, Mnk};
+pub use typestate::{GameFinished, GameInProgress, GameResult, GameSetup};
+pub use types::{Board, MnkConfig, MnkConfigError, Player, Square};
+pub use wrapper::AnyGame;
@@ -0,0 +1,33 @@
+//! Draw detection logic for m,n,k games.
+
+use super::super::Board;
+
+/// Checks if the board is full (all squares occupied).
+///
+/// A full board with no winner indicates a draw.
+pub fn is_full(board: &Board) -> bool {
+    board.coords().all(|coord| !board.is_empty(coord))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::{Coord, MnkConfig, Player, Square};
+
+    #[test]
+    fn test_empty_board_not_full() {
+        assert!(!is_full(&Board::new(MnkConfig::GOMOKU)));
+    }
+
+    #[test]
+    fn test_full_board() {
+        let config = MnkConfig::new(2, 3, 3).expect("Valid config");
+        let mut board = Board::new(config);
+        for row in 0..2 {
+            for column in 0..3 {
+                board.set(Coord::new(row, column), Square::Occupied(Player::O));
+            }
+        }
+        assert!(is_full(&board));
+    }
+}
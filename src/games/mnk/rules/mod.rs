@@ -0,0 +1,10 @@
+//! Game rules for m,n,k games.
+//!
+//! Pure functions for evaluating game state, separated from board
+//! storage so they compose into the contract system.
+
+pub mod draw;
+pub mod win;
+
+pub use draw::is_full;
+pub use win::{check_winner, check_winner_at};
@@ -0,0 +1,100 @@
+//! k-in-a-row detection for m,n,k games.
+
+use super::super::{Board, Coord, Player, Square};
+use tracing::instrument;
+
+/// Directions to scan: horizontal, vertical, and both diagonals.
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+/// Returns the square at a signed (row, column) coordinate, if on the board.
+fn cell(board: &Board, row: isize, column: isize) -> Option<Square> {
+    let coord = Coord::new(usize::try_from(row).ok()?, usize::try_from(column).ok()?);
+    board.get(coord)
+}
+
+/// Checks whether the mark at `coord` completes k in a row.
+///
+/// Only the four lines through `coord` are scanned, so this is
+/// O(k) per move however large the board is.
+pub fn check_winner_at(board: &Board, coord: Coord) -> Option<Player> {
+    let Some(Square::Occupied(player)) = board.get(coord) else {
+        return None;
+    };
+    let target = Square::Occupied(player);
+    let k = board.config().k;
+    let (row, col) = (coord.row as isize, coord.column as isize);
+
+    for (dr, dc) in DIRECTIONS {
+        let mut count = 1;
+        for sign in [1, -1] {
+            let mut step = 1;
+            while count < k
+                && cell(board, row + sign * dr * step, col + sign * dc * step) == Some(target)
+            {
+                count += 1;
+                step += 1;
+            }
+        }
+        if count >= k {
+            return Some(player);
+        }
+    }
+
+    None
+}
+
+/// Checks if there is a winner anywhere on the board.
+///
+/// Returns `Some(player)` if the player has k in a row,
+/// `None` otherwise.
+#[instrument(skip(board))]
+pub fn check_winner(board: &Board) -> Option<Player> {
+    board.coords().find_map(|coord| check_winner_at(board, coord))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::MnkConfig;
+
+    fn place(board: &mut Board, player: Player, cells: &[(usize, usize)]) {
+        for &(row, column) in cells {
+            board.set(Coord::new(row, column), Square::Occupied(player));
+        }
+    }
+
+    #[test]
+    fn test_no_winner_empty_board() {
+        let board = Board::new(MnkConfig::GOMOKU);
+        assert_eq!(check_winner(&board), None);
+    }
+
+    #[test]
+    fn test_horizontal_five() {
+        let mut board = Board::new(MnkConfig::GOMOKU);
+        place(&mut board, Player::X, &[(7, 3), (7, 4), (7, 5), (7, 6), (7, 7)]);
+        assert_eq!(check_winner_at(&board, Coord::new(7, 5)), Some(Player::X));
+        assert_eq!(check_winner(&board), Some(Player::X));
+    }
+
+    #[test]
+    fn test_anti_diagonal_at_edge() {
+        let mut board = Board::new(MnkConfig::GOMOKU);
+        place(&mut board, Player::O, &[(0, 14), (1, 13), (2, 12), (3, 11), (4, 10)]);
+        assert_eq!(check_winner_at(&board, Coord::new(0, 14)), Some(Player::O));
+    }
+
+    #[test]
+    fn test_four_is_not_five() {
+        let mut board = Board::new(MnkConfig::GOMOKU);
+        place(&mut board, Player::X, &[(0, 0), (1, 0), (2, 0), (3, 0)]);
+        assert_eq!(check_winner(&board), None);
+    }
+
+    #[test]
+    fn test_tic_tac_toe_config_matches_three_in_a_row() {
+        let mut board = Board::new(MnkConfig::TIC_TAC_TOE);
+        place(&mut board, Player::X, &[(0, 0), (1, 1), (2, 2)]);
+        assert_eq!(check_winner_at(&board, Coord::new(1, 1)), Some(Player::X));
+    }
+}
@@ -0,0 +1,167 @@
+//! Core domain types for m,n,k games.
+
+use super::Coord;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// Player mark (shared across all games).
+pub use crate::games::player::Player;
+
+/// Squares are the same as in tic-tac-toe.
+pub use crate::games::tictactoe::Square;
+
+/// Board dimensions and win length of an m,n,k game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct MnkConfig {
+    /// Number of rows (m).
+    pub rows: usize,
+    /// Number of columns (n).
+    pub columns: usize,
+    /// Marks in a row needed to win (k).
+    pub k: usize,
+}
+
+/// Error returned for impossible m,n,k parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum MnkConfigError {
+    /// The board has no cells.
+    #[display("Board must have at least one row and one column")]
+    EmptyBoard,
+
+    /// More columns than there are column letters.
+    #[display("Board can have at most {} columns", MnkConfig::MAX_COLUMNS)]
+    TooManyColumns,
+
+    /// No line on the board is long enough to win.
+    #[display("Win length {} does not fit on a {}x{} board", _0, _1, _2)]
+    UnwinnableLength(usize, usize, usize),
+}
+
+impl std::error::Error for MnkConfigError {}
+
+impl MnkConfig {
+    /// Columns are labelled with single letters.
+    pub const MAX_COLUMNS: usize = 26;
+
+    /// Tic-tac-toe: 3x3, three in a row.
+    pub const TIC_TAC_TOE: MnkConfig = MnkConfig { rows: 3, columns: 3, k: 3 };
+
+    /// Gomoku: 15x15, five in a row.
+    pub const GOMOKU: MnkConfig = MnkConfig { rows: 15, columns: 15, k: 5 };
+
+    /// Creates a validated configuration.
+    pub const fn new(rows: usize, columns: usize, k: usize) -> Result<Self, MnkConfigError> {
+        if rows == 0 || columns == 0 {
+            return Err(MnkConfigError::EmptyBoard);
+        }
+        if columns > Self::MAX_COLUMNS {
+            return Err(MnkConfigError::TooManyColumns);
+        }
+        if k == 0 || (k > rows && k > columns) {
+            return Err(MnkConfigError::UnwinnableLength(k, rows, columns));
+        }
+        Ok(Self { rows, columns, k })
+    }
+
+    /// Total number of cells on the board.
+    pub const fn cells(&self) -> usize {
+        self.rows * self.columns
+    }
+}
+
+impl std::fmt::Display for MnkConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{}, {} in a row", self.rows, self.columns, self.k)
+    }
+}
+
+/// m×n board for an m,n,k game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Board {
+    /// Dimensions and win length.
+    config: MnkConfig,
+    /// Squares in row-major order, top row first.
+    squares: Vec<Square>,
+}
+
+impl Board {
+    /// Creates a new empty board.
+    #[instrument]
+    pub fn new(config: MnkConfig) -> Self {
+        Self {
+            config,
+            squares: vec![Square::Empty; config.cells()],
+        }
+    }
+
+    /// Returns the board's configuration.
+    pub fn config(&self) -> MnkConfig {
+        self.config
+    }
+
+    /// Checks whether a coordinate lies on the board.
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.row < self.config.rows && coord.column < self.config.columns
+    }
+
+    /// Gets the square at the given coordinate, if on the board.
+    pub fn get(&self, coord: Coord) -> Option<Square> {
+        if !self.contains(coord) {
+            return None;
+        }
+        self.squares
+            .get(coord.row * self.config.columns + coord.column)
+            .copied()
+    }
+
+    /// Sets the square at the given coordinate.
+    ///
+    /// Coordinates off the board are ignored.
+    pub fn set(&mut self, coord: Coord, square: Square) {
+        if self.contains(coord)
+            && let Some(cell) = self.squares.get_mut(coord.row * self.config.columns + coord.column)
+        {
+            *cell = square;
+        }
+    }
+
+    /// Checks if a square is on the board and empty.
+    pub fn is_empty(&self, coord: Coord) -> bool {
+        self.get(coord) == Some(Square::Empty)
+    }
+
+    /// Iterates over every coordinate in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + use<> {
+        let MnkConfig { rows, columns, .. } = self.config;
+        (0..rows).flat_map(move |row| (0..columns).map(move |column| Coord { row, column }))
+    }
+
+    /// Formats the board as a human-readable string.
+    ///
+    /// Columns are labelled with letters and rows with 1-based numbers,
+    /// matching [`Coord`]'s notation.
+    #[instrument]
+    pub fn display(&self) -> String {
+        let width = self.config.rows.to_string().len();
+        let mut result = format!("{}\n", self.config);
+
+        result.push_str(&" ".repeat(width + 1));
+        let letters: Vec<String> = (0..self.config.columns)
+            .map(|column| Coord::column_letter(column).to_string())
+            .collect();
+        result.push_str(&letters.join(" "));
+
+        for row in 0..self.config.rows {
+            let cells: Vec<&str> = (0..self.config.columns)
+                .map(|column| match self.get(Coord { row, column }) {
+                    Some(Square::Occupied(Player::X)) => "X",
+                    Some(Square::Occupied(Player::O)) => "O",
+                    _ => ".",
+                })
+                .collect();
+            result.push_str(&format!("\n{:>width$} {}", row + 1, cells.join(" ")));
+        }
+        result
+    }
+}
@@ -0,0 +1,273 @@
+//! Serializable game wrapper for m,n,k typestate phases.
+
+use super::action::{Move, MoveError};
+use super::coord::Coord;
+use super::typestate::{GameFinished, GameInProgress, GameResult, GameSetup};
+use super::types::{Board, Player, Square};
+use crate::games::{Action, Outcome};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument, warn};
+
+/// Serializable wrapper for an m,n,k game in any phase.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AnyGame {
+    /// Game in setup phase.
+    Setup {
+        /// The board state.
+        board: Board,
+    },
+    /// Game in progress.
+    InProgress {
+        /// The board state.
+        board: Board,
+        /// Current player to move.
+        to_move: Player,
+        /// Move history.
+        history: Vec<Move>,
+        /// Player whose draw offer awaits an answer.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        draw_offer: Option<Player>,
+    },
+    /// Game finished.
+    Finished {
+        /// The board state.
+        board: Board,
+        /// The outcome.
+        outcome: Outcome,
+        /// Move history.
+        history: Vec<Move>,
+    },
+}
+
+impl From<GameSetup> for AnyGame {
+    fn from(game: GameSetup) -> Self {
+        AnyGame::Setup {
+            board: game.board().clone(),
+        }
+    }
+}
+
+impl From<GameInProgress> for AnyGame {
+    fn from(game: GameInProgress) -> Self {
+        AnyGame::InProgress {
+            board: game.board().clone(),
+            to_move: game.to_move(),
+            history: game.history().to_vec(),
+            draw_offer: game.draw_offer(),
+        }
+    }
+}
+
+impl From<GameFinished> for AnyGame {
+    fn from(game: GameFinished) -> Self {
+        AnyGame::Finished {
+            board: game.board().clone(),
+            outcome: *game.outcome(),
+            history: game.history().to_vec(),
+        }
+    }
+}
+
+impl From<GameResult> for AnyGame {
+    fn from(result: GameResult) -> Self {
+        match result {
+            GameResult::InProgress(g) => g.into(),
+            GameResult::Finished(g) => g.into(),
+        }
+    }
+}
+
+impl TryFrom<AnyGame> for GameInProgress {
+    type Error = MoveError;
+
+    /// Restores the typestate from the stored snapshot without replaying it.
+    fn try_from(game: AnyGame) -> Result<Self, MoveError> {
+        match game {
+            AnyGame::InProgress { board, to_move, history, draw_offer } => {
+                check_snapshot(&board, &history)?;
+                if history.last().is_some_and(|last| last.player == to_move) {
+                    return Err(MoveError::InconsistentState(format!(
+                        "{:?} is to move after their own move",
+                        to_move
+                    )));
+                }
+                Ok(GameInProgress { board, history, to_move, draw_offer })
+            }
+            AnyGame::Setup { .. } => Err(MoveError::NotStarted),
+            AnyGame::Finished { .. } => Err(MoveError::GameOver),
+        }
+    }
+}
+
+impl TryFrom<AnyGame> for GameFinished {
+    type Error = MoveError;
+
+    /// Restores the typestate from the stored snapshot without replaying it.
+    fn try_from(game: AnyGame) -> Result<Self, MoveError> {
+        match game {
+            AnyGame::Finished { board, outcome, history } => {
+                check_snapshot(&board, &history)?;
+                Ok(GameFinished { board, history, outcome })
+            }
+            AnyGame::Setup { .. } => Err(MoveError::NotStarted),
+            AnyGame::InProgress { .. } => {
+                Err(MoveError::InconsistentState("Game is still in progress".to_string()))
+            }
+        }
+    }
+}
+
+/// Checks a stored snapshot in constant time.
+///
+/// The history's last stone must be on the board where it was played.
+/// Everything else was validated by the move contracts when the snapshot
+/// was taken.
+fn check_snapshot(board: &Board, history: &[Move]) -> Result<(), MoveError> {
+    match history.last() {
+        Some(last) if board.get(last.coord) != Some(Square::Occupied(last.player)) => {
+            Err(MoveError::InconsistentState(format!("Last move {} is not on the board", last)))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl AnyGame {
+    /// Returns the board for any game phase.
+    pub fn board(&self) -> &Board {
+        match self {
+            AnyGame::Setup { board } => board,
+            AnyGame::InProgress { board, .. } => board,
+            AnyGame::Finished { board, .. } => board,
+        }
+    }
+
+    /// Returns the move history for any game phase.
+    pub fn history(&self) -> &[Move] {
+        match self {
+            AnyGame::Setup { .. } => &[],
+            AnyGame::InProgress { history, .. } => history,
+            AnyGame::Finished { history, .. } => history,
+        }
+    }
+
+    /// Returns a status string for display.
+    pub fn status_string(&self) -> String {
+        match self {
+            AnyGame::Setup { .. } => "Ready to start".to_string(),
+            AnyGame::InProgress { to_move, .. } => {
+                format!("In progress. Player {:?} to move.", to_move)
+            }
+            AnyGame::Finished { outcome, .. } => format!("Game over. {}", outcome.status()),
+        }
+    }
+
+    /// Returns true if the game is over.
+    pub fn is_over(&self) -> bool {
+        matches!(self, AnyGame::Finished { .. })
+    }
+
+    /// Returns the current player to move, if game is in progress.
+    pub fn to_move(&self) -> Option<Player> {
+        match self {
+            AnyGame::InProgress { to_move, .. } => Some(*to_move),
+            _ => None,
+        }
+    }
+
+    /// Returns the winner, if game is won.
+    pub fn winner(&self) -> Option<Player> {
+        match self {
+            AnyGame::Finished { outcome, .. } => outcome.winner(),
+            _ => None,
+        }
+    }
+
+    /// Returns empty cells, if game is in progress.
+    pub fn valid_moves(&self) -> Vec<Coord> {
+        match self {
+            AnyGame::InProgress { board, .. } => Coord::valid_moves(board),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the player whose draw offer awaits an answer, if any.
+    pub fn draw_offer(&self) -> Option<Player> {
+        match self {
+            AnyGame::InProgress { draw_offer, .. } => *draw_offer,
+            _ => None,
+        }
+    }
+
+    /// Restores the in-progress typestate, if the game is in progress.
+    #[instrument(skip(self))]
+    pub fn in_progress(&self) -> Option<GameInProgress> {
+        if !matches!(self, AnyGame::InProgress { .. }) {
+            return None;
+        }
+        GameInProgress::try_from(self.clone())
+            .inspect_err(|e| warn!(error = %e, "Stored game failed snapshot check"))
+            .ok()
+    }
+
+    /// Makes a move using a Move action.
+    ///
+    /// The typestate is restored directly from the snapshot, so only the
+    /// new move goes through contract validation.
+    #[instrument(skip(self))]
+    pub fn make_move_action(self, action: Move) -> Result<Self, String> {
+        let result = GameInProgress::try_from(self)
+            .and_then(|game| game.make_move(action))
+            .map_err(|e| {
+                warn!(error = %e, "Contract validation failed");
+                e.to_string()
+            })?;
+
+        debug!("Move validated via typestate contracts");
+        Ok(result.into())
+    }
+
+    /// Takes back the last move, reopening a finished game.
+    ///
+    /// The remaining history is replayed with contract validation.
+    #[instrument(skip(self))]
+    pub fn undo(self) -> Result<Self, String> {
+        let (board, mut history) = match self {
+            AnyGame::InProgress { board, history, .. } => (board, history),
+            AnyGame::Finished { outcome, .. } if outcome.is_off_board() => {
+                return Err("A game decided off the board can't be taken back".to_string());
+            }
+            AnyGame::Finished { board, history, .. } => (board, history),
+            AnyGame::Setup { .. } => return Err("Game hasn't started yet".to_string()),
+        };
+        let last = history.pop().ok_or_else(|| "No moves to undo".to_string())?;
+        let first_player = history.first().map(|m| m.player).unwrap_or(last.player);
+
+        debug!(move_count = history.len(), "Replaying moves without the last one");
+
+        match GameInProgress::replay(board.config(), first_player, &history) {
+            Ok(result) => Ok(result.into()),
+            Err(e) => {
+                warn!(error = %e, "Contract validation failed");
+                Err(e.to_string())
+            }
+        }
+    }
+
+    /// Applies any domain action with contract validation.
+    ///
+    /// Placements go through [`make_move_action`](Self::make_move_action);
+    /// resignations and draw offers are applied to the restored typestate.
+    #[instrument(skip(self))]
+    pub fn act(self, action: Action<Move>) -> Result<Self, String> {
+        if let Action::Place(mov) = action {
+            return self.make_move_action(mov);
+        }
+
+        let game = self.in_progress()
+            .ok_or_else(|| "Game is not in progress".to_string())?;
+        game.apply(action).map(Into::into).map_err(|e| {
+            warn!(error = %e, "Contract validation failed");
+            e.to_string()
+        })
+    }
+}
@@ -1,3 +1,29 @@
 //! Game implementations.
+//!
+//! Every game module provides a marker type implementing [`Game`], which
+//! lets sessions, MCP tools and REST routes host it without knowing the
+//! game's rules.
 
+pub mod action;
+pub mod analysis;
+pub mod annotation;
+pub mod contracts;
+pub mod game;
+pub mod kind;
+pub mod outcome;
+pub mod player;
+pub mod solver;
+pub mod transposition;
+
+pub mod connect_four;
+pub mod difficulty;
+pub mod mnk;
 pub mod tictactoe;
+
+pub use action::{Action, DrawError};
+pub use difficulty::Difficulty;
+pub use game::{Game, GameState, Transition};
+pub use kind::GameKind;
+pub use outcome::Outcome;
+pub use player::{Mark, Player};
+pub use solver::{Score, Solver, WIN_SCORE};
@@ -0,0 +1,69 @@
+//! Outcome of a finished game, shared by all two-player games.
+
+use super::Player;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a finished game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub enum Outcome {
+    /// Player won the game.
+    Winner(Player),
+    /// Game ended in a draw.
+    Draw,
+    /// Player conceded the game; the opponent wins.
+    Resigned(Player),
+    /// Players agreed to a draw.
+    AgreedDraw,
+    /// Player ran out of time; the opponent wins.
+    TimedOut(Player),
+}
+
+impl Outcome {
+    /// Returns the winner if there is one.
+    pub fn winner(&self) -> Option<Player> {
+        match self {
+            Outcome::Winner(player) => Some(*player),
+            Outcome::Resigned(player) | Outcome::TimedOut(player) => Some(player.opponent()),
+            Outcome::Draw | Outcome::AgreedDraw => None,
+        }
+    }
+
+    /// Returns true if the game was a draw.
+    pub fn is_draw(&self) -> bool {
+        matches!(self, Outcome::Draw | Outcome::AgreedDraw)
+    }
+
+    /// Returns true if the game ended off the board: by resignation,
+    /// agreement or timeout.
+    pub fn is_off_board(&self) -> bool {
+        matches!(self, Outcome::Resigned(_) | Outcome::AgreedDraw | Outcome::TimedOut(_))
+    }
+
+    /// Describes the outcome for a status line, e.g. `Player X wins!`.
+    pub fn status(&self) -> String {
+        match self {
+            Outcome::Winner(player) => format!("Player {:?} wins!", player),
+            Outcome::Draw => "Draw!".to_string(),
+            Outcome::Resigned(player) => {
+                format!("Player {:?} resigned, Player {:?} wins!", player, player.opponent())
+            }
+            Outcome::AgreedDraw => "Draw agreed!".to_string(),
+            Outcome::TimedOut(player) => {
+                format!("Player {:?} ran out of time, Player {:?} wins!", player, player.opponent())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Winner(player) => write!(f, "Player {:?} wins", player),
+            Outcome::Draw => write!(f, "Draw"),
+            Outcome::Resigned(player) => write!(f, "Player {:?} resigned", player),
+            Outcome::AgreedDraw => write!(f, "Draw agreed"),
+            Outcome::TimedOut(player) => write!(f, "Player {:?} lost on time", player),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Player marks shared by all two-player games.
+
+use elicitation::{Elicit, Prompt, Select};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// Player in the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Elicit)]
+pub enum Player {
+    /// Player X (goes first).
+    X,
+    /// Player O (goes second).
+    O,
+}
+
+impl Player {
+    /// Returns the opponent player.
+    #[instrument]
+    pub fn opponent(self) -> Self {
+        match self {
+            Player::X => Player::O,
+            Player::O => Player::X,
+        }
+    }
+}
+
+/// Alias for clarity in session management.
+pub type Mark = Player;
@@ -0,0 +1,250 @@
+//! Game-agnostic negamax search with alpha-beta pruning.
+//!
+//! The solver only talks to games through the [`Game`] trait, so every
+//! hosted game gets a built-in bot for free. Positions are explored by
+//! consuming clones of the in-progress typestate, which means every
+//! searched move goes through the same contracts as a real one.
+//!
+//! Exhaustive searches of games that provide a
+//! [`Game::position_key`] share results between transposed (and, where
+//! the key is canonical, mirrored) positions through a
+//! [`TranspositionTable`]. Games with a precomputed table
+//! ([`Game::tablebase_scores`]) skip the search entirely.
+
+use super::transposition::{Bound, Entry, TranspositionTable};
+use super::{Game, Transition};
+use tracing::{debug, instrument};
+
+/// Score of a position from the point of view of the player to move.
+///
+/// Positive is good for the player to move, negative is bad, `0` is a
+/// draw (or unknown, at the search horizon).
+pub type Score = i32;
+
+/// Score of a win on the very next move.
+///
+/// A win `n` plies after the next move scores `WIN_SCORE - n`, so the
+/// solver prefers quick wins and slow losses.
+pub const WIN_SCORE: Score = 1_000_000;
+
+/// Negamax solver with alpha-beta pruning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solver {
+    /// Maximum plies to search; `None` searches to the end of the game.
+    max_depth: Option<usize>,
+}
+
+impl Solver {
+    /// Creates a solver that searches to the end of the game.
+    pub fn perfect() -> Self {
+        Self { max_depth: None }
+    }
+
+    /// Creates a solver that stops after `depth` plies.
+    pub fn with_depth(depth: usize) -> Self {
+        Self {
+            max_depth: Some(depth),
+        }
+    }
+
+    /// Creates the solver the built-in bot uses for `G` (see [`Game::BOT_DEPTH`]).
+    pub fn for_game<G: Game>() -> Self {
+        Self {
+            max_depth: G::BOT_DEPTH,
+        }
+    }
+
+    /// Returns the maximum search depth, if limited.
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Picks the best choice for the player to move.
+    ///
+    /// Ties go to the earliest choice in [`Game::legal_choices`] order.
+    /// Returns `None` if there are no legal choices.
+    #[instrument(skip(game), fields(game = G::NAME))]
+    pub fn best_choice<G: Game>(&self, game: &G::InProgress) -> Option<G::Choice> {
+        if let Some(scores) = self.tablebase::<G>(game) {
+            let mut best: Option<(G::Choice, Score)> = None;
+            for (choice, score) in scores {
+                if best.is_none_or(|(_, best_score)| score > best_score) {
+                    best = Some((choice, score));
+                }
+            }
+            debug!(best = ?best, "Tablebase hit");
+            return best.map(|(choice, _)| choice);
+        }
+
+        let mut table = TranspositionTable::new();
+        let mut best: Option<(G::Choice, Score)> = None;
+        let mut alpha = -Score::MAX;
+
+        for choice in G::legal_choices(game) {
+            let Some(score) = self.score_choice::<G>(game, choice, 0, alpha, Score::MAX, &mut table) else {
+                continue;
+            };
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((choice, score));
+                alpha = alpha.max(score);
+            }
+        }
+
+        debug!(best = ?best, positions = table.len(), "Search complete");
+        best.map(|(choice, _)| choice)
+    }
+
+    /// Scores every legal choice for the player to move.
+    ///
+    /// Each choice gets an exact score (no pruning across siblings).
+    #[instrument(skip(game), fields(game = G::NAME))]
+    pub fn score_choices<G: Game>(&self, game: &G::InProgress) -> Vec<(G::Choice, Score)> {
+        if let Some(scores) = self.tablebase::<G>(game) {
+            return scores;
+        }
+
+        let mut table = TranspositionTable::new();
+        G::legal_choices(game)
+            .into_iter()
+            .filter_map(|choice| {
+                let score =
+                    self.score_choice::<G>(game, choice, 0, -Score::MAX, Score::MAX, &mut table)?;
+                Some((choice, score))
+            })
+            .collect()
+    }
+
+    /// Scores the position for the player to move.
+    #[instrument(skip(game), fields(game = G::NAME))]
+    pub fn evaluate<G: Game>(&self, game: &G::InProgress) -> Score {
+        if let Some(scores) = self.tablebase::<G>(game) {
+            return scores.into_iter().map(|(_, score)| score).max().unwrap_or(0);
+        }
+
+        let mut table = TranspositionTable::new();
+        self.negamax::<G>(game, 0, -Score::MAX, Score::MAX, &mut table)
+    }
+
+    /// Looks up the root's choice scores when the search would be exhaustive.
+    ///
+    /// Depth-limited solvers must keep searching: their scores stop at
+    /// the horizon, and the lookup would make them play perfectly.
+    fn tablebase<G: Game>(&self, game: &G::InProgress) -> Option<Vec<(G::Choice, Score)>> {
+        if self.max_depth.is_some() {
+            return None;
+        }
+        G::tablebase_scores(game)
+    }
+
+    /// Scores one choice, made `ply` moves below the root, from the mover's point of view.
+    ///
+    /// Returns `None` if the choice is rejected by the game's contracts.
+    fn score_choice<G: Game>(
+        &self,
+        game: &G::InProgress,
+        choice: G::Choice,
+        ply: usize,
+        alpha: Score,
+        beta: Score,
+        table: &mut TranspositionTable,
+    ) -> Option<Score> {
+        let player = G::to_move(game);
+        let action = G::action(player, choice);
+
+        match G::make_move(game.clone(), action).ok()? {
+            Transition::Finished(finished) => Some(match G::outcome(&finished).winner() {
+                Some(winner) if winner == player => WIN_SCORE - ply as Score,
+                Some(_) => -(WIN_SCORE - ply as Score),
+                None => 0,
+            }),
+            Transition::InProgress(next) => {
+                Some(-self.negamax::<G>(&next, ply + 1, -beta, -alpha, table))
+            }
+        }
+    }
+
+    /// Negamax over the in-progress position, `ply` moves below the root.
+    fn negamax<G: Game>(
+        &self,
+        game: &G::InProgress,
+        ply: usize,
+        mut alpha: Score,
+        mut beta: Score,
+        table: &mut TranspositionTable,
+    ) -> Score {
+        if self.max_depth.is_some_and(|depth| ply >= depth) {
+            return 0;
+        }
+
+        // Horizon scores depend on the remaining depth, so only exhaustive searches share results
+        let key = if self.max_depth.is_none() { G::position_key(game) } else { None };
+        let alpha_orig = alpha;
+
+        if let Some(entry) = key.and_then(|key| table.get(key)) {
+            let score = from_stored(entry.score, ply);
+            match entry.bound {
+                Bound::Exact => return score,
+                Bound::Lower => alpha = alpha.max(score),
+                Bound::Upper => beta = beta.min(score),
+            }
+            if alpha >= beta {
+                return score;
+            }
+        }
+
+        let mut best = -Score::MAX;
+        for choice in G::legal_choices(game) {
+            let Some(score) = self.score_choice::<G>(game, choice, ply, alpha, beta, table) else {
+                continue;
+            };
+            best = best.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let best = if best == -Score::MAX { 0 } else { best };
+
+        if let Some(key) = key {
+            let bound = if best <= alpha_orig {
+                Bound::Upper
+            } else if best >= beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            table.insert(key, Entry { score: to_stored(best, ply), bound });
+        }
+
+        best
+    }
+}
+
+/// Converts a score relative to the root into one relative to a position `ply` moves deep.
+///
+/// Win distances are counted from the root during search but must be
+/// counted from the position itself when stored, since the same position
+/// can be reached at different depths.
+fn to_stored(score: Score, ply: usize) -> Score {
+    match score {
+        s if s > 0 => s + ply as Score,
+        s if s < 0 => s - ply as Score,
+        _ => 0,
+    }
+}
+
+/// Inverse of [`to_stored`].
+fn from_stored(score: Score, ply: usize) -> Score {
+    match score {
+        s if s > 0 => s - ply as Score,
+        s if s < 0 => s + ply as Score,
+        _ => 0,
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::perfect()
+    }
+}
@@ -4,6 +4,8 @@
 //! the player's intent and can be validated independently of execution.
 
 use super::{Player, Position};
+use crate::games::DrawError;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
@@ -14,7 +16,7 @@ use tracing::instrument;
 /// - Serialized for replay
 /// - Logged for debugging
 /// - Reasoned about by contracts
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub struct Move {
     /// The player making the move.
     pub player: Player,
@@ -57,13 +59,35 @@ pub enum MoveError {
     #[display("Game is already over")]
     GameOver,
     
+    /// The game has not started yet.
+    #[display("Game hasn't started yet")]
+    NotStarted,
+    
     /// It's not this player's turn.
     #[display("It's not {:?}'s turn", _0)]
     WrongPlayer(Player),
     
+    /// No move has been played, so there is nothing to take back.
+    #[display("No moves to undo")]
+    NothingToUndo,
+    
+    /// A draw offer or answer was rejected.
+    #[display("{}", _0)]
+    Draw(DrawError),
+    
+    /// A stored game snapshot contradicts itself.
+    #[display("Stored game is inconsistent: {}", _0)]
+    InconsistentState(String),
+    
     /// An invariant was violated (postcondition failure).
     #[display("Invariant violation: {}", _0)]
     InvariantViolation(String),
 }
 
 impl std::error::Error for MoveError {}
+
+impl From<DrawError> for MoveError {
+    fn from(error: DrawError) -> Self {
+        MoveError::Draw(error)
+    }
+}
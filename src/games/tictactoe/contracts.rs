@@ -5,9 +5,15 @@
 //! - Zero-cost verification (proofs are PhantomData)
 //! - Formally verified composition (Kani-checked)
 //! - Type-enforced validation (can't execute without proof)
+//!
+//! Postconditions are checked after every transition in debug builds, or
+//! in any build with the `postconditions` feature. A failure means the
+//! game logic itself is wrong and surfaces as
+//! [`MoveError::InvariantViolation`].
 
 use super::action::{Move, MoveError};
-use super::typestate::GameInProgress;
+use super::typestate::{GameInProgress, GameResult};
+use super::{Board, Player, Square};
 use elicitation::contracts::{And, Established, Prop, both};
 use tracing::instrument;
 
@@ -26,6 +32,26 @@ impl Prop for PlayerTurn {}
 /// Composite proposition: A move is legal (square empty AND player's turn).
 pub type LegalMove = And<SquareEmpty, PlayerTurn>;
 
+/// Postcondition: The board gained exactly the move's mark and nothing else changed.
+pub struct OneMarkAdded;
+impl Prop for OneMarkAdded {}
+
+/// Postcondition: The history grew by exactly the move.
+pub struct HistoryExtended;
+impl Prop for HistoryExtended {}
+
+/// Postcondition: The turn passed to the opponent (vacuous once the game is over).
+pub struct TurnAlternated;
+impl Prop for TurnAlternated {}
+
+/// Postcondition: The first player has the same number of marks as the
+/// second, or one more.
+pub struct MarksBalanced;
+impl Prop for MarksBalanced {}
+
+/// Composite postcondition: A move was applied correctly.
+pub type MoveApplied = And<And<OneMarkAdded, HistoryExtended>, And<TurnAlternated, MarksBalanced>>;
+
 // ─────────────────────────────────────────────────────────────
 //  Validation Functions (Establish Proofs)
 // ─────────────────────────────────────────────────────────────
@@ -94,3 +120,106 @@ pub fn execute_move(
     game.board.set(mov.position, super::Square::Occupied(mov.player));
     game.history.push(*mov);
 }
+
+// ─────────────────────────────────────────────────────────────
+//  Postcondition Checks
+// ─────────────────────────────────────────────────────────────
+
+/// Checks that `after` is `before` plus the move's mark.
+#[instrument(skip(before, after))]
+pub fn check_one_mark_added(
+    mov: &Move,
+    before: &Board,
+    after: &Board,
+) -> Result<Established<OneMarkAdded>, MoveError> {
+    let mut expected = before.clone();
+    expected.set(mov.position, Square::Occupied(mov.player));
+    if before.is_empty(mov.position) && *after == expected {
+        Ok(Established::assert())
+    } else {
+        Err(MoveError::InvariantViolation(format!(
+            "Board after {} is not the board before plus one mark",
+            mov
+        )))
+    }
+}
+
+/// Checks that `after` is the `before_len` earlier moves followed by the move.
+#[instrument(skip(after))]
+pub fn check_history_extended(
+    mov: &Move,
+    before_len: usize,
+    after: &[Move],
+) -> Result<Established<HistoryExtended>, MoveError> {
+    if after.len() == before_len + 1 && after.last() == Some(mov) {
+        Ok(Established::assert())
+    } else {
+        Err(MoveError::InvariantViolation(format!(
+            "History grew from {} to {} moves after {}",
+            before_len,
+            after.len(),
+            mov
+        )))
+    }
+}
+
+/// Checks that the player to move after the move is the mover's opponent.
+#[instrument]
+pub fn check_turn_alternated(
+    mov: &Move,
+    to_move: Player,
+) -> Result<Established<TurnAlternated>, MoveError> {
+    if to_move == mov.player.opponent() {
+        Ok(Established::assert())
+    } else {
+        Err(MoveError::InvariantViolation(format!(
+            "{:?} is to move after {}",
+            to_move, mov
+        )))
+    }
+}
+
+/// Checks that the first player has as many marks as the second, or one more.
+#[instrument(skip(board))]
+pub fn check_marks_balanced(
+    board: &Board,
+    first_player: Player,
+) -> Result<Established<MarksBalanced>, MoveError> {
+    let first = board.mask(first_player).count_ones();
+    let second = board.mask(first_player.opponent()).count_ones();
+    if first == second || first == second + 1 {
+        Ok(Established::assert())
+    } else {
+        Err(MoveError::InvariantViolation(format!(
+            "{:?} moved first but has {} marks to {}",
+            first_player, first, second
+        )))
+    }
+}
+
+/// Checks every postcondition of a transition from `before` by `mov`.
+///
+/// Returns composite proof that the move was applied correctly.
+#[instrument(skip(before, after))]
+pub fn verify_transition(
+    mov: &Move,
+    before: &GameInProgress,
+    after: &GameResult,
+) -> Result<Established<MoveApplied>, MoveError> {
+    let (board, history) = match after {
+        GameResult::InProgress(game) => (game.board(), game.history()),
+        GameResult::Finished(game) => (game.board(), game.history()),
+    };
+    // Only an in-progress game has a player to move
+    let to_move = match after {
+        GameResult::InProgress(game) => game.to_move(),
+        GameResult::Finished(_) => mov.player.opponent(),
+    };
+    let first_player = history.first().map_or(mov.player, |first| first.player);
+
+    let mark = check_one_mark_added(mov, before.board(), board)?;
+    let history = check_history_extended(mov, before.history().len(), history)?;
+    let turn = check_turn_alternated(mov, to_move)?;
+    let balance = check_marks_balanced(board, first_player)?;
+    Ok(both(both(mark, history), both(turn, balance)))
+}
@@ -0,0 +1,141 @@
+//! [`Game`] implementation for tic-tac-toe.
+
+use super::action::{Move, MoveError};
+use super::position::{Position, ValidPositions};
+use super::tablebase::Tablebase;
+use super::typestate::{GameFinished, GameInProgress, GameResult, GameSetup};
+use super::wrapper::AnyGame;
+use crate::games::{Action, Game, GameState, Outcome, Player, Score, Transition};
+use elicitation::ElicitError;
+use rmcp::{Peer, RoleServer};
+use std::future::Future;
+
+/// Marker type for tic-tac-toe.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TicTacToe;
+
+impl Game for TicTacToe {
+    const NAME: &'static str = "tic-tac-toe";
+
+    type Choice = Position;
+    type Action = Move;
+    type Error = MoveError;
+    type Setup = GameSetup;
+    type InProgress = GameInProgress;
+    type Finished = GameFinished;
+    type State = AnyGame;
+
+    fn setup() -> GameSetup {
+        GameSetup::new()
+    }
+
+    fn start(setup: GameSetup, first_player: Player) -> GameInProgress {
+        setup.start(first_player)
+    }
+
+    fn action(player: Player, choice: Position) -> Move {
+        Move::new(player, choice)
+    }
+
+    fn make_move(game: GameInProgress, action: Move) -> Result<Transition<Self>, MoveError> {
+        Ok(game.make_move(action)?.into())
+    }
+
+    fn to_move(game: &GameInProgress) -> Player {
+        game.to_move()
+    }
+
+    fn legal_choices(game: &GameInProgress) -> Vec<Position> {
+        game.valid_moves()
+    }
+
+    fn position_key(game: &GameInProgress) -> Option<u64> {
+        // Mirrored boards have the same value, so share one entry
+        let board = u64::from(game.board().canonical_key());
+        Some(board * 2 + u64::from(game.to_move() == Player::O))
+    }
+
+    fn tablebase_scores(game: &GameInProgress) -> Option<Vec<(Position, Score)>> {
+        Tablebase::shipped().scores(game.board(), game.to_move())
+    }
+
+    fn outcome(game: &GameFinished) -> Outcome {
+        *game.outcome()
+    }
+
+    fn elicit_choice(
+        peer: Peer<RoleServer>,
+        choices: Vec<Position>,
+    ) -> impl Future<Output = Result<Position, ElicitError>> + Send {
+        ValidPositions { positions: choices }.elicit_position(peer)
+    }
+}
+
+impl From<GameResult> for Transition<TicTacToe> {
+    fn from(result: GameResult) -> Self {
+        match result {
+            GameResult::InProgress(g) => Transition::InProgress(g),
+            GameResult::Finished(g) => Transition::Finished(g),
+        }
+    }
+}
+
+impl GameState<TicTacToe> for AnyGame {
+    fn is_over(&self) -> bool {
+        AnyGame::is_over(self)
+    }
+
+    fn to_move(&self) -> Option<Player> {
+        AnyGame::to_move(self)
+    }
+
+    fn winner(&self) -> Option<Player> {
+        AnyGame::winner(self)
+    }
+
+    fn status_string(&self) -> String {
+        AnyGame::status_string(self)
+    }
+
+    fn render(&self) -> String {
+        self.board().display()
+    }
+
+    fn move_count(&self) -> usize {
+        self.history().len()
+    }
+
+    fn legal_choices(&self) -> Vec<Position> {
+        match AnyGame::to_move(self) {
+            Some(_) => Position::valid_moves(self.board()),
+            None => Vec::new(),
+        }
+    }
+
+    fn apply(self, action: Move) -> Result<Self, String> {
+        self.make_move_action(action)
+    }
+
+    fn act(self, action: Action<Move>) -> Result<Self, String> {
+        AnyGame::act(self, action)
+    }
+
+    fn undo(self) -> Result<Self, String> {
+        AnyGame::undo(self)
+    }
+
+    fn draw_offer(&self) -> Option<Player> {
+        AnyGame::draw_offer(self)
+    }
+
+    fn in_progress(&self) -> Option<GameInProgress> {
+        AnyGame::in_progress(self)
+    }
+
+    fn moves(&self) -> Vec<(Player, Position)> {
+        self.move_history()
+            .into_iter()
+            .map(|mov| (mov.player, mov.position))
+            .collect()
+    }
+}
@@ -33,6 +33,8 @@
 
 // Core domain types
 pub mod position;
+pub mod symmetry;
+pub mod tablebase;
 pub mod types;
 
 // Game rules (pure functions)
@@ -46,18 +48,23 @@ pub mod typestate;
 
 // Wrapper for session management
 pub mod wrapper;
+pub mod snapshot;
+
+// Game trait implementation
+pub mod game;
+
+// Kani proof harnesses (`cargo kani`)
+#[cfg(kani)]
+mod proofs;
 
 // Primary API - typestate architecture
 pub use action::{Move, MoveError};
+pub use game::TicTacToe;
 pub use phases::{Finished, InProgress, Outcome, Setup};
 pub use position::{Position, ValidPositions};
+pub use symmetry::Symmetry;
+pub use tablebase::{Tablebase, TablebaseError};
 pub use typestate::{GameSetup, GameInProgress, GameFinished, GameResult};
 pub use types::{Board, Player, Square};
+pub use snapshot::SNAPSHOT_VERSION;
 pub use wrapper::AnyGame;
-
-/// Alias for clarity in session management.
-pub type Mark = Player;
-
-/// Compatibility alias for GameSetup
-pub type Game = GameSetup;
-
@@ -135,7 +135,6 @@ impl Position {
     }
     
     /// Converts position to board index (0-8).
-    #[instrument]
     pub fn to_index(self) -> usize {
         match self {
             Position::TopLeft => 0,
@@ -157,7 +156,6 @@ impl Position {
     }
 
     /// Creates position from board index.
-    #[instrument]
     pub fn from_index(index: usize) -> Option<Self> {
         match index {
             0 => Some(Position::TopLeft),
@@ -191,12 +189,12 @@ impl Position {
     /// This is the key method for dynamic selection: we have a static
     /// enum with all positions, but filter which ones to present based
     /// on runtime board state.
-    #[instrument(skip(board))]
     pub fn valid_moves(board: &Board) -> Vec<Position> {
+        let occupied = board.occupied();
         Self::ALL
             .iter()
             .copied()
-            .filter(|pos| board.is_empty(*pos))
+            .filter(|pos| occupied & (1 << pos.to_index()) == 0)
             .collect()
     }
 }
@@ -0,0 +1,204 @@
+//! Kani proof harnesses for the tic-tac-toe rules and contracts.
+//!
+//! Elicitation proves that every `Position` and `Player` is well-formed.
+//! These harnesses prove what it cannot: that the game logic built on
+//! those types is correct for every reachable game.
+//!
+//! Run with `cargo kani`, or a single harness with
+//! `cargo kani --harness <name>`.
+
+use super::contracts::{execute_move, validate_move, verify_transition};
+use super::rules::{check_winner, is_full};
+use super::rules::win::LINES;
+use super::{
+    AnyGame, Board, GameFinished, GameInProgress, GameResult, GameSetup, Move, Player, Position,
+    Square,
+};
+
+/// Maximum number of moves in a game of tic-tac-toe.
+const MAX_MOVES: usize = 9;
+
+/// Returns an arbitrary position.
+fn any_position() -> Position {
+    let index: usize = kani::any();
+    kani::assume(index < MAX_MOVES);
+    Position::ALL[index]
+}
+
+/// Returns an arbitrary player.
+fn any_player() -> Player {
+    if kani::any() { Player::X } else { Player::O }
+}
+
+/// Returns an arbitrary board, reachable or not.
+fn any_board() -> Board {
+    let mut board = Board::new();
+    for position in Position::ALL {
+        let square = match kani::any::<u8>() % 3 {
+            0 => Square::Empty,
+            1 => Square::Occupied(Player::X),
+            _ => Square::Occupied(Player::O),
+        };
+        board.set(position, square);
+    }
+    board
+}
+
+/// Returns true if the player holds a complete line.
+fn has_line(board: &Board, player: Player) -> bool {
+    let mask = board.mask(player);
+    LINES.into_iter().any(|line| mask & line == line)
+}
+
+/// Plays an arbitrary number of arbitrary legal moves from a fresh game
+/// started by an arbitrary player, stopping early if the game ends.
+fn any_game() -> GameResult {
+    let mut game = GameSetup::new().start(any_player());
+    let moves: usize = kani::any();
+    kani::assume(moves <= MAX_MOVES);
+    for _ in 0..moves {
+        let position = any_position();
+        kani::assume(game.board().is_empty(position));
+        match game.make_move(Move::new(game.to_move(), position)) {
+            Ok(GameResult::InProgress(next)) => game = next,
+            Ok(finished) => return finished,
+            Err(_) => unreachable!("Legal moves are accepted"),
+        }
+    }
+    GameResult::InProgress(game)
+}
+
+/// Plays arbitrary legal moves and returns a game still in progress.
+fn any_game_in_progress() -> GameInProgress {
+    match any_game() {
+        GameResult::InProgress(game) => game,
+        GameResult::Finished(_) => {
+            kani::assume(false);
+            unreachable!()
+        }
+    }
+}
+
+/// `check_winner` only reports a player who holds a line, and reports
+/// nobody when no player does.
+#[kani::proof]
+fn check_winner_reports_a_line_holder() {
+    let board = any_board();
+    match check_winner(&board) {
+        Some(player) => assert!(has_line(&board, player)),
+        None => assert!(!has_line(&board, Player::X) && !has_line(&board, Player::O)),
+    }
+}
+
+/// `is_full` holds exactly when no square is empty.
+#[kani::proof]
+fn is_full_iff_no_empty_square() {
+    let board = any_board();
+    let any_empty = Position::ALL.into_iter().any(|position| board.is_empty(position));
+    assert_eq!(is_full(&board), !any_empty);
+}
+
+/// No board reached through legal play has two winners.
+#[kani::proof]
+#[kani::unwind(10)]
+fn legal_play_never_has_two_winners() {
+    let board = match any_game() {
+        GameResult::InProgress(game) => game.board().clone(),
+        GameResult::Finished(game) => game.board().clone(),
+    };
+    assert!(!(has_line(&board, Player::X) && has_line(&board, Player::O)));
+}
+
+/// `validate_move` accepts a move exactly when the square is empty and
+/// it is the mover's turn.
+#[kani::proof]
+#[kani::unwind(10)]
+fn validate_move_accepts_exactly_legal_moves() {
+    let game = any_game_in_progress();
+    let action = Move::new(any_player(), any_position());
+    let legal = game.board().is_empty(action.position) && action.player == game.to_move();
+    assert_eq!(validate_move(&action, &game).is_ok(), legal);
+}
+
+/// `execute_move` with a proof marks only the target square, and that
+/// square was empty.
+#[kani::proof]
+#[kani::unwind(10)]
+fn execute_move_never_overwrites() {
+    let mut game = any_game_in_progress();
+    let action = Move::new(any_player(), any_position());
+    let Ok(proof) = validate_move(&action, &game) else {
+        return;
+    };
+    let before = game.board().clone();
+    execute_move(&action, &mut game, proof);
+
+    assert_eq!(before.get(action.position), Square::Empty);
+    for position in Position::ALL {
+        if position == action.position {
+            assert_eq!(game.board().get(position), Square::Occupied(action.player));
+        } else {
+            assert_eq!(game.board().get(position), before.get(position));
+        }
+    }
+}
+
+/// Every legal `make_move` satisfies the move postconditions.
+#[kani::proof]
+#[kani::unwind(10)]
+fn make_move_satisfies_postconditions() {
+    let game = any_game_in_progress();
+    let position = any_position();
+    kani::assume(game.board().is_empty(position));
+    let action = Move::new(game.to_move(), position);
+    let result = game.clone().make_move(action).expect("Legal move");
+    assert!(verify_transition(&action, &game, &result).is_ok());
+}
+
+/// `replay` of a legal move sequence agrees with incremental play.
+#[kani::proof]
+#[kani::unwind(10)]
+fn replay_agrees_with_incremental_play() {
+    let incremental = any_game();
+    let history = match &incremental {
+        GameResult::InProgress(game) => game.history().to_vec(),
+        GameResult::Finished(game) => game.history().to_vec(),
+    };
+    match (incremental, GameInProgress::replay(&history).expect("Legal history")) {
+        (GameResult::InProgress(played), GameResult::InProgress(replayed)) => {
+            assert_eq!(played.board(), replayed.board());
+            // An empty history doesn't say who opens; replay starts with X
+            if !history.is_empty() {
+                assert_eq!(played.to_move(), replayed.to_move());
+            }
+        }
+        (GameResult::Finished(played), GameResult::Finished(replayed)) => {
+            assert_eq!(played.board(), replayed.board());
+            assert_eq!(played.outcome(), replayed.outcome());
+        }
+        _ => panic!("Replay ended in a different phase"),
+    }
+}
+
+/// Every reachable game survives the round trip through [`AnyGame`]
+/// without a replay.
+#[kani::proof]
+#[kani::unwind(10)]
+fn snapshot_round_trip_preserves_game() {
+    match any_game() {
+        GameResult::InProgress(game) => {
+            let restored = GameInProgress::try_from(AnyGame::from(game.clone()))
+                .expect("Reachable snapshot");
+            assert_eq!(restored.board(), game.board());
+            assert_eq!(restored.to_move(), game.to_move());
+            assert_eq!(restored.history(), game.history());
+        }
+        GameResult::Finished(game) => {
+            let restored = GameFinished::try_from(AnyGame::from(game.clone()))
+                .expect("Reachable snapshot");
+            assert_eq!(restored.board(), game.board());
+            assert_eq!(restored.outcome(), game.outcome());
+            assert_eq!(restored.history(), game.history());
+        }
+    }
+}
@@ -1,20 +1,18 @@
 //! Draw detection logic for tic-tac-toe.
 
-use super::super::{Board, Square};
-use tracing::instrument;
+use super::super::Board;
 
 /// Checks if the board is full (all squares occupied).
 ///
 /// A full board with no winner indicates a draw.
-#[instrument]
 pub fn is_full(board: &Board) -> bool {
-    board.squares().iter().all(|s| *s != Square::Empty)
+    board.occupied() == Board::FULL
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::super::{Player, Position};
+    use super::super::super::{Player, Position, Square};
     use super::super::win::check_winner;
 
     fn is_draw(board: &Board) -> bool {
@@ -1,44 +1,37 @@
 //! Win detection logic for tic-tac-toe.
 
-use super::super::{Board, Player, Position, Square};
-use tracing::instrument;
+use super::super::{Board, Player};
+
+/// The 8 winning lines as board masks (bit `i` is position index `i`).
+pub const LINES: [u16; 8] = [
+    // Rows
+    0b000_000_111,
+    0b000_111_000,
+    0b111_000_000,
+    // Columns
+    0b001_001_001,
+    0b010_010_010,
+    0b100_100_100,
+    // Diagonals
+    0b100_010_001,
+    0b001_010_100,
+];
 
 /// Checks if there is a winner on the board.
 ///
 /// Returns `Some(player)` if the player has three in a row,
 /// `None` otherwise.
-#[instrument]
 pub fn check_winner(board: &Board) -> Option<Player> {
-    const LINES: [[Position; 3]; 8] = [
-        // Rows
-        [Position::TopLeft, Position::TopCenter, Position::TopRight],
-        [Position::MiddleLeft, Position::Center, Position::MiddleRight],
-        [Position::BottomLeft, Position::BottomCenter, Position::BottomRight],
-        // Columns
-        [Position::TopLeft, Position::MiddleLeft, Position::BottomLeft],
-        [Position::TopCenter, Position::Center, Position::BottomCenter],
-        [Position::TopRight, Position::MiddleRight, Position::BottomRight],
-        // Diagonals
-        [Position::TopLeft, Position::Center, Position::BottomRight],
-        [Position::TopRight, Position::Center, Position::BottomLeft],
-    ];
-
-    for [a, b, c] in LINES {
-        let sq = board.get(a);
-        if sq != Square::Empty && sq == board.get(b) && sq == board.get(c) {
-            return match sq {
-                Square::Occupied(player) => Some(player),
-                Square::Empty => None,
-            };
-        }
-    }
-
-    None
+    [Player::X, Player::O].into_iter().find(|&player| {
+        let mask = board.mask(player);
+        LINES.into_iter().any(|line| mask & line == line)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::super::{Position, Square};
 
     #[test]
     fn test_no_winner_empty_board() {
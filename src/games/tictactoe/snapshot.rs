@@ -0,0 +1,219 @@
+//! Versioned wire format for [`AnyGame`].
+//!
+//! Games are written as a flat object tagged with the format `version`
+//! and the game `phase`:
+//!
+//! ```json
+//! {"version": 2, "phase": "in-progress", "board": {...}, "to_move": "O",
+//!  "history": [{"player": "X", "position": "Center"}]}
+//! ```
+//!
+//! Snapshots written before the format was versioned (version 1) are
+//! still accepted and upgraded on read: the `Won` and `Draw` variants
+//! become `finished`, and histories stored as bare positions get their
+//! players back.
+
+use super::action::Move;
+use super::phases::Outcome;
+use super::position::Position;
+use super::types::{Board, Player};
+use super::wrapper::AnyGame;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Version of the game format written by this build.
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+/// A game as written to the wire.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "TicTacToeGame")]
+pub(super) struct Snapshot {
+    /// Format version, currently 2.
+    version: u32,
+    /// The game phase and its state.
+    #[serde(flatten)]
+    phase: Phase,
+}
+
+/// Game phase, tagged by `phase`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "phase", rename_all = "kebab-case")]
+enum Phase {
+    /// Game not started yet.
+    Setup {
+        /// The board state.
+        board: Board,
+    },
+    /// Game in progress.
+    InProgress {
+        /// The board state.
+        board: Board,
+        /// Current player to move.
+        to_move: Player,
+        /// Moves played so far, oldest first.
+        history: Vec<Move>,
+        /// Player whose draw offer awaits an answer.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        draw_offer: Option<Player>,
+    },
+    /// Game over.
+    Finished {
+        /// The board state.
+        board: Board,
+        /// How the game ended.
+        outcome: Outcome,
+        /// Moves played, oldest first.
+        history: Vec<Move>,
+    },
+}
+
+/// Any game format this build can read.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(super) enum AnyVersion {
+    /// Versioned format.
+    Current(Snapshot),
+    /// Unversioned format (version 1).
+    Legacy(Legacy),
+}
+
+/// Version 1: `AnyGame` as serde's default externally tagged enum.
+#[derive(Deserialize)]
+pub(super) enum Legacy {
+    Setup {
+        board: Board,
+    },
+    InProgress {
+        board: Board,
+        to_move: Player,
+        history: LegacyHistory,
+        #[serde(default)]
+        draw_offer: Option<Player>,
+    },
+    Won {
+        board: Board,
+        winner: Player,
+        history: Vec<Position>,
+    },
+    Draw {
+        board: Board,
+        history: Vec<Position>,
+    },
+    Finished {
+        board: Board,
+        outcome: Outcome,
+        history: Vec<Move>,
+    },
+}
+
+/// Version 1 in-progress history, with or without players.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(super) enum LegacyHistory {
+    Moves(Vec<Move>),
+    Positions(Vec<Position>),
+}
+
+impl From<AnyGame> for Snapshot {
+    fn from(game: AnyGame) -> Self {
+        let phase = match game {
+            AnyGame::Setup { board } => Phase::Setup { board },
+            AnyGame::InProgress { board, to_move, history, draw_offer } => {
+                Phase::InProgress { board, to_move, history, draw_offer }
+            }
+            AnyGame::Finished { board, outcome, history } => {
+                Phase::Finished { board, outcome, history }
+            }
+        };
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            phase,
+        }
+    }
+}
+
+impl TryFrom<AnyVersion> for AnyGame {
+    type Error = String;
+
+    fn try_from(wire: AnyVersion) -> Result<Self, String> {
+        match wire {
+            AnyVersion::Current(snapshot) if snapshot.version == SNAPSHOT_VERSION => {
+                Ok(snapshot.phase.into())
+            }
+            AnyVersion::Current(snapshot) => Err(format!(
+                "Unsupported game format version {} (expected {})",
+                snapshot.version, SNAPSHOT_VERSION
+            )),
+            AnyVersion::Legacy(legacy) => {
+                debug!("Upgrading unversioned game snapshot");
+                Ok(legacy.into())
+            }
+        }
+    }
+}
+
+impl From<Phase> for AnyGame {
+    fn from(phase: Phase) -> Self {
+        match phase {
+            Phase::Setup { board } => AnyGame::Setup { board },
+            Phase::InProgress { board, to_move, history, draw_offer } => {
+                AnyGame::InProgress { board, to_move, history, draw_offer }
+            }
+            Phase::Finished { board, outcome, history } => {
+                AnyGame::Finished { board, outcome, history }
+            }
+        }
+    }
+}
+
+impl From<Legacy> for AnyGame {
+    fn from(legacy: Legacy) -> Self {
+        match legacy {
+            Legacy::Setup { board } => AnyGame::Setup { board },
+            Legacy::InProgress { board, to_move, history, draw_offer } => {
+                let history = match history {
+                    LegacyHistory::Moves(moves) => moves,
+                    LegacyHistory::Positions(positions) => {
+                        // Players alternate, so the first one follows from
+                        // who is to move now
+                        let first = if positions.len() % 2 == 0 {
+                            to_move
+                        } else {
+                            to_move.opponent()
+                        };
+                        alternate(first, &positions)
+                    }
+                };
+                AnyGame::InProgress { board, to_move, history, draw_offer }
+            }
+            // Finished games without players were always started by X
+            Legacy::Won { board, winner, history } => AnyGame::Finished {
+                board,
+                outcome: Outcome::Winner(winner),
+                history: alternate(Player::X, &history),
+            },
+            Legacy::Draw { board, history } => AnyGame::Finished {
+                board,
+                outcome: Outcome::Draw,
+                history: alternate(Player::X, &history),
+            },
+            Legacy::Finished { board, outcome, history } => {
+                AnyGame::Finished { board, outcome, history }
+            }
+        }
+    }
+}
+
+/// Pairs positions with players, alternating from `first`.
+fn alternate(first: Player, positions: &[Position]) -> Vec<Move> {
+    let mut player = first;
+    positions
+        .iter()
+        .map(|&position| {
+            let mov = Move::new(player, position);
+            player = player.opponent();
+            mov
+        })
+        .collect()
+}
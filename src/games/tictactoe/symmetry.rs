@@ -0,0 +1,133 @@
+//! Symmetries of the tic-tac-toe board (the dihedral group D4).
+//!
+//! The board looks the same under 4 rotations and 4 reflections, so
+//! positions related by a symmetry have the same game-theoretic value.
+//! [`Board::canonical`] picks one representative per class, which lets
+//! searches and statistics treat mirrored openings as one position.
+
+use super::{Board, Player, Position, Square};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+/// One of the 8 symmetries of a square board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display)]
+pub enum Symmetry {
+    /// Leaves the board unchanged.
+    Identity,
+    /// Quarter turn clockwise.
+    Rotate90,
+    /// Half turn.
+    Rotate180,
+    /// Quarter turn counter-clockwise.
+    Rotate270,
+    /// Mirror left to right.
+    FlipHorizontal,
+    /// Mirror top to bottom.
+    FlipVertical,
+    /// Mirror across the top-left to bottom-right diagonal.
+    FlipDiagonal,
+    /// Mirror across the top-right to bottom-left diagonal.
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    /// Every symmetry, identity first.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+
+    /// Returns the symmetry that undoes this one.
+    pub fn inverse(self) -> Self {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            // Every other element is its own inverse
+            other => other,
+        }
+    }
+
+    /// Maps a `(row, column)` cell to its image.
+    fn map_cell(self, row: usize, column: usize) -> (usize, usize) {
+        match self {
+            Symmetry::Identity => (row, column),
+            Symmetry::Rotate90 => (column, 2 - row),
+            Symmetry::Rotate180 => (2 - row, 2 - column),
+            Symmetry::Rotate270 => (2 - column, row),
+            Symmetry::FlipHorizontal => (row, 2 - column),
+            Symmetry::FlipVertical => (2 - row, column),
+            Symmetry::FlipDiagonal => (column, row),
+            Symmetry::FlipAntiDiagonal => (2 - column, 2 - row),
+        }
+    }
+}
+
+impl Position {
+    /// Maps this position through a symmetry.
+    pub fn transform(self, symmetry: Symmetry) -> Position {
+        let index = self.to_index();
+        let (row, column) = symmetry.map_cell(index / 3, index % 3);
+        Position::from_index(row * 3 + column).expect("Symmetries map cells onto the board")
+    }
+}
+
+impl Board {
+    /// Returns the image of this board under a symmetry.
+    pub fn transform(&self, symmetry: Symmetry) -> Board {
+        let mut board = Board::new();
+        for position in Position::ALL {
+            board.set(position.transform(symmetry), self.get(position));
+        }
+        board
+    }
+
+    /// Returns the board under every symmetry, in [`Symmetry::ALL`] order.
+    pub fn symmetries(&self) -> [Board; 8] {
+        Symmetry::ALL.map(|symmetry| self.transform(symmetry))
+    }
+
+    /// Encodes the board as a base-3 number (empty 0, X 1, O 2).
+    ///
+    /// Distinct boards always have distinct keys.
+    pub fn key(&self) -> u32 {
+        self.squares().iter().fold(0, |key, square| {
+            key * 3
+                + match square {
+                    Square::Empty => 0,
+                    Square::Occupied(Player::X) => 1,
+                    Square::Occupied(Player::O) => 2,
+                }
+        })
+    }
+
+    /// Returns the canonical representative of this board's symmetry class
+    /// and the symmetry that maps this board onto it.
+    ///
+    /// The representative is the image with the smallest [`Board::key`];
+    /// boards related by a symmetry share it. Map a position on this board
+    /// to the canonical board with `position.transform(symmetry)` and back
+    /// with `symmetry.inverse()`.
+    #[instrument]
+    pub fn canonical(&self) -> (Board, Symmetry) {
+        Symmetry::ALL
+            .into_iter()
+            .map(|symmetry| (self.transform(symmetry), symmetry))
+            .min_by_key(|(board, _)| board.key())
+            .expect("There is always the identity")
+    }
+
+    /// Returns the key of the canonical board (see [`Board::canonical`]).
+    pub fn canonical_key(&self) -> u32 {
+        self.symmetries()
+            .iter()
+            .map(Board::key)
+            .min()
+            .expect("There is always the identity")
+    }
+}
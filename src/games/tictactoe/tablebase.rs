@@ -0,0 +1,246 @@
+//! Precomputed perfect-play table for tic-tac-toe.
+//!
+//! Tic-tac-toe is small enough to solve outright: [`Tablebase::generate`]
+//! walks every position reachable from an empty board, with either player
+//! moving first, and records the perfect-play value of each legal move.
+//! Mirrored and rotated positions share one entry, stored in the
+//! orientation of the canonical board (see [`Board::canonical`]).
+//!
+//! The result ships with the crate as `data/tictactoe.tablebase` and is
+//! loaded once by [`Tablebase::shipped`], so the solver looks values up
+//! instead of searching (see [`Game::tablebase_scores`]). Regenerate it
+//! with `strictly_games tablebase`.
+//!
+//! # File format
+//!
+//! All integers are little-endian.
+//!
+//! - magic `TTTB`, format version (`u8`), entry count (`u32`)
+//! - per entry, sorted by key: the position key (`u32`, see
+//!   [`Tablebase::key`]) and one `i8` per square of the canonical board:
+//!   `d` for a win in `d` plies, `-d` for a loss in `d` plies, `0` for a
+//!   draw and `i8::MIN` for an occupied square
+//!
+//! [`Game::tablebase_scores`]: crate::games::Game::tablebase_scores
+
+use super::{Board, GameInProgress, Player, Position, TicTacToe};
+use crate::games::{Game, Score, Transition, WIN_SCORE};
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use tracing::{debug, info, instrument};
+
+/// Leading bytes of a tablebase file.
+const MAGIC: &[u8; 4] = b"TTTB";
+
+/// Current file format version.
+const VERSION: u8 = 1;
+
+/// Marks an occupied square in an entry.
+const ILLEGAL: i8 = i8::MIN;
+
+/// Bytes per entry: key plus one value per square.
+const ENTRY_LEN: usize = 4 + 9;
+
+/// The tablebase shipped with the crate.
+static SHIPPED: LazyLock<Tablebase> = LazyLock::new(|| {
+    Tablebase::from_bytes(include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/data/tictactoe.tablebase"
+    )))
+    .expect("Shipped tablebase is valid")
+});
+
+/// Error reading a tablebase file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum TablebaseError {
+    /// The file doesn't start with the tablebase magic bytes.
+    #[display("Not a tic-tac-toe tablebase")]
+    BadMagic,
+
+    /// The file was written by an unknown format version.
+    #[display("Unsupported tablebase version {}", _0)]
+    UnsupportedVersion(u8),
+
+    /// The file is shorter or longer than its header says.
+    #[display("Tablebase is truncated or has trailing bytes")]
+    BadLength,
+}
+
+impl std::error::Error for TablebaseError {}
+
+/// Perfect-play values of every legal move in every reachable position.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Tablebase {
+    /// Per-square move values, by position key.
+    entries: BTreeMap<u32, [i8; 9]>,
+}
+
+impl Tablebase {
+    /// Returns the tablebase shipped with the crate.
+    pub fn shipped() -> &'static Tablebase {
+        &SHIPPED
+    }
+
+    /// Solves every position reachable from an empty board.
+    #[instrument]
+    pub fn generate() -> Self {
+        let mut tablebase = Self::default();
+        for first_player in [Player::X, Player::O] {
+            tablebase.solve(&TicTacToe::new_game(first_player));
+        }
+        info!(positions = tablebase.len(), "Generated tablebase");
+        tablebase
+    }
+
+    /// Returns the key of a position: the canonical board's key, doubled,
+    /// plus one if O is to move.
+    pub fn key(board: &Board, to_move: Player) -> u32 {
+        board.canonical_key() * 2 + u32::from(to_move == Player::O)
+    }
+
+    /// Returns the number of positions in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the table has no positions.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the solver score of every legal move, or `None` if the
+    /// position is not in the table.
+    ///
+    /// Scores match [`Solver::score_choices`](crate::games::Solver::score_choices)
+    /// with perfect play.
+    pub fn scores(&self, board: &Board, to_move: Player) -> Option<Vec<(Position, Score)>> {
+        let (canonical, symmetry) = board.canonical();
+        let values = self.entries.get(&Self::key(&canonical, to_move))?;
+        Some(
+            Position::ALL
+                .into_iter()
+                .map(|position| (position, values[position.transform(symmetry).to_index()]))
+                .filter(|&(_, value)| value != ILLEGAL)
+                .map(|(position, value)| (position, to_score(value)))
+                .collect(),
+        )
+    }
+
+    /// Returns the perfect-play value of the position for the player to move.
+    pub fn value(&self, board: &Board, to_move: Player) -> Option<Score> {
+        self.scores(board, to_move)?.into_iter().map(|(_, score)| score).max()
+    }
+
+    /// Returns every move that keeps the perfect-play value.
+    pub fn best_moves(&self, board: &Board, to_move: Player) -> Option<Vec<Position>> {
+        let scores = self.scores(board, to_move)?;
+        let best = scores.iter().map(|&(_, score)| score).max()?;
+        Some(
+            scores
+                .into_iter()
+                .filter(|&(_, score)| score == best)
+                .map(|(position, _)| position)
+                .collect(),
+        )
+    }
+
+    /// Serializes the table in the file format described in the module docs.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(9 + self.entries.len() * ENTRY_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(self.entries.len() as u32).to_le_bytes());
+        for (key, values) in &self.entries {
+            bytes.extend_from_slice(&key.to_le_bytes());
+            bytes.extend(values.iter().map(|&value| value as u8));
+        }
+        bytes
+    }
+
+    /// Parses a table written by [`Tablebase::to_bytes`].
+    #[instrument(skip(bytes), fields(len = bytes.len()))]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TablebaseError> {
+        let (magic, rest) = bytes.split_at_checked(4).ok_or(TablebaseError::BadMagic)?;
+        if magic != MAGIC {
+            return Err(TablebaseError::BadMagic);
+        }
+        let (&version, rest) = rest.split_first().ok_or(TablebaseError::BadLength)?;
+        if version != VERSION {
+            return Err(TablebaseError::UnsupportedVersion(version));
+        }
+        let (count, rest) = rest.split_at_checked(4).ok_or(TablebaseError::BadLength)?;
+        let count = u32::from_le_bytes(count.try_into().expect("Four bytes")) as usize;
+        if rest.len() != count * ENTRY_LEN {
+            return Err(TablebaseError::BadLength);
+        }
+
+        let entries = rest
+            .chunks_exact(ENTRY_LEN)
+            .map(|entry| {
+                let key = u32::from_le_bytes(entry[..4].try_into().expect("Four bytes"));
+                let values = std::array::from_fn(|index| entry[4 + index] as i8);
+                (key, values)
+            })
+            .collect();
+
+        debug!(count, "Loaded tablebase");
+        Ok(Self { entries })
+    }
+
+    /// Solves a position and everything reachable from it, returning its value.
+    fn solve(&mut self, game: &GameInProgress) -> i8 {
+        let (canonical, symmetry) = game.board().canonical();
+        let key = Self::key(&canonical, game.to_move());
+        if let Some(values) = self.entries.get(&key) {
+            return best_value(values);
+        }
+
+        let mover = game.to_move();
+        let mut values = [ILLEGAL; 9];
+        for position in TicTacToe::legal_choices(game) {
+            let action = TicTacToe::action(mover, position);
+            let transition = TicTacToe::make_move(game.clone(), action)
+                .expect("Legal choices pass the contracts");
+            values[position.to_index()] = match transition {
+                Transition::Finished(finished) => match TicTacToe::outcome(&finished).winner() {
+                    Some(winner) if winner == mover => 1,
+                    Some(_) => -1,
+                    None => 0,
+                },
+                // The reply's value, seen from this side and one ply further away
+                Transition::InProgress(next) => match self.solve(&next) {
+                    0 => 0,
+                    reply if reply > 0 => -(reply + 1),
+                    reply => -reply + 1,
+                },
+            };
+        }
+
+        let mut canonical_values = [ILLEGAL; 9];
+        for position in Position::ALL {
+            canonical_values[position.transform(symmetry).to_index()] = values[position.to_index()];
+        }
+        self.entries.insert(key, canonical_values);
+        best_value(&values)
+    }
+}
+
+/// Converts a stored move value into a solver score.
+fn to_score(value: i8) -> Score {
+    let plies = Score::from(value.unsigned_abs());
+    match value {
+        0 => 0,
+        v if v > 0 => WIN_SCORE - (plies - 1),
+        _ => -(WIN_SCORE - (plies - 1)),
+    }
+}
+
+/// Returns the best stored value among a position's legal moves.
+fn best_value(values: &[i8; 9]) -> i8 {
+    values
+        .iter()
+        .copied()
+        .filter(|&value| value != ILLEGAL)
+        .max_by_key(|&value| to_score(value))
+        .unwrap_or(0)
+}
@@ -1,31 +1,16 @@
 //! Core domain types for tic-tac-toe.
 
 use elicitation::{Elicit, Prompt, Select};
+use schemars::{JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use tracing::instrument;
 
-/// Player in the game.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Elicit)]
-pub enum Player {
-    /// Player X (goes first).
-    X,
-    /// Player O (goes second).
-    O,
-}
-
-impl Player {
-    /// Returns the opponent player.
-    #[instrument]
-    pub fn opponent(self) -> Self {
-        match self {
-            Player::X => Player::O,
-            Player::O => Player::X,
-        }
-    }
-}
+/// Player mark (shared across all games).
+pub use crate::games::player::Player;
 
 /// A square on the tic-tac-toe board.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Elicit)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Elicit)]
 pub enum Square {
     /// Empty square.
     Empty,
@@ -34,45 +19,125 @@ pub enum Square {
 }
 
 /// 3x3 tic-tac-toe board.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Elicit)]
+///
+/// Backed by one 9-bit mask per player (bit `i` is [`Position`] index
+/// `i`), so win detection is a few integer ops. The squares are kept
+/// alongside the masks, so [`squares`](Self::squares) still hands out a
+/// reference. Serializes as the list of squares, same as the array-backed
+/// board did.
+///
+/// [`Position`]: super::Position
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Elicit)]
+#[serde(from = "BoardRepr", into = "BoardRepr")]
 pub struct Board {
+    /// Squares in row-major order (0-8), in step with the masks.
+    squares: [Square; 9],
+    /// Squares held by X.
+    x: u16,
+    /// Squares held by O.
+    o: u16,
+}
+
+/// Serialized form of [`Board`].
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[schemars(rename = "Board")]
+struct BoardRepr {
     /// Squares in row-major order (0-8).
     squares: [Square; 9],
 }
 
+impl From<BoardRepr> for Board {
+    fn from(repr: BoardRepr) -> Self {
+        let mut board = Board::new();
+        for (index, square) in repr.squares.into_iter().enumerate() {
+            board.set_index(index, square);
+        }
+        board
+    }
+}
+
+impl From<Board> for BoardRepr {
+    fn from(board: Board) -> Self {
+        Self {
+            squares: board.squares,
+        }
+    }
+}
+
+impl JsonSchema for Board {
+    fn schema_name() -> Cow<'static, str> {
+        BoardRepr::schema_name()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        BoardRepr::json_schema(generator)
+    }
+}
+
 impl Board {
+    /// Mask with every square set.
+    pub const FULL: u16 = 0b1_1111_1111;
+
     /// Creates a new empty board.
-    #[instrument]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             squares: [Square::Empty; 9],
+            x: 0,
+            o: 0,
         }
     }
 
     /// Gets the square at the given position.
-    #[instrument]
     pub fn get(&self, pos: super::Position) -> Square {
-        self.squares[pos.to_index()]
+        self.get_index(pos.to_index())
     }
 
     /// Sets the square at the given position.
-    #[instrument]
     pub fn set(&mut self, pos: super::Position, square: Square) {
-        self.squares[pos.to_index()] = square;
+        self.set_index(pos.to_index(), square);
     }
 
     /// Checks if a square is empty.
-    #[instrument]
     pub fn is_empty(&self, pos: super::Position) -> bool {
-        matches!(self.get(pos), Square::Empty)
+        self.occupied() & (1 << pos.to_index()) == 0
     }
 
-    /// Returns all squares as a slice.
-    #[instrument]
+    /// Returns all squares in row-major order (0-8).
     pub fn squares(&self) -> &[Square; 9] {
         &self.squares
     }
 
+    /// Returns the mask of squares held by a player.
+    pub fn mask(&self, player: Player) -> u16 {
+        match player {
+            Player::X => self.x,
+            Player::O => self.o,
+        }
+    }
+
+    /// Returns the mask of occupied squares.
+    pub fn occupied(&self) -> u16 {
+        self.x | self.o
+    }
+
+    /// Gets the square at a board index (0-8).
+    fn get_index(&self, index: usize) -> Square {
+        self.squares[index]
+    }
+
+    /// Sets the square at a board index (0-8).
+    fn set_index(&mut self, index: usize, square: Square) {
+        self.squares[index] = square;
+        let bit = 1 << index;
+        self.x &= !bit;
+        self.o &= !bit;
+        match square {
+            Square::Empty => {}
+            Square::Occupied(Player::X) => self.x |= bit,
+            Square::Occupied(Player::O) => self.o |= bit,
+        }
+    }
+
     /// Formats the board as a human-readable string.
     #[instrument]
     pub fn display(&self) -> String {
@@ -81,7 +146,7 @@ impl Board {
         for row in 0..3 {
             for col in 0..3 {
                 let pos = Position::from_index(row * 3 + col).unwrap();
-                let symbol = match self.squares[pos.to_index()] {
+                let symbol = match self.get(pos) {
                     Square::Empty => (pos.to_index() + 1).to_string(),
                     Square::Occupied(Player::X) => "X".to_string(),
                     Square::Occupied(Player::O) => "O".to_string(),
@@ -99,6 +164,12 @@ impl Board {
     }
 }
 
+impl std::fmt::Debug for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Board").field("squares", &self.squares).finish()
+    }
+}
+
 impl Default for Board {
     fn default() -> Self {
         Self::new()
@@ -6,8 +6,12 @@
 
 use super::action::{Move, MoveError};
 use super::contracts::{validate_move, execute_move};
+#[cfg(any(debug_assertions, feature = "postconditions"))]
+use super::contracts::verify_transition;
 use super::phases::Outcome;
-use super::{Board, Player, Position};
+use super::{Board, Player, Position, Square};
+use crate::games::Action;
+use crate::games::contracts::{validate_draw_answer, validate_draw_offer};
 use tracing::instrument;
 
 // ─────────────────────────────────────────────────────────────
@@ -44,6 +48,7 @@ impl GameSetup {
             board: self.board,
             history: Vec::new(),
             to_move: first_player,
+            draw_offer: None,
         }
     }
 }
@@ -68,6 +73,7 @@ pub struct GameInProgress {
     pub(super) board: Board,
     pub(super) history: Vec<Move>,
     pub(super) to_move: Player,
+    pub(super) draw_offer: Option<Player>,
 }
 
 impl GameInProgress {
@@ -79,39 +85,100 @@ impl GameInProgress {
     /// - Establishes proof of LegalMove (square empty AND player turn)
     /// - Executes move with proof (zero-cost guarantee)
     /// - Type system enforces validation happened
+    /// - Postconditions are verified in debug builds (or with the
+    ///   `postconditions` feature)
     #[instrument(skip(self))]
     pub fn make_move(self, action: Move) -> Result<GameResult, MoveError> {
         // Establish proof that preconditions hold
         let proof = validate_move(&action, &self)?;
-        
+
+        #[cfg(any(debug_assertions, feature = "postconditions"))]
+        let before = self.clone();
+
         // Execute with proof (zero-cost, enforced by type system)
         let mut game = self;
         execute_move(&action, &mut game, proof);
-        
-        // Check for winner using rules module
-        if let Some(winner) = super::rules::check_winner(&game.board) {
-            return Ok(GameResult::Finished(GameFinished {
+        // Playing on declines the opponent's draw offer
+        game.draw_offer = game.draw_offer.filter(|&offerer| offerer == action.player);
+
+        // Check for winner, then draw, using rules module
+        let result = if let Some(winner) = super::rules::check_winner(&game.board) {
+            GameResult::Finished(GameFinished {
                 board: game.board,
                 history: game.history,
                 outcome: Outcome::Winner(winner),
-            }));
-        }
-        
-        // Check for draw using rules module
-        if super::rules::is_full(&game.board) {
-            return Ok(GameResult::Finished(GameFinished {
+            })
+        } else if super::rules::is_full(&game.board) {
+            GameResult::Finished(GameFinished {
                 board: game.board,
                 history: game.history,
                 outcome: Outcome::Draw,
-            }));
+            })
+        } else {
+            // Continue game
+            game.to_move = game.to_move.opponent();
+            GameResult::InProgress(game)
+        };
+
+        // Postconditions catch bugs in the rules above, not bad moves
+        #[cfg(any(debug_assertions, feature = "postconditions"))]
+        verify_transition(&action, &before, &result)?;
+
+        Ok(result)
+    }
+    
+    /// Takes back the last move (consumes self, returns in-progress).
+    ///
+    /// The square is emptied and the player who made the move is to
+    /// move again.
+    #[instrument(skip(self))]
+    pub fn undo(self) -> Result<GameInProgress, MoveError> {
+        take_back(self.board, self.history)
+    }
+    
+    /// Applies any domain action, consuming self.
+    ///
+    /// Placements go through [`make_move`](Self::make_move). Resigning,
+    /// timing out or accepting a draw finishes the game; a draw offer
+    /// waits for the opponent's answer, and the opponent placing a piece
+    /// declines it.
+    #[instrument(skip(self))]
+    pub fn apply(self, action: Action<Move>) -> Result<GameResult, MoveError> {
+        let mut game = self;
+        match action {
+            Action::Place(mov) => return game.make_move(mov),
+            Action::Resign(player) => return Ok(game.finish(Outcome::Resigned(player))),
+            Action::TimeOut(player) => return Ok(game.finish(Outcome::TimedOut(player))),
+            Action::OfferDraw(player) => {
+                let _proof = validate_draw_offer(game.draw_offer)?;
+                game.draw_offer = Some(player);
+            }
+            Action::AcceptDraw(player) => {
+                let _proof = validate_draw_answer(player, game.draw_offer)?;
+                return Ok(game.finish(Outcome::AgreedDraw));
+            }
+            Action::DeclineDraw(player) => {
+                let _proof = validate_draw_answer(player, game.draw_offer)?;
+                game.draw_offer = None;
+            }
         }
-        
-        // Continue game
-        game.to_move = game.to_move.opponent();
-        
         Ok(GameResult::InProgress(game))
     }
     
+    /// Ends the game off the board with the given outcome.
+    fn finish(self, outcome: Outcome) -> GameResult {
+        GameResult::Finished(GameFinished {
+            board: self.board,
+            history: self.history,
+            outcome,
+        })
+    }
+    
+    /// Returns the player whose draw offer awaits an answer, if any.
+    pub fn draw_offer(&self) -> Option<Player> {
+        self.draw_offer
+    }
+    
     /// Returns the current player to move.
     pub fn to_move(&self) -> Player {
         self.to_move
@@ -134,9 +201,12 @@ impl GameInProgress {
     }
     
     /// Replays moves from initial state.
+    ///
+    /// The player of the first move starts (X for an empty history).
     #[instrument]
     pub fn replay(moves: &[Move]) -> Result<GameResult, MoveError> {
-        let mut game = GameSetup::new().start(Player::X);
+        let first_player = moves.first().map_or(Player::X, |m| m.player);
+        let mut game = GameSetup::new().start(first_player);
         
         for action in moves {
             match game.make_move(*action)? {
@@ -159,9 +229,9 @@ impl GameInProgress {
 /// This struct encodes the invariant at the type level.
 #[derive(Debug, Clone)]
 pub struct GameFinished {
-    board: Board,
-    history: Vec<Move>,
-    outcome: Outcome,  // ✅ NOT Option
+    pub(super) board: Board,
+    pub(super) history: Vec<Move>,
+    pub(super) outcome: Outcome,  // ✅ NOT Option
 }
 
 impl GameFinished {
@@ -182,6 +252,18 @@ impl GameFinished {
         &self.history
     }
     
+    /// Takes back the final move (consumes finished, returns in-progress).
+    ///
+    /// Games decided off the board (resignation, agreed draw or timeout)
+    /// are final.
+    #[instrument(skip(self))]
+    pub fn undo(self) -> Result<GameInProgress, MoveError> {
+        if self.outcome.is_off_board() {
+            return Err(MoveError::GameOver);
+        }
+        take_back(self.board, self.history)
+    }
+    
     /// Restarts the game (consumes finished, returns setup).
     #[instrument(skip(self))]
     pub fn restart(self) -> GameSetup {
@@ -189,6 +271,18 @@ impl GameFinished {
     }
 }
 
+/// Removes the last move from the board and history.
+fn take_back(mut board: Board, mut history: Vec<Move>) -> Result<GameInProgress, MoveError> {
+    let last = history.pop().ok_or(MoveError::NothingToUndo)?;
+    board.set(last.position, Square::Empty);
+    Ok(GameInProgress {
+        board,
+        history,
+        to_move: last.player,
+        draw_offer: None,
+    })
+}
+
 // ─────────────────────────────────────────────────────────────
 //  Result Type
 // ─────────────────────────────────────────────────────────────
@@ -2,19 +2,28 @@
 
 use super::typestate::{GameSetup, GameInProgress, GameFinished, GameResult};
 use super::phases::Outcome;
-use super::action::Move;
+use super::action::{Move, MoveError};
 use super::position::Position;
-use super::types::{Board, Player};
+use super::snapshot::Snapshot;
+use super::types::{Board, Player, Square};
+use crate::games::Action;
+use schemars::{JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use tracing::{debug, warn, instrument};
 
 /// Serializable wrapper for Game<S> in any phase.
 ///
 /// Since typestate phases can't be directly serialized,
 /// we use this enum to wrap all possible phases.
+///
+/// Serializes in the versioned format described in
+/// [`snapshot`](super::snapshot); older unversioned snapshots are
+/// upgraded when read.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "Snapshot", try_from = "super::snapshot::AnyVersion")]
 pub enum AnyGame {
-    /// Game in setup phase (new architecture).
+    /// Game in setup phase.
     Setup {
         /// The board state.
         board: Board,
@@ -25,26 +34,12 @@ pub enum AnyGame {
         board: Board,
         /// Current player to move.
         to_move: Player,
-        /// Move history (new architecture uses Move, old uses Position).
-        history: Vec<Position>,
-    },
-    /// Game ended with winner.
-    Won {
-        /// The board state.
-        board: Board,
-        /// The winner.
-        winner: Player,
         /// Move history.
-        history: Vec<Position>,
-    },
-    /// Game ended in draw.
-    Draw {
-        /// The board state.
-        board: Board,
-        /// Move history.
-        history: Vec<Position>,
+        history: Vec<Move>,
+        /// Player whose draw offer awaits an answer.
+        draw_offer: Option<Player>,
     },
-    /// Game finished (new architecture - unified outcome).
+    /// Game finished.
     Finished {
         /// The board state.
         board: Board,
@@ -59,6 +54,16 @@ pub enum AnyGame {
 //  New typestate conversions (typestate.rs)
 // ─────────────────────────────────────────────────────────────
 
+impl JsonSchema for AnyGame {
+    fn schema_name() -> Cow<'static, str> {
+        Snapshot::schema_name()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        Snapshot::json_schema(generator)
+    }
+}
+
 impl From<GameSetup> for AnyGame {
     fn from(game: GameSetup) -> Self {
         AnyGame::Setup {
@@ -72,7 +77,8 @@ impl From<GameInProgress> for AnyGame {
         AnyGame::InProgress {
             board: game.board().clone(),
             to_move: game.to_move(),
-            history: game.history().iter().map(|m| m.position).collect(),
+            history: game.history().to_vec(),
+            draw_offer: game.draw_offer(),
         }
     }
 }
@@ -96,14 +102,74 @@ impl From<GameResult> for AnyGame {
     }
 }
 
+impl TryFrom<AnyGame> for GameInProgress {
+    type Error = MoveError;
+
+    /// Restores the typestate from the stored snapshot without replaying it.
+    fn try_from(game: AnyGame) -> Result<Self, MoveError> {
+        match game {
+            AnyGame::InProgress { board, to_move, history, draw_offer } => {
+                check_snapshot(&board, &history)?;
+                if history.last().is_some_and(|last| last.player == to_move) {
+                    return Err(MoveError::InconsistentState(format!(
+                        "{:?} is to move after their own move",
+                        to_move
+                    )));
+                }
+                Ok(GameInProgress { board, history, to_move, draw_offer })
+            }
+            AnyGame::Setup { .. } => Err(MoveError::NotStarted),
+            AnyGame::Finished { .. } => Err(MoveError::GameOver),
+        }
+    }
+}
+
+impl TryFrom<AnyGame> for GameFinished {
+    type Error = MoveError;
+
+    /// Restores the typestate from the stored snapshot without replaying it.
+    fn try_from(game: AnyGame) -> Result<Self, MoveError> {
+        match game {
+            AnyGame::Finished { board, outcome, history } => {
+                check_snapshot(&board, &history)?;
+                Ok(GameFinished { board, history, outcome })
+            }
+            AnyGame::Setup { .. } => Err(MoveError::NotStarted),
+            AnyGame::InProgress { .. } => {
+                Err(MoveError::InconsistentState("Game is still in progress".to_string()))
+            }
+        }
+    }
+}
+
+/// Checks a stored snapshot in constant time.
+///
+/// The board must hold one mark per move, the last of them where the
+/// history says it was played. Everything else was validated by the
+/// move contracts when the snapshot was taken.
+fn check_snapshot(board: &Board, history: &[Move]) -> Result<(), MoveError> {
+    let marks = board.occupied().count_ones() as usize;
+    if marks != history.len() {
+        return Err(MoveError::InconsistentState(format!(
+            "{} marks on the board but {} moves in the history",
+            marks,
+            history.len()
+        )));
+    }
+    match history.last() {
+        Some(last) if board.get(last.position) != Square::Occupied(last.player) => {
+            Err(MoveError::InconsistentState(format!("Last move {} is not on the board", last)))
+        }
+        _ => Ok(()),
+    }
+}
+
 impl AnyGame {
     /// Returns the board for any game phase.
     pub fn board(&self) -> &Board {
         match self {
             AnyGame::Setup { board } => board,
             AnyGame::InProgress { board, .. } => board,
-            AnyGame::Won { board, .. } => board,
-            AnyGame::Draw { board, .. } => board,
             AnyGame::Finished { board, .. } => board,
         }
     }
@@ -112,10 +178,19 @@ impl AnyGame {
     pub fn history(&self) -> Vec<Position> {
         match self {
             AnyGame::Setup { .. } => vec![],
-            AnyGame::InProgress { history, .. } => history.clone(),
-            AnyGame::Won { history, .. } => history.clone(),
-            AnyGame::Draw { history, .. } => history.clone(),
-            AnyGame::Finished { history, .. } => history.iter().map(|m| m.position).collect(),
+            AnyGame::InProgress { history, .. } | AnyGame::Finished { history, .. } => {
+                history.iter().map(|m| m.position).collect()
+            }
+        }
+    }
+
+    /// Returns the move history for any game phase, with players.
+    pub fn move_history(&self) -> Vec<Move> {
+        match self {
+            AnyGame::Setup { .. } => vec![],
+            AnyGame::InProgress { history, .. } | AnyGame::Finished { history, .. } => {
+                history.clone()
+            }
         }
     }
 
@@ -126,24 +201,13 @@ impl AnyGame {
             AnyGame::InProgress { to_move, .. } => {
                 format!("In progress. Player {:?} to move.", to_move)
             }
-            AnyGame::Won { winner, .. } => {
-                format!("Game over. Player {:?} wins!", winner)
-            }
-            AnyGame::Draw { .. } => {
-                "Game over. Draw!".to_string()
-            }
-            AnyGame::Finished { outcome, .. } => {
-                match outcome {
-                    Outcome::Winner(player) => format!("Game over. Player {:?} wins!", player),
-                    Outcome::Draw => "Game over. Draw!".to_string(),
-                }
-            }
+            AnyGame::Finished { outcome, .. } => format!("Game over. {}", outcome.status()),
         }
     }
 
     /// Returns true if the game is over.
     pub fn is_over(&self) -> bool {
-        matches!(self, AnyGame::Won { .. } | AnyGame::Draw { .. } | AnyGame::Finished { .. })
+        matches!(self, AnyGame::Finished { .. })
     }
 
     /// Returns the current player to move, if game is in progress.
@@ -157,49 +221,76 @@ impl AnyGame {
     /// Returns the winner, if game is won.
     pub fn winner(&self) -> Option<Player> {
         match self {
-            AnyGame::Won { winner, .. } => Some(*winner),
-            AnyGame::Finished { outcome: Outcome::Winner(player), .. } => Some(*player),
+            AnyGame::Finished { outcome, .. } => outcome.winner(),
+            _ => None,
+        }
+    }
+
+    /// Returns the player whose draw offer awaits an answer, if any.
+    pub fn draw_offer(&self) -> Option<Player> {
+        match self {
+            AnyGame::InProgress { draw_offer, .. } => *draw_offer,
             _ => None,
         }
     }
 
-    /// Makes a move using a Move action (new architecture).
+    /// Restores the in-progress typestate, if the game is in progress.
+    #[instrument(skip(self))]
+    pub fn in_progress(&self) -> Option<GameInProgress> {
+        if !matches!(self, AnyGame::InProgress { .. }) {
+            return None;
+        }
+        GameInProgress::try_from(self.clone())
+            .inspect_err(|e| warn!(error = %e, "Stored game failed snapshot check"))
+            .ok()
+    }
+
+    /// Makes a move using a Move action.
     ///
-    /// This uses the NEW typestate architecture with contract validation.
+    /// The typestate is restored directly from the snapshot, so only the
+    /// new move goes through contract validation.
     #[instrument(skip(self))]
     pub fn make_move_action(self, action: Move) -> Result<Self, String> {
-        match self {
-            AnyGame::InProgress { board: _, to_move: _, history } => {
-                // Reconstruct move history (position history → Move actions)
-                let mut current_player = Player::X;
-                let mut moves: Vec<Move> = history.iter().map(|&pos| {
-                    let mov = Move::new(current_player, pos);
-                    current_player = current_player.opponent();
-                    mov
-                }).collect();
-                
-                // Add the new move
-                moves.push(action);
-                
-                debug!(move_count = moves.len(), "Replaying moves with contract validation");
-                
-                // Replay all moves to reconstruct game state with contract validation
-                match GameInProgress::replay(&moves) {
-                    Ok(result) => {
-                        debug!("Move validated via NEW typestate with contracts");
-                        Ok(result.into())
-                    }
-                    Err(e) => {
-                        warn!(error = %e, "Contract validation failed");
-                        Err(e.to_string())
-                    }
-                }
+        let result = GameInProgress::try_from(self)
+            .and_then(|game| game.make_move(action))
+            .map_err(|e| {
+                warn!(error = %e, "Contract validation failed");
+                e.to_string()
+            })?;
+
+        debug!("Move validated via typestate contracts");
+        Ok(result.into())
+    }
+
+    /// Takes back the last move, reopening a finished game.
+    #[instrument(skip(self))]
+    pub fn undo(self) -> Result<Self, String> {
+        let undone = match self {
+            AnyGame::Setup { .. } => return Err("Game hasn't started yet".to_string()),
+            AnyGame::Finished { outcome, .. } if outcome.is_off_board() => {
+                return Err("A game decided off the board can't be taken back".to_string());
             }
-            AnyGame::Setup { .. } => Err("Game hasn't started yet".to_string()),
-            AnyGame::Won { .. } => Err("Game is already over (won)".to_string()),
-            AnyGame::Draw { .. } => Err("Game is already over (draw)".to_string()),
-            AnyGame::Finished { .. } => Err("Game is already over".to_string()),
+            AnyGame::InProgress { .. } => GameInProgress::try_from(self).and_then(GameInProgress::undo),
+            AnyGame::Finished { .. } => GameFinished::try_from(self).and_then(GameFinished::undo),
+        };
+        undone.map(Into::into).map_err(|e| e.to_string())
+    }
+
+    /// Applies any domain action with contract validation.
+    ///
+    /// Placements go through [`make_move_action`](Self::make_move_action);
+    /// resignations and draw offers are applied to the restored typestate.
+    #[instrument(skip(self))]
+    pub fn act(self, action: Action<Move>) -> Result<Self, String> {
+        if let Action::Place(mov) = action {
+            return self.make_move_action(mov);
         }
+
+        let game = self.in_progress()
+            .ok_or_else(|| "Game is not in progress".to_string())?;
+        game.apply(action).map(Into::into).map_err(|e| {
+            warn!(error = %e, "Contract validation failed");
+            e.to_string()
+        })
     }
 }
-
@@ -0,0 +1,56 @@
+//! Transposition table for the [`Solver`](super::Solver).
+//!
+//! Different move orders (and, with canonical keys, mirrored positions)
+//! reach the same position. The table remembers what a search learned
+//! about a position so it is only searched once per call.
+
+use super::Score;
+use std::collections::HashMap;
+
+/// How a stored score relates to the position's true value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    /// The score is the exact value.
+    Exact,
+    /// The true value is at least the score (the search failed high).
+    Lower,
+    /// The true value is at most the score (the search failed low).
+    Upper,
+}
+
+/// A stored search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// Score relative to the stored position (wins counted from it, not the root).
+    pub score: Score,
+    /// What the score bounds.
+    pub bound: Bound,
+}
+
+/// Search results keyed by [`Game::position_key`](super::Game::position_key).
+#[derive(Debug, Clone, Default)]
+pub struct TranspositionTable {
+    entries: HashMap<u64, Entry>,
+}
+
+impl TranspositionTable {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Looks up a position.
+    pub fn get(&self, key: u64) -> Option<Entry> {
+        self.entries.get(&key).copied()
+    }
+
+    /// Stores (or replaces) the result for a position.
+    pub fn insert(&mut self, key: u64, entry: Entry) {
+        self.entries.insert(key, entry);
+    }
+
+    /// Returns the number of stored positions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
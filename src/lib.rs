@@ -6,8 +6,10 @@
 //! 
 //! - **Server**: MCP server for game sessions (stdio or HTTP)
 //! - **Agent**: AI players using LLM APIs (OpenAI, Anthropic)
-//! - **Games**: Type-safe game implementations (currently tic-tac-toe)
-//! - **Session**: Multi-player session management
+//! - **Games**: Type-safe game implementations (tic-tac-toe, Connect Four, m,n,k games)
+//! - **Session**: Multi-player session management, backed by an append-only event log
+//!   kept in memory or on disk
+//! - **Lobby**: Matchmaking queue that pairs players into fresh sessions
 //! - **Typestates**: Compile-time state machine enforcement
 //! 
 //! # Example
@@ -34,11 +36,18 @@
 // Private module declarations
 mod agent_config;
 mod agent_handler;
+mod clock;
+mod events;
 mod games;
 mod llm_client;
+mod lobby;
 mod server;
+mod series;
 mod session;
+mod store;
+mod token;
 mod tui;
+mod turn_order;
 
 // Crate-level exports - Agent configuration
 pub use agent_config::{AgentConfig, ConfigError};
@@ -50,27 +59,81 @@ pub use agent_handler::GameAgent;
 pub use llm_client::{LlmClient, LlmConfig, LlmError, LlmProvider};
 
 // Crate-level exports - Server types
-pub use server::{GameServer, GetBoardRequest, MakeMoveRequest, PlayGameRequest, RegisterPlayerRequest};
+pub use server::{
+    DropPieceRequest, GameServer, GetBoardRequest, JoinQueueRequest, LeaveQueueRequest,
+    MakeMoveRequest, OfferDrawRequest, PlaceStoneRequest, PlayGameRequest, QueueTicketRequest,
+    RegisterPlayerRequest, ResignRequest, RespondDrawRequest, RespondUndoRequest,
+    SessionCommandRequest, SpectateRequest, StopSpectatingRequest, UndoRequest,
+    WatchSessionRequest,
+};
+
+// Crate-level exports - Game clocks
+pub use clock::{Clock, GameClock, ManualClock, SystemClock, TimeControl};
+
+// Crate-level exports - Session event log
+pub use events::{EventLog, LoggedEvent, SessionEvent, SNAPSHOT_EVERY};
+
+// Crate-level exports - Session storage
+pub use store::{JsonlStore, MemoryStore, SessionStore};
+
+// Crate-level exports - Player tokens
+pub use token::PlayerToken;
+
+// Crate-level exports - Matchmaking
+pub use lobby::{Lobby, Pairing, SEEKER_TIMEOUT, SeekConstraints, Seeker};
+
+// Crate-level exports - Matches
+pub use series::{Match, MatchFormat, MatchGame, MatchResult, MatchScore};
+
+// Crate-level exports - Turn order
+pub use turn_order::{FirstMove, TurnOrder};
 
 // Crate-level exports - Session management
-pub use session::{GameSession, Player, PlayerType, SessionManager};
+pub use session::{
+    Expiry, GameSession, GameSessions, Player, PlayerType, SessionManager, SessionStatus,
+    Spectator,
+};
+
+// Crate-level exports - Game-agnostic traits
+pub use games::{Action, DrawError, Game, GameKind, GameState, Transition};
+
+// Crate-level exports - Built-in bot
+pub use games::{Difficulty, Score, Solver, WIN_SCORE};
+
+// Crate-level exports - Position analysis
+pub use games::analysis::{self, ChoiceAnalysis, Verdict};
+pub use games::annotation::{self, AnnotatedMove, MoveQuality};
+
+// Crate-level exports - Connect Four (namespaced, its types mirror tic-tac-toe's)
+pub use games::connect_four::{self, ConnectFour};
+
+// Crate-level exports - m,n,k games (namespaced like Connect Four)
+pub use games::mnk::{self, Gomoku, Mnk};
 
 // Crate-level exports - TUI
 pub use tui::run as run_tui;
 
 // Crate-level exports - Game types (tic-tac-toe with typestates)
 pub use games::tictactoe::{
+    // Game trait marker
+    TicTacToe,
     // New typestate API (phase-specific structs)
     GameSetup, GameInProgress, GameFinished, GameResult,
-    // Compatibility alias
-    Game,
     // Action types
     Move, MoveError,
     // Legacy phase markers (deprecated)
     Setup, InProgress, Finished, Outcome,
     // Domain types
     AnyGame, Board, Position, Square,
+    // Versioned wire format
+    SNAPSHOT_VERSION,
     Player as TicTacToePlayer,
     // Filtered position view
     ValidPositions,
+    // Board symmetries
+    Symmetry,
+    // Precomputed perfect play
+    Tablebase, TablebaseError,
+    // Move pre- and postconditions
+    contracts,
 };
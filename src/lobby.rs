@@ -0,0 +1,426 @@
+//! Matchmaking lobby: pairs players who don't share a session ID yet.
+//!
+//! A player joins the queue as a [`Seeker`], optionally constrained by game,
+//! opponent type and rating range, and gets a ticket. As soon as two waiting
+//! seekers accept each other the server creates a fresh session, seats both,
+//! and hands each ticket a [`Pairing`]: the session, seat and token to play
+//! with. Asking for a bot opponent skips the wait. A seeker who stops
+//! checking its ticket for [`SEEKER_TIMEOUT`] is dropped from the queue.
+
+use crate::clock::{Clock, SystemClock};
+use crate::games::{Difficulty, Game, GameKind, Mark};
+use crate::session::{GameSessions, PlayerId, PlayerType, SessionId, SessionManager};
+use crate::token::{PlayerToken, random_secret};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::{debug, info, instrument, warn};
+
+/// Time a waiting seeker may go without checking its ticket before it is
+/// dropped from the queue.
+///
+/// Longer than the longest single wait, so a seeker blocked in one is
+/// never dropped.
+pub const SEEKER_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// What a seeker will accept as game and opponent.
+///
+/// Unset fields accept anything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SeekConstraints {
+    /// Game to play. When neither side names one, they play tic-tac-toe.
+    #[serde(default)]
+    pub game: Option<GameKind>,
+    /// Kind of opponent (human, agent or bot). A bot opponent is seated at once.
+    #[serde(default)]
+    pub opponent_type: Option<PlayerType>,
+    /// Strength of a bot opponent (defaults to perfect).
+    #[serde(default)]
+    pub bot_difficulty: Option<Difficulty>,
+    /// Lowest rating the opponent may have declared.
+    #[serde(default)]
+    pub min_rating: Option<u32>,
+    /// Highest rating the opponent may have declared.
+    #[serde(default)]
+    pub max_rating: Option<u32>,
+}
+
+/// A player looking for an opponent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seeker {
+    /// Player name.
+    pub name: String,
+    /// Human or agent; bots don't queue.
+    pub player_type: PlayerType,
+    /// Rating the player declares. The server keeps no ratings of its own;
+    /// this is only checked against opponents' rating ranges.
+    pub rating: Option<u32>,
+    /// What the player will accept.
+    pub constraints: SeekConstraints,
+}
+
+impl Seeker {
+    /// Returns the game two seekers would play, or `None` if either one
+    /// turns the other down.
+    pub fn pairs_with(&self, other: &Seeker) -> Option<GameKind> {
+        if !self.accepts(other) || !other.accepts(self) {
+            return None;
+        }
+        match (self.constraints.game, other.constraints.game) {
+            (Some(ours), Some(theirs)) if ours != theirs => None,
+            (ours, theirs) => Some(ours.or(theirs).unwrap_or_default()),
+        }
+    }
+
+    /// Returns true if this seeker would play the other one.
+    fn accepts(&self, other: &Seeker) -> bool {
+        let wanted = &self.constraints;
+        let rated = (wanted.min_rating.is_none() && wanted.max_rating.is_none())
+            || other.rating.is_some_and(|rating| {
+                wanted.min_rating.is_none_or(|min| rating >= min)
+                    && wanted.max_rating.is_none_or(|max| rating <= max)
+            });
+        wanted.opponent_type.is_none_or(|kind| kind == other.player_type) && rated
+    }
+}
+
+/// Where the lobby seated a player.
+#[derive(Debug, Clone)]
+pub struct Pairing {
+    /// The freshly created session.
+    pub session_id: SessionId,
+    /// Game the session hosts.
+    pub game: GameKind,
+    /// The player's ID in the session.
+    pub player_id: PlayerId,
+    /// The player's seat.
+    pub mark: Mark,
+    /// The player's secret token for the seat.
+    pub token: PlayerToken,
+    /// The opponent's name.
+    pub opponent: String,
+}
+
+/// Matchmaking queue shared by every connection.
+///
+/// Cloning is cheap: clones share the queue.
+#[derive(Debug, Clone)]
+pub struct Lobby {
+    state: Arc<Mutex<LobbyState>>,
+    /// Woken whenever a ticket is paired.
+    updates: Arc<Notify>,
+    /// Time source for telling when seekers were last seen.
+    clock: Arc<dyn Clock>,
+}
+
+/// The queue and its results, behind the lobby's lock.
+#[derive(Debug, Default)]
+struct LobbyState {
+    /// Seekers still waiting, longest-waiting first.
+    waiting: Vec<Waiting>,
+    /// Pairings by ticket, kept until their session is evicted.
+    paired: HashMap<String, Pairing>,
+    /// Lobby sessions created so far, for naming the next one.
+    created: u64,
+}
+
+/// A seeker in the queue.
+#[derive(Debug)]
+struct Waiting {
+    /// The seeker's ticket.
+    ticket: String,
+    /// Who is waiting.
+    seeker: Seeker,
+    /// When the seeker joined or last checked its ticket, on the lobby's clock.
+    last_seen: Duration,
+}
+
+impl Default for Lobby {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lobby {
+    /// Creates an empty lobby.
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock::new()))
+    }
+
+    /// Creates an empty lobby that reads the given time source.
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(LobbyState::default())),
+            updates: Arc::new(Notify::new()),
+            clock,
+        }
+    }
+
+    /// Returns how many seekers are waiting for an opponent.
+    pub fn waiting(&self) -> usize {
+        self.state.lock().unwrap().waiting.len()
+    }
+
+    /// Takes a waiting seeker out of the queue.
+    #[instrument(skip_all)]
+    pub fn leave(&self, ticket: &str) -> Result<(), String> {
+        let mut state = self.state.lock().unwrap();
+        let Some(index) = state.waiting.iter().position(|waiting| waiting.ticket == ticket) else {
+            return Err(if state.paired.contains_key(ticket) {
+                "Ticket is already paired; play the session it was given".to_string()
+            } else {
+                "Unknown ticket".to_string()
+            });
+        };
+        let waiting = state.waiting.remove(index);
+        info!(name = %waiting.seeker.name, "Seeker left the queue");
+        Ok(())
+    }
+
+    /// Returns a ticket's pairing, or `None` while it is still waiting.
+    pub fn pairing(&self, ticket: &str) -> Result<Option<Pairing>, String> {
+        let state = self.state.lock().unwrap();
+        if let Some(pairing) = state.paired.get(ticket) {
+            return Ok(Some(pairing.clone()));
+        }
+        if state.waiting.iter().any(|waiting| waiting.ticket == ticket) {
+            Ok(None)
+        } else {
+            Err("Unknown ticket; it may have expired".to_string())
+        }
+    }
+
+    /// Waits until a ticket is paired, or the timeout passes.
+    ///
+    /// Returns the pairing, or `None` if the ticket is still waiting.
+    /// Waiting counts as checking the ticket, so the seeker stays queued.
+    #[instrument(skip_all)]
+    pub async fn wait(&self, ticket: &str, timeout: Duration) -> Result<Option<Pairing>, String> {
+        self.touch(ticket);
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            // Listen before looking, so a pairing made in between still wakes us
+            let notified = self.updates.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            if let Some(pairing) = self.pairing(ticket)? {
+                return Ok(Some(pairing));
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                debug!("Not paired before the timeout");
+                self.touch(ticket);
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Drops the pairings of evicted sessions.
+    pub fn forget_sessions(&self, evicted: &[SessionId]) {
+        self.state
+            .lock()
+            .unwrap()
+            .paired
+            .retain(|_, pairing| !evicted.contains(&pairing.session_id));
+    }
+
+    /// Marks a waiting ticket as seen now.
+    fn touch(&self, ticket: &str) {
+        let now = self.clock.now();
+        let mut state = self.state.lock().unwrap();
+        if let Some(waiting) = state.waiting.iter_mut().find(|waiting| waiting.ticket == ticket) {
+            waiting.last_seen = now;
+        }
+    }
+
+    /// Takes the longest-waiting seeker who pairs with this one out of the
+    /// queue, or queues this one under its ticket if there is none.
+    ///
+    /// Seekers not seen for [`SEEKER_TIMEOUT`] are dropped first, so nobody
+    /// is paired with a player who has gone away. Returns the match and the
+    /// game they agreed on.
+    fn match_or_enqueue(&self, ticket: &str, seeker: &Seeker) -> Option<(Waiting, GameKind)> {
+        let now = self.clock.now();
+        let mut state = self.state.lock().unwrap();
+        state.waiting.retain(|waiting| {
+            let fresh = now.saturating_sub(waiting.last_seen) < SEEKER_TIMEOUT;
+            if !fresh {
+                info!(name = %waiting.seeker.name, "Dropping seeker who stopped checking the queue");
+            }
+            fresh
+        });
+
+        let found = state
+            .waiting
+            .iter()
+            .enumerate()
+            .find_map(|(index, waiting)| waiting.seeker.pairs_with(seeker).map(|game| (index, game)));
+        match found {
+            Some((index, game)) => Some((state.waiting.remove(index), game)),
+            None => {
+                state.waiting.push(Waiting {
+                    ticket: ticket.to_string(),
+                    seeker: seeker.clone(),
+                    last_seen: now,
+                });
+                None
+            }
+        }
+    }
+
+    /// Puts a seeker back at the head of the queue after a failed pairing.
+    fn requeue(&self, waiting: Waiting) {
+        self.state.lock().unwrap().waiting.insert(0, waiting);
+    }
+
+    /// Records a ticket's pairing and wakes whoever waits for it.
+    fn complete(&self, ticket: String, pairing: Pairing) {
+        self.state.lock().unwrap().paired.insert(ticket, pairing);
+        self.updates.notify_waiters();
+    }
+
+    /// Returns the next lobby session number.
+    fn next_session_number(&self) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.created += 1;
+        state.created
+    }
+}
+
+impl GameSessions {
+    /// Puts a player in the matchmaking queue.
+    ///
+    /// Returns the player's ticket, with its pairing if an opponent was
+    /// found at once. Otherwise the ticket waits in [`Lobby`] until another
+    /// seeker pairs with it.
+    #[instrument(skip(self), fields(name = %seeker.name))]
+    pub fn join_queue(&self, seeker: Seeker) -> Result<(String, Option<Pairing>), String> {
+        if seeker.player_type == PlayerType::Bot {
+            return Err("Bots don't queue; ask for a bot opponent instead".to_string());
+        }
+        let ticket = random_secret();
+
+        if seeker.constraints.opponent_type == Some(PlayerType::Bot) {
+            let game = seeker.constraints.game.unwrap_or_default();
+            let difficulty = seeker.constraints.bot_difficulty.unwrap_or_default();
+            let pairing = self
+                .start_lobby_game(game, &[&seeker], Some(difficulty))?
+                .remove(0);
+            info!(session_id = %pairing.session_id, %difficulty, "Paired with a bot");
+            self.lobby.complete(ticket.clone(), pairing.clone());
+            return Ok((ticket, Some(pairing)));
+        }
+
+        let Some((waiting, game)) = self.lobby.match_or_enqueue(&ticket, &seeker) else {
+            info!("No opponent yet; queued");
+            return Ok((ticket, None));
+        };
+        match self.start_lobby_game(game, &[&waiting.seeker, &seeker], None) {
+            Ok(pairings) => {
+                let ours = pairings.last().cloned();
+                info!(opponent = %waiting.seeker.name, %game, "Paired two seekers");
+                for (paired, pairing) in [waiting.ticket, ticket.clone()].into_iter().zip(pairings) {
+                    self.lobby.complete(paired, pairing);
+                }
+                Ok((ticket, ours))
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to start a lobby game; requeueing the waiting seeker");
+                self.lobby.requeue(waiting);
+                Err(e)
+            }
+        }
+    }
+
+    /// Creates a session of the given game and seats the seekers, then the
+    /// bot if any.
+    fn start_lobby_game(
+        &self,
+        kind: GameKind,
+        seekers: &[&Seeker],
+        bot: Option<Difficulty>,
+    ) -> Result<Vec<Pairing>, String> {
+        let session_id = loop {
+            let id = format!("lobby_{}", self.lobby.next_session_number());
+            if self.kind_of(&id).is_none() {
+                break id;
+            }
+        };
+        match kind {
+            GameKind::TicTacToe => seat_lobby_game(&self.tictactoe, kind, session_id, seekers, bot),
+            GameKind::ConnectFour => seat_lobby_game(&self.connect_four, kind, session_id, seekers, bot),
+            GameKind::Gomoku => seat_lobby_game(&self.gomoku, kind, session_id, seekers, bot),
+        }
+    }
+}
+
+/// Creates a lobby session of any game and seats the seekers, then the bot
+/// if any.
+///
+/// Returns each seeker's pairing, in order. The session is deleted again if
+/// someone can't be seated.
+#[instrument(skip(sessions, seekers), fields(game = G::NAME))]
+fn seat_lobby_game<G: Game>(
+    sessions: &SessionManager<G>,
+    kind: GameKind,
+    session_id: SessionId,
+    seekers: &[&Seeker],
+    bot: Option<Difficulty>,
+) -> Result<Vec<Pairing>, String> {
+    sessions.create_session(session_id.clone())?;
+
+    let seat = || -> Result<Vec<Pairing>, String> {
+        let mut seats: Vec<(PlayerId, Mark)> = Vec::new();
+        for seeker in seekers {
+            let mut player_id = format!("{}_{}", session_id, seeker.name.to_lowercase().replace(' ', "_"));
+            if seats.iter().any(|(seated, _)| *seated == player_id) {
+                player_id.push_str("_2");
+            }
+            let mark = sessions.register_player_atomic(
+                &session_id,
+                player_id.clone(),
+                seeker.name.clone(),
+                seeker.player_type,
+            )?;
+            seats.push((player_id, mark));
+        }
+
+        let mut names: Vec<String> = seekers.iter().map(|seeker| seeker.name.clone()).collect();
+        if let Some(difficulty) = bot {
+            let name = format!("Bot ({})", difficulty);
+            sessions.register_bot_atomic(&session_id, format!("{}_bot", session_id), name.clone(), difficulty)?;
+            names.push(name);
+        }
+
+        let session = sessions
+            .get_session(&session_id)
+            .ok_or_else(|| "Lobby session disappeared".to_string())?;
+        seats
+            .into_iter()
+            .enumerate()
+            .map(|(index, (player_id, mark))| -> Result<Pairing, String> {
+                let token = session
+                    .get_player(&player_id)
+                    .and_then(|player| player.token.clone())
+                    .ok_or_else(|| format!("No token issued to {}", player_id))?;
+                Ok(Pairing {
+                    session_id: session_id.clone(),
+                    game: kind,
+                    player_id,
+                    mark,
+                    token,
+                    opponent: names[1 - index].clone(),
+                })
+            })
+            .collect()
+    };
+
+    let seated = seat();
+    if seated.is_err() {
+        let _ = sessions.delete_session(&session_id);
+    }
+    seated
+}
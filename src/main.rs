@@ -11,8 +11,8 @@ use clap::Parser;
 use cli::{Cli, Command};
 use rmcp::ServiceExt;
 use strictly_games::{
-    AgentConfig, GameAgent, GameServer, SessionManager,
-    run_tui as run_tui_impl, Game,
+    AgentConfig, Difficulty, Expiry, Game, GameAgent, GameKind, GameServer, GameSessions, SessionManager,
+    SystemClock, Tablebase, run_tui as run_tui_impl,
 };
 use tracing::{error, info, instrument};
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
@@ -26,15 +26,25 @@ async fn main() -> Result<()> {
 
     match cli.command {
         Command::Server => run_mcp_server().await,
-        Command::Http { port, host } => run_http_server(host, port).await,
-        Command::Tui { server_url, port, agent_config } => run_tui(server_url, port, agent_config).await,
+        Command::Http { port, host, data_dir, idle_timeout_secs, retention_secs } => {
+            let expiry = Expiry {
+                idle_timeout: std::time::Duration::from_secs(idle_timeout_secs),
+                retention: std::time::Duration::from_secs(retention_secs),
+            };
+            run_http_server(host, port, data_dir, expiry).await
+        }
+        Command::Tui { server_url, port, agent_config, game, bot, spectate } => {
+            run_tui(server_url, port, agent_config, game, bot, spectate).await
+        }
         Command::Agent {
             config,
             server_url,
             server_command,
             test_play,
             test_session,
-        } => run_agent(config, server_url, server_command, test_play, test_session).await,
+            test_game,
+        } => run_agent(config, server_url, server_command, test_play, test_session, test_game).await,
+        Command::Tablebase { output } => generate_tablebase(output),
     }
 }
 
@@ -57,8 +67,13 @@ async fn run_mcp_server() -> Result<()> {
 }
 
 /// Run the HTTP game server
-#[instrument(skip_all, fields(host = %host, port))]
-async fn run_http_server(host: String, port: u16) -> Result<()> {
+#[instrument(skip_all, fields(host = %host, port, data_dir = ?data_dir, expiry = ?expiry))]
+async fn run_http_server(
+    host: String,
+    port: u16,
+    data_dir: Option<std::path::PathBuf>,
+    expiry: Expiry,
+) -> Result<()> {
     use axum::{body::Body, http::Request, Router};
     use rmcp::transport::streamable_http_server::{
         session::local::LocalSessionManager,
@@ -90,8 +105,15 @@ async fn run_http_server(host: String, port: u16) -> Result<()> {
 
     let session_manager = Arc::new(LocalSessionManager::default());
     
-    // Create SHARED SessionManager for game state (already has Arc<Mutex<>> internally)
-    let game_sessions = SessionManager::new();
+    // Create SHARED SessionManagers for game state (already have Arc<Mutex<>> internally)
+    let game_sessions = match &data_dir {
+        Some(dir) => {
+            info!(dir = %dir.display(), "Restoring sessions from disk");
+            GameSessions::open(dir, Arc::new(SystemClock::new())).map_err(anyhow::Error::msg)?
+        }
+        None => GameSessions::new(),
+    };
+    spawn_reaper(game_sessions.clone(), expiry);
     
     // Configure for STATEFUL mode (required for elicitation loops)
     let mut config = StreamableHttpServerConfig::default();
@@ -119,26 +141,12 @@ async fn run_http_server(host: String, port: u16) -> Result<()> {
     // Build app with REST API and MCP fallback
     let app = Router::new()
         .route("/health", axum::routing::get(|| async { "OK" }))
-        .route("/api/sessions/{session_id}/game", axum::routing::get({
-            let sessions = rest_sessions.clone();
-            move |axum::extract::Path(session_id): axum::extract::Path<String>| async move {
-                use axum::Json;
-                if let Some(session) = sessions.get_session(&session_id) {
-                    Json(session.game.clone())
-                } else {
-                    Json(Game::new().into())
-                }
-            }
-        }))
-        .route("/api/sessions/{session_id}/restart", axum::routing::post({
-            move |axum::extract::Path(session_id): axum::extract::Path<String>| async move {
-                use axum::http::StatusCode;
-                match rest_sessions.restart_game(&session_id) {
-                    Ok(()) => StatusCode::OK,
-                    Err(_) => StatusCode::NOT_FOUND,
-                }
-            }
+        .route("/api/schema/game", axum::routing::get(|| async {
+            axum::Json(schemars::schema_for!(strictly_games::AnyGame))
         }))
+        .nest("/api/sessions", game_routes(rest_sessions.tictactoe))
+        .nest("/api/connect-four/sessions", game_routes(rest_sessions.connect_four))
+        .nest("/api/gomoku/sessions", game_routes(rest_sessions.gomoku))
         .fallback_service(ServiceBuilder::new()
             .map_request(|req: Request<Body>| {
                 info!(
@@ -165,7 +173,7 @@ async fn run_http_server(host: String, port: u16) -> Result<()> {
     let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await?;
     info!("✅ Server ready at http://localhost:{}/", port);
     info!("📡 Accepting SSE connections");
-    info!("🎮 Tools: start_game, get_board, make_move");
+    info!("🎮 Tools: start_game, get_board, make_move, drop_piece, place_stone, spectate, watch_session, join_queue");
     info!("🔍 Trace logging enabled - all requests will be logged");
     
     info!("🔄 About to call axum::serve() - this should block forever");
@@ -177,10 +185,182 @@ async fn run_http_server(host: String, port: u16) -> Result<()> {
     Ok(())
 }
 
+/// How often the reaper looks for idle and expired sessions.
+const REAP_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Periodically closes idle sessions and evicts expired ones.
+fn spawn_reaper(sessions: GameSessions, expiry: Expiry) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REAP_INTERVAL);
+        loop {
+            interval.tick().await;
+            let evicted = sessions.reap(&expiry);
+            if !evicted.is_empty() {
+                info!(?evicted, "Reaper evicted sessions");
+            }
+        }
+    });
+}
+
+/// Longest a request for a session's events waits for a new one.
+const MAX_EVENTS_WAIT_SECS: u64 = 60;
+
+/// Query of a request for a session's events.
+#[derive(Debug, serde::Deserialize)]
+struct EventsQuery {
+    /// First event to return.
+    #[serde(default)]
+    from: usize,
+    /// Seconds to wait for an event at or after `from` if there is none yet.
+    #[serde(default)]
+    wait_secs: u64,
+}
+
+/// REST routes for the sessions of one game type.
+fn game_routes<G: Game>(sessions: SessionManager<G>) -> axum::Router {
+    use axum::{Json, extract::{Path, Query}, http::{HeaderMap, StatusCode}, routing::{delete, get, post}};
+
+    let game_sessions = sessions.clone();
+    let annotation_sessions = sessions.clone();
+    let match_sessions = sessions.clone();
+    let event_sessions = sessions.clone();
+    let close_sessions = sessions.clone();
+    let delete_sessions = sessions.clone();
+    axum::Router::new()
+        .route("/{session_id}/game", get(
+            move |Path(session_id): Path<String>| async move {
+                // Polling the game is enough to flag a player out of time
+                let _ = game_sessions.check_clock_atomic(&session_id);
+                if let Some(session) = game_sessions.get_session(&session_id) {
+                    Json(session.game)
+                } else {
+                    Json(G::State::from(G::setup()))
+                }
+            }
+        ))
+        .route("/{session_id}/annotations", get(
+            move |Path(session_id): Path<String>| async move {
+                // Annotating searches every move, so it happens on first request
+                match annotation_sessions.annotations(&session_id).await {
+                    Ok(annotations) => Ok(Json(annotations)),
+                    Err(_) => Err(StatusCode::NOT_FOUND),
+                }
+            }
+        ))
+        .route("/{session_id}/match", get(
+            move |Path(session_id): Path<String>| async move {
+                match match_sessions.get_session(&session_id) {
+                    Some(session) => Ok(Json(session.match_play)),
+                    None => Err(StatusCode::NOT_FOUND),
+                }
+            }
+        ))
+        .route("/{session_id}/events", get(
+            move |Path(session_id): Path<String>, Query(query): Query<EventsQuery>| async move {
+                // With `wait_secs`, hold the request until there is something new
+                let wait = std::time::Duration::from_secs(query.wait_secs.min(MAX_EVENTS_WAIT_SECS));
+                match event_sessions.wait_for_events(&session_id, query.from, wait).await {
+                    Some(session) => {
+                        let events = session.events().get(query.from..).unwrap_or_default();
+                        Ok(Json(events.iter().map(|e| e.redacted()).collect::<Vec<_>>()))
+                    }
+                    None => Err(StatusCode::NOT_FOUND),
+                }
+            }
+        ))
+        .route("/{session_id}/close", post(
+            move |Path(session_id): Path<String>, headers: HeaderMap| async move {
+                if let Err(status) = authorize(&close_sessions, &session_id, &headers) {
+                    return status;
+                }
+                match close_sessions.close_session(&session_id) {
+                    Ok(()) => StatusCode::OK,
+                    // The session exists, so it was closed already
+                    Err(_) if close_sessions.get_session(&session_id).is_some() => StatusCode::CONFLICT,
+                    Err(_) => StatusCode::NOT_FOUND,
+                }
+            }
+        ))
+        .route("/{session_id}", delete(
+            move |Path(session_id): Path<String>, headers: HeaderMap| async move {
+                if let Err(status) = authorize(&delete_sessions, &session_id, &headers) {
+                    return status;
+                }
+                match delete_sessions.delete_session(&session_id) {
+                    Ok(()) => StatusCode::NO_CONTENT,
+                    Err(_) => StatusCode::NOT_FOUND,
+                }
+            }
+        ))
+        .route("/{session_id}/restart", post(
+            move |Path(session_id): Path<String>, headers: HeaderMap| async move {
+                if let Err(status) = authorize(&sessions, &session_id, &headers) {
+                    return status;
+                }
+                match sessions.restart_game(&session_id) {
+                    Ok(()) => {
+                        // A bot that moves first in the new game opens now
+                        if let Err(e) = sessions.play_bot_turns(&session_id).await {
+                            tracing::warn!(session_id = %session_id, error = %e, "Bot could not open the restarted game");
+                        }
+                        StatusCode::OK
+                    }
+                    // The session exists, so it is closed or its match is over
+                    Err(_) if sessions.get_session(&session_id).is_some() => StatusCode::CONFLICT,
+                    Err(_) => StatusCode::NOT_FOUND,
+                }
+            }
+        ))
+}
+
+/// Checks the player token sent as `Authorization: Bearer <token>`.
+///
+/// Fails with 404 for an unknown session, 401 without a token and 403
+/// with a token no player in the session holds.
+fn authorize<G: Game>(
+    sessions: &SessionManager<G>,
+    session_id: &str,
+    headers: &axum::http::HeaderMap,
+) -> Result<(), axum::http::StatusCode> {
+    use axum::http::{StatusCode, header::AUTHORIZATION};
+
+    let session = sessions.get_session(session_id).ok_or(StatusCode::NOT_FOUND)?;
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    session.authorize_any(token).map_err(|e| {
+        tracing::warn!(session_id, error = %e, "Rejected session command");
+        if token.is_some() { StatusCode::FORBIDDEN } else { StatusCode::UNAUTHORIZED }
+    })
+}
+
 /// Run the TUI client
-#[instrument(skip_all, fields(server_url = ?server_url, port))]
-async fn run_tui(server_url: Option<String>, port: u16, agent_config: std::path::PathBuf) -> Result<()> {
-    run_tui_impl(server_url, port, agent_config).await
+#[instrument(skip_all, fields(server_url = ?server_url, port, game = %game, bot = ?bot, spectate = ?spectate))]
+async fn run_tui(
+    server_url: Option<String>,
+    port: u16,
+    agent_config: std::path::PathBuf,
+    game: GameKind,
+    bot: Option<Difficulty>,
+    spectate: Option<String>,
+) -> Result<()> {
+    run_tui_impl(server_url, port, agent_config, game, bot, spectate).await
+}
+
+/// Solve tic-tac-toe and write the tablebase file
+#[instrument(fields(output = %output.display()))]
+fn generate_tablebase(output: std::path::PathBuf) -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .init();
+
+    let tablebase = Tablebase::generate();
+    std::fs::write(&output, tablebase.to_bytes())?;
+    info!(positions = tablebase.len(), "Wrote tablebase");
+    println!("Wrote {} positions to {}", tablebase.len(), output.display());
+
+    Ok(())
 }
 
 /// Run the MCP agent
@@ -191,6 +371,7 @@ async fn run_agent(
     server_command: Option<String>,
     test_play: bool,
     test_session: Option<String>,
+    test_game: GameKind,
 ) -> Result<()> {
     // Load .env file (needed when run as subprocess)
     dotenvy::dotenv().ok();
@@ -244,13 +425,13 @@ async fn run_agent(
     // If --test-play flag is set, call play_game tool
     if test_play {
         info!("Test mode: calling play_game tool in continuous loop");
-        let session_id = test_session
-            .unwrap_or_else(|| format!("auto_game_{}", std::process::id()));
+        // Without a session ID each game is paired through the matchmaking queue
+        let session_id = test_session;
         
         // Continuously play games until Ctrl+C
         loop {
             info!("Starting new game session");
-            match test_play_game(&peer, &config, &session_id).await {
+            match test_play_game(&peer, &config, session_id.as_deref(), test_game).await {
                 Ok(_) => {
                     info!("Game completed, waiting for next game to start");
                     // Small delay before checking for next game
@@ -276,18 +457,20 @@ async fn run_agent(
 async fn test_play_game(
     peer: &rmcp::Peer<rmcp::RoleClient>,
     config: &AgentConfig,
-    session_id: &str,
+    session_id: Option<&str>,
+    game: GameKind,
 ) -> Result<()> {
     use serde_json::json;
     
-    info!(session_id, player_name = %config.name(), "test_play_game: Calling play_game tool");
+    info!(session_id = ?session_id, player_name = %config.name(), game = %game, "test_play_game: Calling play_game tool");
     
     let result = peer
         .call_tool(rmcp::model::CallToolRequestParams {
             name: "play_game".into(),
             arguments: Some(json!({
                 "session_id": session_id,
-                "player_name": config.name()
+                "player_name": config.name(),
+                "game": game
             }).as_object().unwrap().clone()),
             task: None,
             meta: None,
@@ -0,0 +1,224 @@
+//! Matches: a series of games between the same two players.
+//!
+//! A session's [`Match`] keeps a running score across restarts and
+//! decides the match under its [`MatchFormat`]. Scores follow players,
+//! not marks, since the players swap seats between the games of a match.
+
+use crate::games::Mark;
+use crate::session::{Player, PlayerId};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+
+/// How long a match lasts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case", tag = "kind")]
+pub enum MatchFormat {
+    /// At most `games` games; over once a player can no longer be caught.
+    BestOf {
+        /// Maximum number of games.
+        games: u32,
+    },
+    /// Over when a player reaches `wins` wins; draws don't count.
+    FirstTo {
+        /// Wins needed to take the match.
+        wins: u32,
+    },
+    /// Exactly `games` games, however the score stands.
+    Fixed {
+        /// Number of games.
+        games: u32,
+    },
+}
+
+impl std::fmt::Display for MatchFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchFormat::BestOf { games } => write!(f, "best of {}", games),
+            MatchFormat::FirstTo { wins } => write!(f, "first to {}", wins),
+            MatchFormat::Fixed { games } => write!(f, "{} games", games),
+        }
+    }
+}
+
+/// A player's running score in a match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchScore {
+    /// Player's unique ID.
+    pub player_id: PlayerId,
+    /// Player's name.
+    pub name: String,
+    /// Games won so far.
+    pub wins: u32,
+}
+
+/// A finished game of a match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchGame {
+    /// Number of the game in its session.
+    pub game_number: u32,
+    /// Player who played X.
+    pub player_x: PlayerId,
+    /// Player who played O.
+    pub player_o: PlayerId,
+    /// Winner of the game, or `None` for a draw.
+    pub winner: Option<PlayerId>,
+}
+
+/// How a match ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchResult {
+    /// The player won the match.
+    Won(PlayerId),
+    /// Neither player came out ahead.
+    Drawn,
+}
+
+/// Running score of a match.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Match {
+    /// How long the match lasts.
+    pub format: MatchFormat,
+    /// Both players' scores, in the order they first played.
+    pub scores: Vec<MatchScore>,
+    /// Finished games, oldest first.
+    pub games: Vec<MatchGame>,
+}
+
+impl Match {
+    /// Starts a match with no games played.
+    #[instrument]
+    pub fn new(format: MatchFormat) -> Self {
+        Self {
+            format,
+            scores: Vec::new(),
+            games: Vec::new(),
+        }
+    }
+
+    /// Number of games played so far.
+    pub fn games_played(&self) -> u32 {
+        self.games.len() as u32
+    }
+
+    /// Number of drawn games so far.
+    pub fn draws(&self) -> u32 {
+        self.games.iter().filter(|game| game.winner.is_none()).count() as u32
+    }
+
+    /// Returns the player's score, if they have played in the match.
+    pub fn score(&self, player_id: &str) -> Option<&MatchScore> {
+        self.scores.iter().find(|score| score.player_id == player_id)
+    }
+
+    /// Records a finished game, unless it was already recorded.
+    ///
+    /// The players are given by seat; `winner` is the winning mark, or
+    /// `None` for a draw.
+    #[instrument(skip(self, player_x, player_o), fields(format = %self.format))]
+    pub fn record(
+        &mut self,
+        game_number: u32,
+        player_x: &Player,
+        player_o: &Player,
+        winner: Option<Mark>,
+    ) {
+        if self.is_over() || self.games.iter().any(|game| game.game_number == game_number) {
+            return;
+        }
+
+        for player in [player_x, player_o] {
+            if self.score(&player.id).is_none() {
+                self.scores.push(MatchScore {
+                    player_id: player.id.clone(),
+                    name: player.name.clone(),
+                    wins: 0,
+                });
+            }
+        }
+
+        let winner = winner.map(|mark| match mark {
+            Mark::X => player_x.id.clone(),
+            Mark::O => player_o.id.clone(),
+        });
+        if let Some(score) = self.scores.iter_mut().find(|s| Some(&s.player_id) == winner.as_ref()) {
+            score.wins += 1;
+        }
+        self.games.push(MatchGame {
+            game_number,
+            player_x: player_x.id.clone(),
+            player_o: player_o.id.clone(),
+            winner,
+        });
+
+        info!(game_number, summary = %self.summary(), "Recorded match game");
+    }
+
+    /// Forgets a game that was taken back after it finished.
+    pub fn unrecord(&mut self, game_number: u32) {
+        let Some(index) = self.games.iter().position(|game| game.game_number == game_number) else {
+            return;
+        };
+        let game = self.games.remove(index);
+        if let Some(score) = self.scores.iter_mut().find(|s| Some(&s.player_id) == game.winner.as_ref()) {
+            score.wins -= 1;
+        }
+        debug!(game_number, "Forgot match game");
+    }
+
+    /// Returns the match result once the match is decided.
+    pub fn result(&self) -> Option<MatchResult> {
+        let [first, second] = self.scores.as_slice() else {
+            return None;
+        };
+        let (leader, lead) = if first.wins >= second.wins {
+            (first, first.wins - second.wins)
+        } else {
+            (second, second.wins - first.wins)
+        };
+        let played = self.games_played();
+
+        let decided = match self.format {
+            // Over once the trailing player can't catch up in the games left
+            MatchFormat::BestOf { games } => lead > games.saturating_sub(played) || played >= games,
+            MatchFormat::FirstTo { wins } => leader.wins >= wins,
+            MatchFormat::Fixed { games } => played >= games,
+        };
+
+        decided.then(|| {
+            if lead > 0 {
+                MatchResult::Won(leader.player_id.clone())
+            } else {
+                MatchResult::Drawn
+            }
+        })
+    }
+
+    /// Returns true once the match is decided.
+    pub fn is_over(&self) -> bool {
+        self.result().is_some()
+    }
+
+    /// Describes the score, e.g. `Alice 2 - 1 Bob, 1 draw (best of 5)`.
+    pub fn summary(&self) -> String {
+        let score = match self.scores.as_slice() {
+            [first, second] => format!("{} {} - {} {}", first.name, first.wins, second.wins, second.name),
+            _ => "No games played".to_string(),
+        };
+        let draws = match self.draws() {
+            0 => String::new(),
+            1 => ", 1 draw".to_string(),
+            n => format!(", {} draws", n),
+        };
+        let result = match self.result() {
+            Some(MatchResult::Won(player_id)) => {
+                let name = self.score(&player_id).map_or(player_id.as_str(), |s| s.name.as_str());
+                format!(": {} wins the match", name)
+            }
+            Some(MatchResult::Drawn) => ": match drawn".to_string(),
+            None => String::new(),
+        };
+        format!("{}{} ({}){}", score, draws, self.format, result)
+    }
+}
@@ -1,7 +1,15 @@
 //! MCP server setup and configuration.
 
-use crate::games::tictactoe::{Player, Position, ValidPositions};
-use crate::session::{PlayerType, SessionManager};
+use crate::clock::TimeControl;
+use crate::games::connect_four::Column;
+use crate::games::mnk::Coord;
+use crate::games::tictactoe::{Player, Position};
+use crate::games::analysis;
+use crate::games::{Difficulty, Game, GameKind, GameState, Mark, Solver};
+use crate::lobby::{Lobby, Pairing, SeekConstraints, Seeker};
+use crate::series::{Match, MatchFormat, MatchResult};
+use crate::session::{GameSession, GameSessions, PlayerType, SessionManager, SessionStatus};
+use crate::turn_order::TurnOrder;
 use rmcp::handler::server::router::tool::ToolRouter;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::{CallToolResult, Content, ServerCapabilities, ServerInfo};
@@ -18,9 +26,24 @@ pub struct RegisterPlayerRequest {
     pub session_id: String,
     /// Player name.
     pub name: String,
-    /// Player type (human or agent).
+    /// Player type (human, agent or bot). Bots are moved by the server.
     #[serde(rename = "type")]
     pub player_type: PlayerType,
+    /// Game to create if the session doesn't exist yet (defaults to tic-tac-toe).
+    #[serde(default)]
+    pub game: GameKind,
+    /// Bot strength (random, greedy, lookahead or perfect). Only valid for bots; defaults to perfect.
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+    /// Time control if the session is created now (defaults to unlimited).
+    #[serde(default)]
+    pub time_control: TimeControl,
+    /// Turn order if the session is created now (defaults to X moving first, fixed seats).
+    #[serde(default)]
+    pub turn_order: TurnOrder,
+    /// Match to play if the session is created now (defaults to single games).
+    #[serde(default)]
+    pub match_format: Option<MatchFormat>,
 }
 
 /// Request for making a move.
@@ -30,17 +53,130 @@ pub struct MakeMoveRequest {
     pub session_id: String,
     /// Player ID.
     pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
     /// Position on board.
     pub position: crate::games::tictactoe::Position,
 }
 
-/// Request for playing a game with elicitation.
+/// Request for dropping a piece in Connect Four.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct PlayGameRequest {
+pub struct DropPieceRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Player ID.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Column to drop the piece into.
+    pub column: Column,
+}
+
+/// Request for placing a stone in Gomoku.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlaceStoneRequest {
     /// Session ID.
     pub session_id: String,
+    /// Player ID.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Cell to place the stone on (0-based row from the top, 0-based column from the left).
+    pub coord: Coord,
+}
+
+/// Request for playing a game with elicitation.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlayGameRequest {
+    /// Session ID to join or create. Leave it out to be paired through the
+    /// matchmaking queue instead; lobby sessions use the default time
+    /// control and turn order and play single games.
+    #[serde(default)]
+    pub session_id: Option<String>,
     /// Player name.
     pub player_name: String,
+    /// Game to create if the session doesn't exist yet (defaults to tic-tac-toe).
+    #[serde(default)]
+    pub game: GameKind,
+    /// Seat a built-in bot of this difficulty as the opponent.
+    #[serde(default)]
+    pub bot_opponent: Option<Difficulty>,
+    /// Time control if the session is created now (defaults to unlimited).
+    #[serde(default)]
+    pub time_control: TimeControl,
+    /// Turn order if the session is created now (defaults to X moving first, fixed seats).
+    #[serde(default)]
+    pub turn_order: TurnOrder,
+    /// Match to play if the session is created now (defaults to single games).
+    #[serde(default)]
+    pub match_format: Option<MatchFormat>,
+}
+
+/// Request for asking to take back a move.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UndoRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Player ID of the player taking back their move.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+/// Request for answering the opponent's undo request.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RespondUndoRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Player ID of the player answering.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Whether to allow the opponent to take back their move.
+    pub accept: bool,
+}
+
+/// Request for resigning the game.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResignRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Player ID of the player resigning.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+/// Request for offering the opponent a draw.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OfferDrawRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Player ID of the player offering the draw.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+/// Request for answering the opponent's draw offer.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RespondDrawRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Player ID of the player answering.
+    pub player_id: String,
+    /// Player token returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Whether to agree to the draw.
+    pub accept: bool,
 }
 
 /// Request for getting board state.
@@ -50,152 +186,1087 @@ pub struct GetBoardRequest {
     pub session_id: String,
 }
 
-/// Main server handler.
-pub struct GameServer {
-    sessions: SessionManager,
-    tool_router: ToolRouter<Self>,
-}
+/// Request for acting on a whole session.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionCommandRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Token of a player seated in the session, returned by register_player.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+/// Request for watching a session without taking a seat.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SpectateRequest {
+    /// Session ID to watch.
+    pub session_id: String,
+    /// Spectator name.
+    pub name: String,
+}
+
+/// Request for following a session's events as they happen.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WatchSessionRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// First event to return: 0 for the whole history, then the `next` value of the previous call.
+    #[serde(default)]
+    pub from: u64,
+    /// Seconds to wait for a new event if there is none yet (defaults to 30, at most 60).
+    #[serde(default)]
+    pub wait_secs: Option<u64>,
+}
+
+/// Request for no longer watching a session.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StopSpectatingRequest {
+    /// Session ID.
+    pub session_id: String,
+    /// Spectator ID returned by spectate.
+    pub spectator_id: String,
+    /// Spectator token returned by spectate.
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+/// Request for joining the matchmaking queue.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JoinQueueRequest {
+    /// Player name.
+    pub name: String,
+    /// Player type (human or agent).
+    #[serde(rename = "type")]
+    pub player_type: PlayerType,
+    /// Your rating, checked against opponents' rating ranges. The server keeps no ratings of its own.
+    #[serde(default)]
+    pub rating: Option<u32>,
+    /// Game and opponent you'll accept.
+    #[serde(flatten)]
+    pub constraints: SeekConstraints,
+    /// Seconds to wait for an opponent before returning your ticket (defaults to 30, at most 60).
+    #[serde(default)]
+    pub wait_secs: Option<u64>,
+}
+
+/// Request for waiting on a matchmaking ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct QueueTicketRequest {
+    /// Ticket returned by join_queue.
+    pub ticket: String,
+    /// Seconds to wait for an opponent (defaults to 30, at most 60).
+    #[serde(default)]
+    pub wait_secs: Option<u64>,
+}
+
+/// Request for leaving the matchmaking queue.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LeaveQueueRequest {
+    /// Ticket returned by join_queue.
+    pub ticket: String,
+}
+
+/// Seconds `join_queue` and `check_queue` wait for an opponent by default.
+const DEFAULT_QUEUE_SECS: u64 = 30;
+
+/// Longest `join_queue` and `check_queue` wait for an opponent.
+const MAX_QUEUE_SECS: u64 = 60;
+
+/// Seconds `watch_session` waits for a new event by default.
+const DEFAULT_WATCH_SECS: u64 = 30;
+
+/// Longest `watch_session` waits for a new event.
+const MAX_WATCH_SECS: u64 = 60;
+
+/// Seconds `play_game` waits for an opponent's move before giving up,
+/// unless the opponent's clock allows them longer.
+const MAX_OPPONENT_WAIT_SECS: u64 = 300;
+
+/// Main server handler.
+pub struct GameServer {
+    sessions: GameSessions,
+    tool_router: ToolRouter<Self>,
+}
+
+#[tool_router]
+impl GameServer {
+    /// Creates a new game server with shared session managers.
+    #[instrument]
+    pub fn with_sessions(sessions: GameSessions) -> Self {
+        info!("Creating game server with shared session managers");
+        Self {
+            sessions,
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    /// Creates a new game server.
+    pub fn new() -> Self {
+        info!("Creating game server with session management");
+        Self::with_sessions(GameSessions::new())
+    }
+
+    /// Resolves which game a session hosts.
+    ///
+    /// An existing session keeps its game; otherwise the requested one is used.
+    fn resolve_kind(&self, session_id: &str, requested: GameKind) -> GameKind {
+        self.sessions.kind_of(session_id).unwrap_or(requested)
+    }
+
+    /// Looks up the game of an existing session.
+    fn existing_kind(&self, session_id: &str) -> Result<GameKind, McpError> {
+        self.sessions
+            .kind_of(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))
+    }
+
+    /// Waits on a matchmaking ticket for up to `wait_secs` (capped).
+    async fn wait_in_queue(&self, ticket: &str, wait_secs: Option<u64>) -> Result<Option<Pairing>, McpError> {
+        let wait = wait_secs.unwrap_or(DEFAULT_QUEUE_SECS).min(MAX_QUEUE_SECS);
+        self.sessions.lobby
+            .wait(ticket, std::time::Duration::from_secs(wait))
+            .await
+            .map_err(|e| McpError::invalid_params(e, None))
+    }
+
+    /// Formats a ticket's state: its pairing, or how to keep waiting.
+    fn describe_ticket(&self, ticket: &str, pairing: Option<Pairing>) -> CallToolResult {
+        let message = match pairing {
+            Some(pairing) => format!(
+                "Paired with {}!\nSession: {}\nGame: {}\nSeat: {:?}\nPlayer ID: {}\nToken: {}\nKeep the token secret; pass it with every command you send.",
+                pairing.opponent,
+                pairing.session_id,
+                pairing.game,
+                pairing.mark,
+                pairing.player_id,
+                pairing.token.as_str()
+            ),
+            None => format!(
+                "Waiting for an opponent ({} in the queue).\nTicket: {}\nCall check_queue with the ticket to keep waiting, or leave_queue to give up.",
+                self.sessions.lobby.waiting(),
+                ticket
+            ),
+        };
+        CallToolResult::success(vec![Content::text(message)])
+    }
+
+    /// Queues for an opponent of the requested game and waits until paired.
+    ///
+    /// Leaves the queue if the call is dropped first, e.g. when the client
+    /// disconnects.
+    async fn queue_for_game(&self, req: &PlayGameRequest) -> Result<Pairing, McpError> {
+        let seeker = Seeker {
+            name: req.player_name.clone(),
+            player_type: PlayerType::Agent,
+            rating: None,
+            constraints: SeekConstraints {
+                game: Some(req.game),
+                opponent_type: req.bot_opponent.map(|_| PlayerType::Bot),
+                bot_difficulty: req.bot_opponent,
+                ..SeekConstraints::default()
+            },
+        };
+        let (ticket, pairing) = self.sessions.join_queue(seeker)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        if let Some(pairing) = pairing {
+            return Ok(pairing);
+        }
+
+        info!(game = %req.game, "Waiting in the matchmaking queue");
+        let _queued = QueuedTicket { lobby: &self.sessions.lobby, ticket: &ticket };
+        loop {
+            if let Some(pairing) = self.wait_in_queue(&ticket, Some(MAX_QUEUE_SECS)).await? {
+                info!(session_id = %pairing.session_id, opponent = %pairing.opponent, "Paired through the queue");
+                return Ok(pairing);
+            }
+            debug!("Still waiting for an opponent");
+        }
+    }
+
+    /// Registers a player in a session.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, name = %req.name))]
+    #[tool(description = "Register as a player in a game session. Creates a session of the requested game (tic-tac-toe, connect-four or gomoku) if it doesn't exist. Returns your player ID and a secret token; every command acting for you needs both.")]
+    pub async fn register_player(
+        &self,
+        Parameters(req): Parameters<RegisterPlayerRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.resolve_kind(&req.session_id, req.game) {
+            GameKind::TicTacToe => Self::register_player_in(&self.sessions.tictactoe, req).await,
+            GameKind::ConnectFour => Self::register_player_in(&self.sessions.connect_four, req).await,
+            GameKind::Gomoku => Self::register_player_in(&self.sessions.gomoku, req).await,
+        }
+    }
+
+    /// Starts a new game in a session.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
+    #[tool(description = "Start a new game in the session, keeping its game type. Needs the token of a seated player. The session's turn order decides who moves first. During a match, or when the turn order swaps seats, the same players stay with their seats swapped; otherwise the players must rejoin.")]
+    pub async fn start_game(
+        &self,
+        Parameters(req): Parameters<SessionCommandRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!(session_id = %req.session_id, "Starting new game");
+        
+        let kind = self.sessions.kind_of(&req.session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found. Use register_player first.", None))?;
+
+        match kind {
+            GameKind::TicTacToe => Self::start_game_in(&self.sessions.tictactoe, &req.session_id, req.token.as_deref()).await,
+            GameKind::ConnectFour => Self::start_game_in(&self.sessions.connect_four, &req.session_id, req.token.as_deref()).await,
+            GameKind::Gomoku => Self::start_game_in(&self.sessions.gomoku, &req.session_id, req.token.as_deref()).await,
+        }
+    }
+
+    /// Makes a move at the given position.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id, position = ?req.position))]
+    #[tool(description = "Make a tic-tac-toe move at the specified position, passing your player ID and token from register_player. Use Position enum (TopLeft, TopCenter, TopRight, MiddleLeft, Center, MiddleRight, BottomLeft, BottomCenter, BottomRight).")]
+    pub async fn make_move(
+        &self,
+        Parameters(req): Parameters<MakeMoveRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        Self::make_move_in(&self.sessions.tictactoe, &req.session_id, &req.player_id, req.token.as_deref(), req.position).await
+    }
+
+    /// Drops a piece into the given column.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id, column = ?req.column))]
+    #[tool(description = "Drop a Connect Four piece into the specified column, passing your player ID and token from register_player. Use Column enum (One, Two, Three, Four, Five, Six, Seven), left to right.")]
+    pub async fn drop_piece(
+        &self,
+        Parameters(req): Parameters<DropPieceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        Self::make_move_in(&self.sessions.connect_four, &req.session_id, &req.player_id, req.token.as_deref(), req.column).await
+    }
+
+    /// Places a Gomoku stone on the given cell.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id, coord = %req.coord))]
+    #[tool(description = "Place a Gomoku stone on an empty cell of the 15x15 board, passing your player ID and token from register_player. Coordinates are 0-based: {\"row\": 7, \"column\": 7} is the center (H8). Five in a row wins.")]
+    pub async fn place_stone(
+        &self,
+        Parameters(req): Parameters<PlaceStoneRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        Self::make_move_in(&self.sessions.gomoku, &req.session_id, &req.player_id, req.token.as_deref(), req.coord).await
+    }
+
+    /// Asks the opponent to let the player take back their last move.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id))]
+    #[tool(description = "Ask your opponent to let you take back your last move (works in any game, also after it ended). A bot opponent accepts immediately; other opponents answer with respond_undo.")]
+    pub async fn request_undo(
+        &self,
+        Parameters(req): Parameters<UndoRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::request_undo_in(&self.sessions.tictactoe, &req.session_id, &req.player_id, req.token.as_deref()).await,
+            GameKind::ConnectFour => Self::request_undo_in(&self.sessions.connect_four, &req.session_id, &req.player_id, req.token.as_deref()).await,
+            GameKind::Gomoku => Self::request_undo_in(&self.sessions.gomoku, &req.session_id, &req.player_id, req.token.as_deref()).await,
+        }
+    }
+
+    /// Accepts or declines the opponent's undo request.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id, accept = req.accept))]
+    #[tool(description = "Accept or decline your opponent's pending undo request. Accepting takes back their last move (and any of yours played after it).")]
+    pub async fn respond_undo(
+        &self,
+        Parameters(req): Parameters<RespondUndoRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::respond_undo_in(&self.sessions.tictactoe, req).await,
+            GameKind::ConnectFour => Self::respond_undo_in(&self.sessions.connect_four, req).await,
+            GameKind::Gomoku => Self::respond_undo_in(&self.sessions.gomoku, req).await,
+        }
+    }
+
+    /// Resigns the game.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id))]
+    #[tool(description = "Resign the game; your opponent wins. Only possible while the game is in progress.")]
+    pub async fn resign(
+        &self,
+        Parameters(req): Parameters<ResignRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::resign_in(&self.sessions.tictactoe, &req.session_id, &req.player_id, req.token.as_deref()).await,
+            GameKind::ConnectFour => Self::resign_in(&self.sessions.connect_four, &req.session_id, &req.player_id, req.token.as_deref()).await,
+            GameKind::Gomoku => Self::resign_in(&self.sessions.gomoku, &req.session_id, &req.player_id, req.token.as_deref()).await,
+        }
+    }
+
+    /// Offers the opponent a draw.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id))]
+    #[tool(description = "Offer your opponent a draw. A bot opponent answers immediately; other opponents answer with respond_draw, and playing on declines the offer.")]
+    pub async fn offer_draw(
+        &self,
+        Parameters(req): Parameters<OfferDrawRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::offer_draw_in(&self.sessions.tictactoe, &req.session_id, &req.player_id, req.token.as_deref()).await,
+            GameKind::ConnectFour => Self::offer_draw_in(&self.sessions.connect_four, &req.session_id, &req.player_id, req.token.as_deref()).await,
+            GameKind::Gomoku => Self::offer_draw_in(&self.sessions.gomoku, &req.session_id, &req.player_id, req.token.as_deref()).await,
+        }
+    }
+
+    /// Accepts or declines the opponent's draw offer.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id, accept = req.accept))]
+    #[tool(description = "Accept or decline your opponent's pending draw offer. Accepting ends the game as a draw.")]
+    pub async fn respond_draw(
+        &self,
+        Parameters(req): Parameters<RespondDrawRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::respond_draw_in(&self.sessions.tictactoe, req).await,
+            GameKind::ConnectFour => Self::respond_draw_in(&self.sessions.connect_four, req).await,
+            GameKind::Gomoku => Self::respond_draw_in(&self.sessions.gomoku, req).await,
+        }
+    }
+
+    /// Gets the current board state.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
+    #[tool(description = "Get the current board state and game status")]
+    pub async fn get_board(
+        &self,
+        Parameters(req): Parameters<GetBoardRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::get_board_in(&self.sessions.tictactoe, &req.session_id),
+            GameKind::ConnectFour => Self::get_board_in(&self.sessions.connect_four, &req.session_id),
+            GameKind::Gomoku => Self::get_board_in(&self.sessions.gomoku, &req.session_id),
+        }
+    }
+    
+    /// Gets the score of the session's match.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
+    #[tool(description = "Get the running score and game-by-game results of the session's match, and the match result once it is decided.")]
+    pub async fn get_match(
+        &self,
+        Parameters(req): Parameters<GetBoardRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::get_match_in(&self.sessions.tictactoe, &req.session_id),
+            GameKind::ConnectFour => Self::get_match_in(&self.sessions.connect_four, &req.session_id),
+            GameKind::Gomoku => Self::get_match_in(&self.sessions.gomoku, &req.session_id),
+        }
+    }
+    
+    /// Evaluates every legal move in the current position.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
+    #[tool(description = "Analyze the current position: for every legal move, its value under best play (win, draw or loss) and the number of plies until that result. Games too large to solve report 'unknown' beyond the search horizon.")]
+    pub async fn analyze_position(
+        &self,
+        Parameters(req): Parameters<GetBoardRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::analyze_position_in(&self.sessions.tictactoe, &req.session_id).await,
+            GameKind::ConnectFour => Self::analyze_position_in(&self.sessions.connect_four, &req.session_id).await,
+            GameKind::Gomoku => Self::analyze_position_in(&self.sessions.gomoku, &req.session_id).await,
+        }
+    }
+    
+    /// Closes a session.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
+    #[tool(description = "Close a session: it accepts no more moves, players or games, and is removed from the server after a while. Needs the token of a seated player. Use delete_session to remove it straight away.")]
+    pub async fn close_session(
+        &self,
+        Parameters(req): Parameters<SessionCommandRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::close_session_in(&self.sessions.tictactoe, &req.session_id, req.token.as_deref()),
+            GameKind::ConnectFour => Self::close_session_in(&self.sessions.connect_four, &req.session_id, req.token.as_deref()),
+            GameKind::Gomoku => Self::close_session_in(&self.sessions.gomoku, &req.session_id, req.token.as_deref()),
+        }
+    }
+
+    /// Deletes a session.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
+    #[tool(description = "Delete a session and its game history straight away, whatever state it is in. Needs the token of a seated player.")]
+    pub async fn delete_session(
+        &self,
+        Parameters(req): Parameters<SessionCommandRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let token = req.token.as_deref();
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::delete_session_in(&self.sessions.tictactoe, &req.session_id, token),
+            GameKind::ConnectFour => Self::delete_session_in(&self.sessions.connect_four, &req.session_id, token),
+            GameKind::Gomoku => Self::delete_session_in(&self.sessions.gomoku, &req.session_id, token),
+        }
+    }
+    
+    /// Joins a session as a spectator.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, name = %req.name))]
+    #[tool(description = "Watch a session without taking a seat: follow the board and its history, but make no moves. Returns your spectator ID and a token for stop_spectating; use watch_session to receive each move as it happens.")]
+    pub async fn spectate(
+        &self,
+        Parameters(req): Parameters<SpectateRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let kind = self.existing_kind(&req.session_id)?;
+        match kind {
+            GameKind::TicTacToe => Self::spectate_in(&self.sessions.tictactoe, kind, req),
+            GameKind::ConnectFour => Self::spectate_in(&self.sessions.connect_four, kind, req),
+            GameKind::Gomoku => Self::spectate_in(&self.sessions.gomoku, kind, req),
+        }
+    }
+
+    /// Waits for and returns a session's new events.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, from = req.from))]
+    #[tool(description = "Follow a session live: returns its events from the given one on, waiting up to wait_secs for a new one if there is none yet, together with the current board. Pass the returned next value as from to keep following. Read-only; anyone may watch.")]
+    pub async fn watch_session(
+        &self,
+        Parameters(req): Parameters<WatchSessionRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => Self::watch_session_in(&self.sessions.tictactoe, req).await,
+            GameKind::ConnectFour => Self::watch_session_in(&self.sessions.connect_four, req).await,
+            GameKind::Gomoku => Self::watch_session_in(&self.sessions.gomoku, req).await,
+        }
+    }
+
+    /// Leaves a session as a spectator.
+    #[instrument(skip(self, req), fields(session_id = %req.session_id, spectator_id = %req.spectator_id))]
+    #[tool(description = "Stop watching a session you joined with spectate. Requires the token spectate returned.")]
+    pub async fn stop_spectating(
+        &self,
+        Parameters(req): Parameters<StopSpectatingRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let token = req.token.as_deref();
+        let left = match self.existing_kind(&req.session_id)? {
+            GameKind::TicTacToe => self.sessions.tictactoe.remove_spectator_atomic(&req.session_id, &req.spectator_id, token),
+            GameKind::ConnectFour => self.sessions.connect_four.remove_spectator_atomic(&req.session_id, &req.spectator_id, token),
+            GameKind::Gomoku => self.sessions.gomoku.remove_spectator_atomic(&req.session_id, &req.spectator_id, token),
+        };
+        left.map_err(|e| McpError::invalid_params(e, None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(format!("Stopped watching session {}.", req.session_id))]))
+    }
+
+    /// Joins the matchmaking queue.
+    #[instrument(skip(self, req), fields(name = %req.name))]
+    #[tool(description = "Find an opponent without agreeing on a session ID: join the matchmaking queue, optionally choosing the game, the opponent type (human, agent or bot) and the opponent's rating range. When paired, a fresh session is created and you get its session ID, your seat, player ID and token. If nobody pairs with you within wait_secs, returns a ticket for check_queue.")]
+    pub async fn join_queue(
+        &self,
+        Parameters(req): Parameters<JoinQueueRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let seeker = Seeker {
+            name: req.name,
+            player_type: req.player_type,
+            rating: req.rating,
+            constraints: req.constraints,
+        };
+        let (ticket, pairing) = self.sessions.join_queue(seeker)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let pairing = match pairing {
+            Some(pairing) => {
+                // A bot opponent that moves first opens at once
+                self.sessions.play_bot_turns(&pairing.session_id).await
+                    .map_err(|e| McpError::internal_error(e, None))?;
+                Some(pairing)
+            }
+            None => self.wait_in_queue(&ticket, req.wait_secs).await?,
+        };
+        Ok(self.describe_ticket(&ticket, pairing))
+    }
+
+    /// Keeps waiting on a matchmaking ticket.
+    #[instrument(skip(self, req))]
+    #[tool(description = "Keep waiting in the matchmaking queue with the ticket join_queue returned. Returns your session, seat, player ID and token once you are paired, or the ticket again if nobody paired with you within wait_secs.")]
+    pub async fn check_queue(
+        &self,
+        Parameters(req): Parameters<QueueTicketRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let pairing = self.wait_in_queue(&req.ticket, req.wait_secs).await?;
+        Ok(self.describe_ticket(&req.ticket, pairing))
+    }
+
+    /// Leaves the matchmaking queue.
+    #[instrument(skip(self, req))]
+    #[tool(description = "Give up waiting in the matchmaking queue with the ticket join_queue returned.")]
+    pub async fn leave_queue(
+        &self,
+        Parameters(req): Parameters<LeaveQueueRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.sessions.lobby.leave(&req.ticket)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        Ok(CallToolResult::success(vec![Content::text("Left the matchmaking queue.")]))
+    }
+
+    /// Lists all available game sessions
+    #[instrument(skip(self))]
+    #[tool(description = "List all available game sessions to see which ones need players")]
+    pub async fn list_sessions(&self) -> Result<CallToolResult, McpError> {
+        info!("Listing all game sessions");
+        
+        let mut result = String::new();
+        let session_count = Self::describe_sessions(&self.sessions.tictactoe, &mut result)
+            + Self::describe_sessions(&self.sessions.connect_four, &mut result)
+            + Self::describe_sessions(&self.sessions.gomoku, &mut result);
+        
+        if session_count == 0 {
+            info!("No active sessions found");
+            return Ok(CallToolResult::success(vec![Content::text("No active game sessions")]));
+        }
+        
+        info!(session_count, "Listed available sessions");
+        Ok(CallToolResult::success(vec![Content::text(format!("Available game sessions:\n\n{}", result))]))
+    }
+
+    /// Play a game using elicitation
+    #[instrument(skip(self, peer, req), fields(session_id = ?req.session_id, player_name = %req.player_name))]
+    #[tool(description = "Play a complete game (tic-tac-toe, connect-four or gomoku). Without a session ID, waits in the matchmaking queue for an opponent of the same game. The agent will be prompted for moves interactively until the game ends.")]
+    pub async fn play_game(
+        &self,
+        peer: Peer<RoleServer>,
+        Parameters(req): Parameters<PlayGameRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let (kind, session_id, pairing) = match &req.session_id {
+            Some(session_id) => (self.resolve_kind(session_id, req.game), session_id.clone(), None),
+            None => {
+                let pairing = self.queue_for_game(&req).await?;
+                (pairing.game, pairing.session_id.clone(), Some(pairing))
+            }
+        };
+        match kind {
+            GameKind::TicTacToe => Self::play_game_in(&self.sessions.tictactoe, peer, session_id, req, pairing).await,
+            GameKind::ConnectFour => Self::play_game_in(&self.sessions.connect_four, peer, session_id, req, pairing).await,
+            GameKind::Gomoku => Self::play_game_in(&self.sessions.gomoku, peer, session_id, req, pairing).await,
+        }
+    }
+
+    // Auto-generate elicitation tools for type-safe LLM interaction
+    elicitation::elicit_tools! {
+        Position,
+        Column,
+        Player,
+    }
+}
+
+/// A ticket `play_game` waits on, taken out of the queue if the wait is
+/// dropped before a pairing.
+struct QueuedTicket<'a> {
+    lobby: &'a Lobby,
+    ticket: &'a str,
+}
+
+impl Drop for QueuedTicket<'_> {
+    fn drop(&mut self) {
+        // Fails harmlessly once the ticket is paired
+        if self.lobby.leave(self.ticket).is_ok() {
+            info!("Left the matchmaking queue before being paired");
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+//  Game-generic tool implementations
+// ─────────────────────────────────────────────────────────────
+
+impl GameServer {
+    /// Plays the bot replies due in a session, then reads it back.
+    ///
+    /// The bots search off the session lock, so other sessions and
+    /// requests carry on meanwhile.
+    async fn play_bot_turns<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+    ) -> Result<GameSession<G>, McpError> {
+        sessions.play_bot_turns(session_id).await
+            .map_err(|e| McpError::internal_error(e, None))?;
+        sessions.get_session(session_id)
+            .ok_or_else(|| McpError::internal_error("Session not found", None))
+    }
+
+    /// Registers a player in a session of any game.
+    #[instrument(skip(sessions, req), fields(game = G::NAME, session_id = %req.session_id, name = %req.name))]
+    async fn register_player_in<G: Game>(
+        sessions: &SessionManager<G>,
+        req: RegisterPlayerRequest,
+    ) -> Result<CallToolResult, McpError> {
+        info!(
+            session_id = %req.session_id,
+            name = %req.name,
+            player_type = ?req.player_type,
+            difficulty = ?req.difficulty,
+            "Registering player"
+        );
+
+        if req.difficulty.is_some() && req.player_type != PlayerType::Bot {
+            return Err(McpError::invalid_params("difficulty is only valid for bot players", None));
+        }
+
+        // Create session if it doesn't exist
+        if sessions.get_session(&req.session_id).is_none() {
+            info!(session_id = %req.session_id, time_control = %req.time_control, turn_order = %req.turn_order, "Creating new session");
+            match sessions.create_ordered_session(req.session_id.clone(), req.time_control, req.turn_order) {
+                Ok(_) => {
+                    if let Some(format) = req.match_format {
+                        sessions
+                            .start_match_atomic(&req.session_id, format)
+                            .map_err(|e| McpError::internal_error(e, None))?;
+                    }
+                }
+                // Someone else created it in the meantime; join theirs
+                Err(e) if sessions.get_session(&req.session_id).is_some() => {
+                    debug!(error = %e, "Session created concurrently");
+                }
+                Err(e) => return Err(McpError::internal_error(e, None)),
+            }
+        }
+
+        // Generate player ID
+        let player_id = format!("{}_{}", req.session_id, req.name.to_lowercase().replace(' ', "_"));
+
+        // Seat the player and take their token under one lock, so players
+        // joining at the same time don't trip over each other
+        let (mark, token) = sessions
+            .register_atomic(&req.session_id, player_id.clone(), req.name.clone(), req.player_type, req.difficulty)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        // A bot that joins on its own turn moves straight away
+        let session = Self::play_bot_turns(sessions, &req.session_id).await?;
+
+        // Bots are moved by the server and get no token
+        let token_str = token
+            .map(|token| format!("\nToken: {}\nKeep the token secret; pass it with every command you send.", token.as_str()))
+            .unwrap_or_default();
+        let message = format!(
+            "Registered as player {:?}!\nPlayer ID: {}{}\nSession: {}\n\n{}",
+            mark,
+            player_id,
+            token_str,
+            req.session_id,
+            session.game.render()
+        );
+
+        info!(
+            session_id = %req.session_id,
+            player_id = %player_id,
+            mark = ?mark,
+            "Player registered successfully"
+        );
+
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
+
+    /// Resets the board of a session of any game.
+    ///
+    /// During a match, or when the turn order swaps seats, the players
+    /// stay seated and trade seats; otherwise they are cleared. Either way
+    /// the next game starts through [`SessionManager::restart_game`].
+    #[instrument(skip(sessions, token), fields(game = G::NAME))]
+    async fn start_game_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        token: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found. Use register_player first.", None))?;
+        session.authorize_any(token)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        if session.closed {
+            return Err(McpError::invalid_params("Session is closed", None));
+        }
+
+        if let Some(match_play) = &session.match_play {
+            if session.match_over() {
+                return Err(McpError::invalid_params(
+                    format!("Match is over. {}", match_play.summary()),
+                    None,
+                ));
+            }
+            sessions.restart_game(session_id)
+                .map_err(|e| McpError::invalid_params(e, None))?;
+            let session = Self::play_bot_turns(sessions, session_id).await?;
+            let summary = session.match_play.as_ref().map(Match::summary).unwrap_or_default();
+            let message = format!(
+                "Next game of the match started! Match: {}\n{}",
+                summary,
+                session.game.render()
+            );
+            return Ok(CallToolResult::success(vec![Content::text(message)]));
+        }
+
+        // Seats that swap between games keep their players; otherwise everyone rejoins
+        let keep_players = session.turn_order.swap_seats;
+        if !keep_players {
+            session.clear_players();
+            sessions.update_session(session)
+                .map_err(|e| McpError::internal_error(e, None))?;
+        }
+        sessions.restart_game(session_id)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let session = Self::play_bot_turns(sessions, session_id).await?;
+
+        let message = if keep_players {
+            let name = |mark| {
+                session.player_with_mark(mark).map(|p| p.name.as_str()).unwrap_or("(waiting)")
+            };
+            format!(
+                "New game started! Seats swapped: X is {}, O is {}.\n{}",
+                name(Mark::X),
+                name(Mark::O),
+                session.game.render()
+            )
+        } else {
+            format!("New game started! Players can rejoin.\n{}", session.game.render())
+        };
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
+
+    /// Applies a player's choice in a session of any game.
+    #[instrument(skip(sessions, token), fields(game = G::NAME, choice = %choice))]
+    async fn make_move_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        player_id: &str,
+        token: Option<&str>,
+        choice: G::Choice,
+    ) -> Result<CallToolResult, McpError> {
+        debug!(session_id, player_id, choice = %choice, "Processing move");
+
+        let mut session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        session.authorize(player_id, token)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        // A flagged player loses before the move is considered
+        if let Some(loser) = session.check_clock().map_err(|e| McpError::internal_error(e, None))? {
+            sessions.update_session(session.clone())
+                .map_err(|e| McpError::internal_error(e, None))?;
+            let message = format!(
+                "Player {:?} ran out of time. {}\n\n{}",
+                loser,
+                session.game.status_string(),
+                session.game.render()
+            );
+            return Err(McpError::invalid_params(message, None));
+        }
+
+        // Make the move (validates turn and choice)
+        session.make_move(player_id, choice)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        sessions.update_session(session)
+            .map_err(|e| McpError::internal_error(e, None))?;
+        // Let a bot opponent reply
+        let session = Self::play_bot_turns(sessions, session_id).await?;
+
+        let status_msg = session.game.status_string();
 
-#[tool_router]
-impl GameServer {
-    /// Creates a new game server with shared session manager.
-    #[instrument]
-    pub fn with_sessions(sessions: SessionManager) -> Self {
-        info!("Creating game server with shared session manager");
-        Self {
-            sessions,
-            tool_router: Self::tool_router(),
-        }
+        info!(
+            session_id,
+            player_id,
+            choice = %choice,
+            status = %status_msg,
+            "Move completed successfully"
+        );
+
+        let message = format!("{}\n\n{}", status_msg, session.game.render());
+        Ok(CallToolResult::success(vec![Content::text(message)]))
     }
 
-    /// Creates a new game server.
-    pub fn new() -> Self {
-        info!("Creating game server with session management");
-        Self::with_sessions(SessionManager::new())
+    /// Asks for an undo in a session of any game.
+    #[instrument(skip(sessions, token), fields(game = G::NAME))]
+    async fn request_undo_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        player_id: &str,
+        token: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        session.authorize(player_id, token)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        session.request_undo(player_id)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        sessions.update_session(session)
+            .map_err(|e| McpError::internal_error(e, None))?;
+        let session = Self::play_bot_turns(sessions, session_id).await?;
+
+        let status_msg = if session.undo_request.is_some() {
+            "Undo requested. Waiting for your opponent to respond.".to_string()
+        } else {
+            format!("Undo accepted. {}", session.game.status_string())
+        };
+
+        let message = format!("{}\n\n{}", status_msg, session.game.render());
+        Ok(CallToolResult::success(vec![Content::text(message)]))
     }
 
-    /// Registers a player in a session.
-    #[instrument(skip(self, req), fields(session_id = %req.session_id, name = %req.name))]
-    #[tool(description = "Register as a player in a game session. Creates session if it doesn't exist.")]
-    pub async fn register_player(
-        &self,
-        Parameters(req): Parameters<RegisterPlayerRequest>,
+    /// Answers an undo request in a session of any game.
+    #[instrument(skip(sessions, req), fields(game = G::NAME, session_id = %req.session_id, player_id = %req.player_id))]
+    async fn respond_undo_in<G: Game>(
+        sessions: &SessionManager<G>,
+        req: RespondUndoRequest,
     ) -> Result<CallToolResult, McpError> {
-        info!(
-            session_id = %req.session_id,
-            name = %req.name,
-            player_type = ?req.player_type,
-            "Registering player"
-        );
+        let mut session = sessions.get_session(&req.session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        session.authorize(&req.player_id, req.token.as_deref())
+            .map_err(|e| McpError::invalid_params(e, None))?;
 
-        // Create session if it doesn't exist
-        if self.sessions.get_session(&req.session_id).is_none() {
-            info!(session_id = %req.session_id, "Creating new session");
-            self.sessions
-                .create_session(req.session_id.clone())
-                .map_err(|e| McpError::internal_error(e, None))?;
-        }
+        session.respond_undo(&req.player_id, req.accept)
+            .map_err(|e| McpError::invalid_params(e, None))?;
 
-        // Get session and register player
-        let mut session = self.sessions.get_session(&req.session_id)
-            .ok_or_else(|| McpError::internal_error("Session not found after creation", None))?;
+        sessions.update_session(session)
+            .map_err(|e| McpError::internal_error(e, None))?;
+        let session = Self::play_bot_turns(sessions, &req.session_id).await?;
 
-        // Generate player ID
-        let player_id = format!("{}_{}", req.session_id, req.name.to_lowercase().replace(' ', "_"));
-        
-        let mark = session
-            .register_player(player_id.clone(), req.name.clone(), req.player_type)
+        let status_msg = if req.accept {
+            format!("Undo accepted. {}", session.game.status_string())
+        } else {
+            format!("Undo declined. {}", session.game.status_string())
+        };
+
+        let message = format!("{}\n\n{}", status_msg, session.game.render());
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
+
+    /// Resigns a game in a session of any game.
+    ///
+    /// The position given up is judged off the session lock.
+    #[instrument(skip(sessions, token), fields(game = G::NAME))]
+    async fn resign_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        player_id: &str,
+        token: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        session.authorize(player_id, token)
             .map_err(|e| McpError::invalid_params(e, None))?;
 
-        self.sessions.update_session(session.clone());
+        let verdict = sessions.resign(session_id, player_id).await
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::internal_error("Session not found", None))?;
 
         let message = format!(
-            "Registered as player {:?}!\nPlayer ID: {}\nSession: {}\n\n{}",
-            mark,
-            player_id,
-            req.session_id,
-            session.game.board().display()
+            "{}\nPosition at resignation (your side): {}\n\n{}",
+            session.game.status_string(),
+            verdict,
+            session.game.render()
         );
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
 
-        info!(
-            session_id = %req.session_id,
-            player_id = %player_id,
-            mark = ?mark,
-            "Player registered successfully"
-        );
+    /// Offers a draw in a session of any game.
+    #[instrument(skip(sessions, token), fields(game = G::NAME))]
+    async fn offer_draw_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        player_id: &str,
+        token: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        session.authorize(player_id, token)
+            .map_err(|e| McpError::invalid_params(e, None))?;
 
+        session.offer_draw(player_id)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        sessions.update_session(session)
+            .map_err(|e| McpError::internal_error(e, None))?;
+        // A bot opponent answers the offer straight away
+        let session = Self::play_bot_turns(sessions, session_id).await?;
+
+        let status_msg = if session.game.draw_offer().is_some() {
+            "Draw offered. Waiting for your opponent to respond.".to_string()
+        } else if session.game.is_over() {
+            format!("Draw accepted. {}", session.game.status_string())
+        } else {
+            format!("Draw declined. {}", session.game.status_string())
+        };
+
+        let message = format!("{}\n\n{}", status_msg, session.game.render());
         Ok(CallToolResult::success(vec![Content::text(message)]))
     }
 
-    /// Starts a new game in a session.
-    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
-    #[tool(description = "Start a new tic-tac-toe game in the session. Player X goes first.")]
-    pub async fn start_game(
-        &self,
-        Parameters(req): Parameters<GetBoardRequest>,
+    /// Answers a draw offer in a session of any game.
+    #[instrument(skip(sessions, req), fields(game = G::NAME, session_id = %req.session_id, player_id = %req.player_id))]
+    async fn respond_draw_in<G: Game>(
+        sessions: &SessionManager<G>,
+        req: RespondDrawRequest,
     ) -> Result<CallToolResult, McpError> {
-        info!(session_id = %req.session_id, "Starting new game");
-        
-        let mut session = self.sessions.get_session(&req.session_id)
-            .ok_or_else(|| McpError::invalid_params("Session not found. Use register_player first.", None))?;
+        let mut session = sessions.get_session(&req.session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        session.authorize(&req.player_id, req.token.as_deref())
+            .map_err(|e| McpError::invalid_params(e, None))?;
 
-        // Reset the game board and clear players for fresh start
-        session.game = crate::games::tictactoe::Game::new().into();
-        session.player_x = None;
-        session.player_o = None;
-        self.sessions.update_session(session.clone());
-        
-        let message = format!("New game started! Players can rejoin.\n{}", session.game.board().display());
+        session.respond_draw(&req.player_id, req.accept)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+
+        sessions.update_session(session)
+            .map_err(|e| McpError::internal_error(e, None))?;
+        let session = Self::play_bot_turns(sessions, &req.session_id).await?;
+
+        let status_msg = if req.accept {
+            format!("Draw accepted. {}", session.game.status_string())
+        } else {
+            format!("Draw declined. {}", session.game.status_string())
+        };
+
+        let message = format!("{}\n\n{}", status_msg, session.game.render());
         Ok(CallToolResult::success(vec![Content::text(message)]))
     }
 
-    /// Makes a move at the given position.
-    #[instrument(skip(self, req), fields(session_id = %req.session_id, player_id = %req.player_id, position = ?req.position))]
-    #[tool(description = "Make a move at the specified position. Use Position enum (TopLeft, TopCenter, TopRight, MiddleLeft, Center, MiddleRight, BottomLeft, BottomCenter, BottomRight).")]
-    pub async fn make_move(
-        &self,
-        Parameters(req): Parameters<MakeMoveRequest>,
+    /// Closes a session of any game.
+    #[instrument(skip(sessions, token), fields(game = G::NAME))]
+    fn close_session_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        token: Option<&str>,
     ) -> Result<CallToolResult, McpError> {
-        debug!(
-            session_id = %req.session_id,
-            player_id = %req.player_id,
-            position = ?req.position,
-            "Processing move"
+        sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?
+            .authorize_any(token)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        sessions.close_session(session_id)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::internal_error("Session not found after closing", None))?;
+
+        let message = format!(
+            "Session {} closed. {}\n\n{}",
+            session_id,
+            session.game.status_string(),
+            session.game.render()
         );
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
 
-        let mut session = self.sessions.get_session(&req.session_id)
-            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+    /// Deletes a session of any game.
+    #[instrument(skip(sessions, token), fields(game = G::NAME))]
+    fn delete_session_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        token: Option<&str>,
+    ) -> Result<CallToolResult, McpError> {
+        sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?
+            .authorize_any(token)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        sessions.delete_session(session_id)
+            .map_err(|e| McpError::invalid_params(e, None))?;
 
-        // Make the move (validates turn and position)
-        session.make_move(&req.player_id, req.position)
+        info!(session_id, "Session deleted");
+        Ok(CallToolResult::success(vec![Content::text(format!("Session {} deleted.", session_id))]))
+    }
+
+    /// Adds a spectator to a session of any game.
+    #[instrument(skip(sessions, req), fields(game = G::NAME, session_id = %req.session_id, name = %req.name))]
+    fn spectate_in<G: Game>(
+        sessions: &SessionManager<G>,
+        kind: GameKind,
+        req: SpectateRequest,
+    ) -> Result<CallToolResult, McpError> {
+        let spectator_id = format!("{}_watch_{}", req.session_id, req.name.to_lowercase().replace(' ', "_"));
+        let token = sessions.add_spectator_atomic(&req.session_id, spectator_id.clone(), req.name.clone())
             .map_err(|e| McpError::invalid_params(e, None))?;
+        let session = sessions.get_session(&req.session_id)
+            .ok_or_else(|| McpError::internal_error("Session not found after joining", None))?;
 
-        self.sessions.update_session(session.clone());
+        info!(spectator_id = %spectator_id, "Spectator joined");
 
-        let status_msg = session.game.status_string();
+        let message = format!(
+            "Watching session {}.\nSpectator ID: {}\nToken: {}\nKeep the token secret; pass it to stop_spectating.\nGame: {}\nEvents so far: {}\nStatus: {}\n\n{}",
+            req.session_id,
+            spectator_id,
+            token.as_str(),
+            kind,
+            session.events().len(),
+            session.game.status_string(),
+            session.game.render()
+        );
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
 
-        info!(
-            session_id = %req.session_id,
-            player_id = %req.player_id,
-            position = ?req.position,
-            status = %status_msg,
-            "Move completed successfully"
+    /// Waits for new events in a session of any game.
+    #[instrument(skip(sessions, req), fields(game = G::NAME, session_id = %req.session_id, from = req.from))]
+    async fn watch_session_in<G: Game>(
+        sessions: &SessionManager<G>,
+        req: WatchSessionRequest,
+    ) -> Result<CallToolResult, McpError> {
+        let wait = req.wait_secs.unwrap_or(DEFAULT_WATCH_SECS).min(MAX_WATCH_SECS);
+        let from = req.from as usize;
+        let session = sessions
+            .wait_for_events(&req.session_id, from, std::time::Duration::from_secs(wait))
+            .await
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+
+        let events = session.events();
+        let lines: Vec<String> = events
+            .get(from..)
+            .unwrap_or_default()
+            .iter()
+            .map(|logged| format!("#{} {}", logged.seq, logged.event))
+            .collect();
+        let events_str = if lines.is_empty() {
+            format!("No new events in {}s.", wait)
+        } else {
+            lines.join("\n")
+        };
+
+        let message = format!(
+            "{}\n\nNext: {}\nStatus: {}\n\n{}",
+            events_str,
+            events.len(),
+            session.game.status_string(),
+            session.game.render()
         );
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
 
-        let message = format!("{}\n\n{}", status_msg, session.game.board().display());
+    /// Describes the match of a session of any game.
+    #[instrument(skip(sessions), fields(game = G::NAME))]
+    fn get_match_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+    ) -> Result<CallToolResult, McpError> {
+        let session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+        let match_play = session.match_play
+            .ok_or_else(|| McpError::invalid_params("No match is being played in this session", None))?;
+
+        let name_of = |player_id: &str| {
+            match_play.score(player_id)
+                .map_or_else(|| player_id.to_string(), |score| score.name.clone())
+        };
+        let games: Vec<String> = match_play.games.iter().map(|game| {
+            let result = game.winner.as_deref()
+                .map_or_else(|| "draw".to_string(), |winner| format!("{} won", name_of(winner)));
+            format!(
+                "Game {}: {} (X) vs {} (O), {}",
+                game.game_number + 1,
+                name_of(&game.player_x),
+                name_of(&game.player_o),
+                result
+            )
+        }).collect();
+        let status = match match_play.result() {
+            Some(MatchResult::Won(player_id)) => format!("Match over: {} wins", name_of(&player_id)),
+            Some(MatchResult::Drawn) => "Match over: drawn".to_string(),
+            None => format!("In progress, game {} of the match", match_play.games_played() + 1),
+        };
+
+        let message = format!(
+            "Session: {}\nMatch: {}\nStatus: {}\n\n{}",
+            session_id,
+            match_play.summary(),
+            status,
+            games.join("\n")
+        );
         Ok(CallToolResult::success(vec![Content::text(message)]))
     }
 
-    /// Gets the current board state.
-    #[instrument(skip(self, req), fields(session_id = %req.session_id))]
-    #[tool(description = "Get the current board state and game status")]
-    pub async fn get_board(
-        &self,
-        Parameters(req): Parameters<GetBoardRequest>,
+    /// Describes the board and players of a session of any game.
+    #[instrument(skip(sessions), fields(game = G::NAME))]
+    fn get_board_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
     ) -> Result<CallToolResult, McpError> {
-        debug!(session_id = %req.session_id, "Getting board state");
+        debug!(session_id, "Getting board state");
+
+        // Looking at the board is enough to flag a player out of time
+        sessions.check_clock_atomic(session_id)
+            .map_err(|e| McpError::invalid_params(e, None))?;
         
-        let session = self.sessions.get_session(&req.session_id)
+        let session = sessions.get_session(session_id)
             .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
 
         let player_x_name = session.player_x.as_ref()
@@ -209,50 +1280,113 @@ impl GameServer {
             .map(|p| format!("{:?}", p))
             .unwrap_or_else(|| "Game Over".to_string());
 
+        let undo_str = session.undo_request
+            .map(|mark| format!("\nUndo requested by: {:?}", mark))
+            .unwrap_or_default();
+        let draw_str = session.game.draw_offer()
+            .map(|mark| format!("\nDraw offered by: {:?}", mark))
+            .unwrap_or_default();
+        let clock_str = session.clock.summary()
+            .map(|summary| format!("\nClocks ({}): {}", session.clock.control(), summary))
+            .unwrap_or_default();
+        let match_str = session.match_play.as_ref()
+            .map(|m| format!("\nMatch: {}", m.summary()))
+            .unwrap_or_default();
+        let spectators_str = if session.spectators.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<&str> = session.spectators.iter().map(|s| s.name.as_str()).collect();
+            format!("\nSpectators: {}", names.join(", "))
+        };
+
         let message = format!(
-            "Session: {}\nPlayer X: {}\nPlayer O: {}\nCurrent player: {}\nStatus: {}\nMoves: {}\n\n{}",
-            req.session_id,
+            "Session: {} ({})\nGame: {} (game {}, {:?} opened)\nPlayer X: {}\nPlayer O: {}\nCurrent player: {}\nStatus: {}\nMoves: {}{}{}{}{}{}\n\n{}",
+            session_id,
+            session.status,
+            G::NAME,
+            session.game_number + 1,
+            session.first_mover(),
             player_x_name,
             player_o_name,
             current_player_str,
             session.game.status_string(),
-            session.game.history().len(),
-            session.game.board().display()
+            session.game.move_count(),
+            undo_str,
+            draw_str,
+            clock_str,
+            match_str,
+            spectators_str,
+            session.game.render()
         );
         
         Ok(CallToolResult::success(vec![Content::text(message)]))
     }
-    
-    /// Lists all available game sessions
-    #[instrument(skip(self))]
-    #[tool(description = "List all available game sessions to see which ones need players")]
-    pub async fn list_sessions(&self) -> Result<CallToolResult, McpError> {
-        info!("Listing all game sessions");
-        
-        let session_ids: Vec<String> = self.sessions.list_sessions();
-        
-        if session_ids.is_empty() {
-            info!("No active sessions found");
-            return Ok(CallToolResult::success(vec![Content::text("No active game sessions")]));
-        }
-        
-        let mut result = String::from("Available game sessions:\n\n");
+
+    /// Evaluates every legal move in a session of any game.
+    ///
+    /// The search runs on the blocking thread pool over a copy of the
+    /// game, so neither the session lock nor the async executor waits for it.
+    #[instrument(skip(sessions), fields(game = G::NAME))]
+    async fn analyze_position_in<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+    ) -> Result<CallToolResult, McpError> {
+        let session = sessions.get_session(session_id)
+            .ok_or_else(|| McpError::invalid_params("Session not found", None))?;
+
+        let to_move = session.game.to_move()
+            .ok_or_else(|| McpError::invalid_params("Game is over", None))?;
+        let game = session.game.in_progress()
+            .ok_or_else(|| McpError::internal_error("Game state could not be rebuilt for analysis", None))?;
+        let analysis = tokio::task::spawn_blocking(move || analysis::analyze::<G>(&game))
+            .await
+            .map_err(|e| McpError::internal_error(format!("Analysis failed: {}", e), None))?;
+
+        let horizon = match Solver::for_game::<G>().max_depth() {
+            Some(depth) => format!("searched {} plies ahead", depth),
+            None => "solved to the end".to_string(),
+        };
+        let lines: Vec<String> = analysis.iter().map(|choice| choice.to_string()).collect();
+
+        info!(to_move = ?to_move, choices = analysis.len(), "Position analyzed");
+
+        let message = format!(
+            "Analysis for player {:?} ({}, {}):\n\n{}\n\n{}",
+            to_move,
+            G::NAME,
+            horizon,
+            lines.join("\n"),
+            session.game.render()
+        );
+
+        Ok(CallToolResult::success(vec![Content::text(message)]))
+    }
+
+    /// Appends a description of every session in the manager to `out`.
+    ///
+    /// Returns the number of sessions described.
+    #[instrument(skip(sessions, out), fields(game = G::NAME))]
+    fn describe_sessions<G: Game>(sessions: &SessionManager<G>, out: &mut String) -> usize {
+        let session_ids: Vec<String> = sessions.list_sessions();
         
         for session_id in &session_ids {
-            if let Some(session) = self.sessions.get_session(session_id) {
+            if let Some(session) = sessions.get_session(session_id) {
                 let has_x = session.player_x.is_some();
                 let has_o = session.player_o.is_some();
                 let player_count = if has_x { 1 } else { 0 } + if has_o { 1 } else { 0 };
-                let needs_players = player_count < 2;
-                let status = if needs_players {
-                    format!("⏳ Waiting for {} more player(s)", 2 - player_count)
-                } else {
-                    "✅ Ready to play".to_string()
+                let status = match session.status {
+                    SessionStatus::WaitingForPlayers => {
+                        format!("⏳ Waiting for {} more player(s)", 2 - player_count)
+                    }
+                    SessionStatus::Active => "✅ In progress".to_string(),
+                    SessionStatus::Finished => "🏁 Finished".to_string(),
+                    SessionStatus::Abandoned => "💤 Abandoned".to_string(),
                 };
                 
-                result.push_str(&format!(
-                    "Session: {}\n  Players: {}/{}\n  Status: {}\n",
+                out.push_str(&format!(
+                    "Session: {}\n  Game: {}\n  Players: {}/{}\n  Status: {}\n",
                     session_id,
+                    G::NAME,
                     player_count,
                     2,
                     status
@@ -260,61 +1394,107 @@ impl GameServer {
                 
                 // Show player details
                 if let Some(px) = &session.player_x {
-                    result.push_str(&format!("    - {} (X)\n", px.name));
+                    out.push_str(&format!("    - {} (X)\n", px.name));
                 }
                 if let Some(po) = &session.player_o {
-                    result.push_str(&format!("    - {} (O)\n", po.name));
+                    out.push_str(&format!("    - {} (O)\n", po.name));
+                }
+                if !session.spectators.is_empty() {
+                    out.push_str(&format!("  Spectators: {}\n", session.spectators.len()));
                 }
-                result.push('\n');
+                out.push('\n');
             }
         }
         
-        info!(session_count = session_ids.len(), "Listed available sessions");
-        Ok(CallToolResult::success(vec![Content::text(result)]))
+        session_ids.len()
     }
 
-    /// Play a game of tic-tac-toe using elicitation
-    #[instrument(skip(self, peer, req), fields(session_id = %req.session_id, player_name = %req.player_name))]
-    #[tool(description = "Play a complete game of tic-tac-toe. The agent will be prompted for moves interactively until the game ends.")]
-    pub async fn play_game(
-        &self,
-        peer: Peer<RoleServer>,
-        Parameters(req): Parameters<PlayGameRequest>,
-    ) -> Result<CallToolResult, McpError> {
-        
-        
-        info!(session_id = %req.session_id, player_name = %req.player_name, "Starting elicitation-based game");
-        
+    /// Creates the session if needed and seats an agent for `play_game`,
+    /// with a bot opponent if one was requested.
+    ///
+    /// Returns the agent's player ID and seat.
+    fn seat_for_game<G: Game>(
+        sessions: &SessionManager<G>,
+        session_id: &str,
+        req: &PlayGameRequest,
+    ) -> Result<(String, Mark), McpError> {
         // Register the agent player
-        let player_id = format!("{}_{}", req.session_id, req.player_name.to_lowercase().replace(' ', "_"));
+        let player_id = format!("{}_{}", session_id, req.player_name.to_lowercase().replace(' ', "_"));
         
         // Get or create session
-        if self.sessions.get_session(&req.session_id).is_none() {
-            info!(session_id = %req.session_id, "Creating new session for game");
-            self.sessions
-                .create_session(req.session_id.clone())
+        if sessions.get_session(session_id).is_none() {
+            info!(session_id, time_control = %req.time_control, turn_order = %req.turn_order, "Creating new session for game");
+            sessions
+                .create_ordered_session(session_id.to_string(), req.time_control, req.turn_order)
                 .map_err(|e: String| McpError::internal_error(e, None))?;
+            if let Some(format) = req.match_format {
+                sessions
+                    .start_match_atomic(session_id, format)
+                    .map_err(|e| McpError::internal_error(e, None))?;
+            }
         }
         
         // Register player atomically (thread-safe)
-        let mark = self.sessions
-            .register_player_atomic(&req.session_id, player_id.clone(), req.player_name.clone(), PlayerType::Agent)
+        let mark = sessions
+            .register_player_atomic(session_id, player_id.clone(), req.player_name.clone(), PlayerType::Agent)
             .map_err(|e| {
                 error!(error = %e, "Failed to register player");
                 let msg = format!("Failed to register: {}", e);
                 McpError::invalid_params(msg, None)
             })?;
         
+        // Seat the requested bot in the other chair
+        if let Some(difficulty) = req.bot_opponent {
+            let bot_id = format!("{}_bot", session_id);
+            let bot_mark = sessions
+                .register_bot_atomic(session_id, bot_id, format!("Bot ({})", difficulty), difficulty)
+                .map_err(|e| {
+                    error!(error = %e, "Failed to seat bot opponent");
+                    McpError::invalid_params(format!("Failed to seat bot: {}", e), None)
+                })?;
+            info!(difficulty = %difficulty, mark = ?bot_mark, "Bot opponent seated");
+        }
+
+        Ok((player_id, mark))
+    }
+
+    /// Plays a complete game of any kind using elicitation.
+    ///
+    /// A player paired through the lobby is already seated; otherwise the
+    /// session is created if needed and the player registered.
+    #[instrument(skip(sessions, peer, req, pairing), fields(game = G::NAME, player_name = %req.player_name))]
+    async fn play_game_in<G: Game>(
+        sessions: &SessionManager<G>,
+        peer: Peer<RoleServer>,
+        session_id: String,
+        req: PlayGameRequest,
+        pairing: Option<Pairing>,
+    ) -> Result<CallToolResult, McpError> {
+        info!(session_id = %session_id, player_name = %req.player_name, "Starting elicitation-based game");
+
+        let (player_id, mark) = match pairing {
+            Some(pairing) => (pairing.player_id, pairing.mark),
+            None => Self::seat_for_game(sessions, &session_id, &req)?,
+        };
+
         info!(player_id = %player_id, mark = ?mark, "Agent registered, entering elicitation loop");
-        
+
         // Game loop - continue until game is over
         loop {
-            // Get fresh session state at start of each iteration
-            let mut session = self.sessions.get_session(&req.session_id)
-                .ok_or_else(|| McpError::internal_error("Session not found", None))?;
+            // Let a bot opponent move, then get fresh session state
+            let session = Self::play_bot_turns(sessions, &session_id).await?;
             
             // Check if game is over
             if session.game.is_over() {
+                let annotations: Vec<String> = sessions.annotations(&session_id).await
+                    .map_err(|e| McpError::internal_error(e, None))?
+                    .iter()
+                    .map(|m| m.to_string())
+                    .collect();
+                let match_str = session.match_play.as_ref()
+                    .map(|m| format!("\n\nMatch: {}", m.summary()))
+                    .unwrap_or_default();
+                
                 if let Some(winner) = session.game.winner() {
                     let winner_name = if winner == mark {
                         req.player_name.clone()
@@ -323,51 +1503,77 @@ impl GameServer {
                     };
                     
                     let message = format!(
-                        "🎉 Game Over! {} wins!\n\nFinal Board:\n{}\n\nMoves: {}",
+                        "🎉 Game Over! {} wins!\n\nFinal Board:\n{}\n\nMoves: {}\n\nAnnotated moves:\n{}{}",
                         winner_name,
-                        session.game.board().display(),
-                        session.game.history().len()
+                        session.game.render(),
+                        session.game.move_count(),
+                        annotations.join("\n"),
+                        match_str
                     );
                     
-                    tracing::info!(winner = ?winner, moves = session.game.history().len(), "Game ended with winner");
-                    self.sessions.update_session(session);
+                    tracing::info!(winner = ?winner, moves = session.game.move_count(), "Game ended with winner");
                     return Ok(CallToolResult::success(vec![Content::text(message)]));
                 } else {
                     let message = format!(
-                        "🤝 Game Over! It's a draw.\n\nFinal Board:\n{}\n\nMoves: {}",
-                        session.game.board().display(),
-                        session.game.history().len()
+                        "🤝 Game Over! It's a draw.\n\nFinal Board:\n{}\n\nMoves: {}\n\nAnnotated moves:\n{}{}",
+                        session.game.render(),
+                        session.game.move_count(),
+                        annotations.join("\n"),
+                        match_str
                     );
                     
-                    tracing::info!(moves = session.game.history().len(), "Game ended in draw");
-                    self.sessions.update_session(session);
+                    tracing::info!(moves = session.game.move_count(), "Game ended in draw");
                     return Ok(CallToolResult::success(vec![Content::text(message)]));
                 }
             }
             
+            // A closed or abandoned session takes no more moves
+            if session.closed || session.status == SessionStatus::Abandoned {
+                return Ok(Self::session_ended(&session));
+            }
+
             // Check if it's our turn
             if !session.is_players_turn(&player_id) {
                 // Wait for opponent's move (agent vs agent mode)
                 tracing::info!(mark = ?mark, "Not our turn, waiting for opponent");
                 // Don't update - we haven't modified anything
                 
-                // Poll for opponent's move
-                let max_polls = 300; // 5 minutes (1 second per poll)
+                // Poll for opponent's move (1 second per poll); their clock
+                // ends the wait if it runs out first
+                let max_polls = session.clock.remaining(mark.opponent())
+                    .map_or(MAX_OPPONENT_WAIT_SECS, |left| (left.as_secs() + 1).max(MAX_OPPONENT_WAIT_SECS));
+                let mut done_waiting = false;
                 for poll_count in 0..max_polls {
                     tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+                    if let Some(loser) = sessions.check_clock_atomic(&session_id)
+                        .map_err(|e| McpError::internal_error(e, None))?
+                    {
+                        tracing::info!(loser = ?loser, poll_count, "Opponent ran out of time");
+                        done_waiting = true;
+                        break;
+                    }
                     
                     // Refresh session state
-                    let refreshed_session = self.sessions.get_session(&req.session_id)
+                    let refreshed_session = sessions.get_session(&session_id)
                         .ok_or_else(|| McpError::internal_error("Session disappeared", None))?;
                     
                     // Check if game ended while we were waiting
                     if refreshed_session.game.is_over() {
+                        done_waiting = true;
                         break; // Exit to outer loop to handle game end
                     }
+
+                    // A closed or abandoned session takes no more moves
+                    if refreshed_session.closed || refreshed_session.status == SessionStatus::Abandoned {
+                        tracing::info!(status = %refreshed_session.status, poll_count, "Session ended while waiting");
+                        return Ok(Self::session_ended(&refreshed_session));
+                    }
                     
                     // Check if it's now our turn
                     if refreshed_session.is_players_turn(&player_id) {
                         tracing::info!(poll_count, "Opponent moved, now our turn");
+                        done_waiting = true;
                         break; // Exit poll loop, continue to our move
                     }
                     
@@ -375,6 +1581,18 @@ impl GameServer {
                         tracing::debug!(poll_count, "Still waiting for opponent");
                     }
                 }
+
+                // Bounded, so an opponent who walks away doesn't hold the call open forever
+                if !done_waiting {
+                    tracing::warn!(max_polls, "Opponent did not move in time, giving up");
+                    let message = format!(
+                        "Stopped waiting: the opponent has not moved for {} seconds. Session {} stays open; use get_board to follow it.\n\n{}",
+                        max_polls,
+                        session_id,
+                        session.game.render()
+                    );
+                    return Ok(CallToolResult::success(vec![Content::text(message)]));
+                }
                 
                 // Loop continues to check game status and make our move
                 continue;
@@ -384,107 +1602,129 @@ impl GameServer {
             tracing::info!(mark = ?mark, "Agent's turn - entering elicitation walled garden");
             
             // Elicitation + Validation loop (demonstrates composition)
-            // Elicitation ensures TYPE safety (Position enum)
-            // Validation ensures SEMANTIC correctness (square is empty)
-            let position = loop {
-                // THE ONLY WAY TO GET A POSITION: Through filtered elicitation
-                // Server wraps Position::valid_moves into the elicitation call stack
-                let candidate = self.elicit_position_filtered(peer.clone(), &req.session_id)
-                    .await?;
+            // Elicitation ensures TYPE safety (G::Choice enum)
+            // Validation ensures SEMANTIC correctness (choice is legal)
+            let time_left = session.clock.remaining(mark);
+            let choice = loop {
+                // THE ONLY WAY TO GET A CHOICE: Through filtered elicitation
+                // Server wraps the legal choices into the elicitation call stack
+                let elicitation = Self::elicit_choice_filtered(sessions, peer.clone(), &session_id);
+                let candidate = match time_left {
+                    Some(time_left) => match tokio::time::timeout(time_left, elicitation).await {
+                        Ok(candidate) => candidate?,
+                        Err(_) => {
+                            tracing::warn!(mark = ?mark, "Agent ran out of time while thinking");
+                            sessions.check_clock_atomic(&session_id)
+                                .map_err(|e| McpError::internal_error(e, None))?;
+                            break None;
+                        }
+                    },
+                    None => elicitation.await?,
+                };
                 
-                tracing::info!(position = ?candidate, "Position elicited via framework Select paradigm");
+                tracing::info!(choice = %candidate, "Choice elicited via framework Select paradigm");
                 
-                // Validate against board state (composition of elicitation + contracts)
+                // Validate against game state (composition of elicitation + contracts)
                 // Note: With filtering above, this should always pass, but defensive check
-                let session = self.sessions.get_session(&req.session_id)
+                let session = sessions.get_session(&session_id)
                     .ok_or_else(|| McpError::internal_error("Session disappeared", None))?;
                     
-                let board = session.game.board();
-                if board.is_empty(candidate) {
-                    tracing::debug!(position = ?candidate, "Position validated as empty");
-                    break candidate;
+                if session.game.legal_choices().contains(&candidate) {
+                    tracing::debug!(choice = %candidate, "Choice validated as legal");
+                    break Some(candidate);
                 } else {
                     tracing::warn!(
-                        position = ?candidate,
-                        "Position occupied despite filtering - retrying"
+                        choice = %candidate,
+                        "Choice illegal despite filtering - retrying"
                     );
                     continue;
                 }
             };
             
-            tracing::info!(position = ?position, "Position elicited via framework Select paradigm");
-            
+            // Out of time: the outer loop reports the loss
+            let Some(choice) = choice else {
+                continue;
+            };
+
             // Elicitation guarantees type safety, validation loop ensures semantic correctness
             // Session API handles final validation + typestate transitions
-            match session.make_move(&player_id, position) {
+            // (atomically, so the clocks and registrations stay in step)
+            match sessions.make_move_atomic(&session_id, &player_id, choice) {
                 Ok(()) => {
-                    tracing::info!(position = ?position, "Move executed - typestate transition complete");
+                    tracing::info!(choice = %choice, "Move executed - typestate transition complete");
                 }
                 Err(e) => {
-                    // Should not happen - we validated above - but defensive
-                    tracing::error!(error = %e, position = ?position, "Move rejected despite validation");
+                    // Should not happen - we validated above - unless time ran out
+                    let session = sessions.get_session(&session_id)
+                        .ok_or_else(|| McpError::internal_error("Session disappeared", None))?;
+                    if session.game.is_over() {
+                        tracing::warn!(error = %e, "Move rejected, game already over");
+                        continue;
+                    }
+                    tracing::error!(error = %e, choice = %choice, "Move rejected despite validation");
                     return Err(McpError::internal_error(
                         format!("Move rejected: {}", e),
                         None,
                     ));
                 }
             }
-            
-            // Update game state atomically (preserves player registrations)
-            self.sessions.update_game_atomic(&req.session_id, session.game)
-                .map_err(|e| McpError::internal_error(e, None))?;
         }
     }
 
-    /// Elicit a position with board-state filtering (walled garden pattern).
+    /// Reports a session that ended before its game did.
+    fn session_ended<G: Game>(session: &GameSession<G>) -> CallToolResult {
+        let reason = if session.status == SessionStatus::Abandoned {
+            "was abandoned after sitting idle"
+        } else {
+            "was closed"
+        };
+        let message = format!(
+            "Session {} {} before the game finished.\n\nFinal Board:\n{}",
+            session.id,
+            reason,
+            session.game.render()
+        );
+        CallToolResult::success(vec![Content::text(message)])
+    }
+
+    /// Elicit a choice with game-state filtering (walled garden pattern).
     ///
     /// This demonstrates the pattern for context-aware selection:
-    /// 1. Get current board state
-    /// 2. Filter Position::ALL to only valid (empty) squares  
-    /// 3. Wrap in ValidPositions view struct (derives Elicit)
-    /// 4. Call elicitation on the view - framework handles the rest
+    /// 1. Get current game state
+    /// 2. Filter to only legal choices (e.g. empty squares)
+    /// 3. Hand the filtered list to the game's elicitation view (e.g. `ValidPositions`)
+    /// 4. The framework handles the rest
     ///
     /// Future: This pattern could be generalized with Select::Filter associated type.
-    #[instrument(skip(self, peer), fields(session_id))]
-    async fn elicit_position_filtered(
-        &self,
+    #[instrument(skip(sessions, peer), fields(game = G::NAME, session_id))]
+    async fn elicit_choice_filtered<G: Game>(
+        sessions: &SessionManager<G>,
         peer: Peer<RoleServer>,
         session_id: &str,
-    ) -> Result<Position, McpError> {
-        // Get current board state for filtering
-        let session = self.sessions.get_session(session_id)
+    ) -> Result<G::Choice, McpError> {
+        // Get current game state for filtering
+        let session = sessions.get_session(session_id)
             .ok_or_else(|| McpError::internal_error("Session not found", None))?;
         
-        let board = session.game.board();
-        let valid_positions = Position::valid_moves(board);
+        let valid_choices = session.game.legal_choices();
         
-        if valid_positions.is_empty() {
+        if valid_choices.is_empty() {
             return Err(McpError::internal_error("No valid moves available", None));
         }
         
         tracing::debug!(
-            valid_count = valid_positions.len(),
-            positions = ?valid_positions,
-            "Filtered to valid positions"
+            valid_count = valid_choices.len(),
+            choices = ?valid_choices,
+            "Filtered to valid choices"
         );
         
-        // Wrap in view struct and elicit through framework
-        let view = ValidPositions {
-            positions: valid_positions,
-        };
-        
-        let position = view.elicit_position(peer)
+        // Elicit through the game's filtered view
+        let choice = G::elicit_choice(peer, valid_choices)
             .await
             .map_err(|e| McpError::internal_error(format!("Elicitation failed: {}", e), None))?;
         
-        tracing::info!(position = ?position, "Position selected from filtered options");
-        Ok(position)
-    }
-
-    // Auto-generate elicitation tools for type-safe LLM interaction
-    elicitation::elicit_tools! {
-        Position,
-        Player,
+        tracing::info!(choice = %choice, "Choice selected from filtered options");
+        Ok(choice)
     }
 }
 
@@ -492,7 +1732,7 @@ impl GameServer {
 impl ServerHandler for GameServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
-            instructions: Some("Type-safe tic-tac-toe game server".into()),
+            instructions: Some("Type-safe game server hosting tic-tac-toe, connect-four and gomoku".into()),
             capabilities: ServerCapabilities::builder().enable_tools().build(),
             ..Default::default()
         }
@@ -1,10 +1,25 @@
 //! Game session management for HTTP multiplayer.
 
-use crate::games::tictactoe::{AnyGame, GameSetup, Mark, Position};
+use crate::clock::{Clock, GameClock, SystemClock, TimeControl};
+use crate::events::{EventLog, LoggedEvent, SessionEvent};
+use crate::games::connect_four::ConnectFour;
+use crate::games::mnk::Gomoku;
+use crate::games::tictactoe::TicTacToe;
+use crate::games::analysis::{self, ChoiceAnalysis, Verdict};
+use crate::games::annotation::{self, AnnotatedMove};
+use crate::games::{Action, Difficulty, Game, GameKind, GameState, Mark};
+use crate::lobby::Lobby;
+use crate::series::{Match, MatchFormat};
+use crate::store::{JsonlStore, MemoryStore, SessionStore};
+use crate::token::PlayerToken;
+use crate::turn_order::{FirstMove, TurnOrder};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
-use tracing::{debug, info, instrument, warn};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tracing::{debug, error, info, instrument, warn};
 
 /// Unique identifier for a game session.
 pub type SessionId = String;
@@ -20,10 +35,57 @@ pub enum PlayerType {
     Human,
     /// AI agent via MCP.
     Agent,
+    /// Built-in minimax bot, moved by the server.
+    Bot,
+}
+
+/// Where a session is in its life.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionStatus {
+    /// Fewer than two players are seated.
+    #[default]
+    WaitingForPlayers,
+    /// Both players are seated and the game is being played.
+    Active,
+    /// The game is over, or the session was closed.
+    Finished,
+    /// Nobody acted in the session before its idle timeout.
+    Abandoned,
+}
+
+impl std::fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionStatus::WaitingForPlayers => write!(f, "waiting for players"),
+            SessionStatus::Active => write!(f, "active"),
+            SessionStatus::Finished => write!(f, "finished"),
+            SessionStatus::Abandoned => write!(f, "abandoned"),
+        }
+    }
+}
+
+/// When idle sessions expire, and how long ended sessions are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expiry {
+    /// Time without events after which an open session is closed: a
+    /// finished one as finished, any other as abandoned.
+    pub idle_timeout: Duration,
+    /// Time a closed session stays listed before it is evicted.
+    pub retention: Duration,
+}
+
+impl Default for Expiry {
+    fn default() -> Self {
+        Self {
+            idle_timeout: Duration::from_secs(30 * 60),
+            retention: Duration::from_secs(5 * 60),
+        }
+    }
 }
 
 /// A player in a game session.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Player {
     /// Player's unique ID.
     pub id: PlayerId,
@@ -33,32 +95,362 @@ pub struct Player {
     pub player_type: PlayerType,
     /// Which mark this player uses (X or O).
     pub mark: Mark,
+    /// Playing strength, for bots only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
+    /// Secret the player acts with; bots, moved by the server, have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<PlayerToken>,
+}
+
+/// Someone watching a session without taking a seat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Spectator {
+    /// Spectator's unique ID.
+    pub id: String,
+    /// Spectator's name.
+    pub name: String,
+    /// Secret the spectator stops watching with.
+    ///
+    /// Spectators logged before tokens were issued have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<PlayerToken>,
 }
 
 /// A game session with two players.
+///
+/// Generic over the hosted [`Game`]; defaults to tic-tac-toe. Every change
+/// is recorded as a [`SessionEvent`] in the session's log and applied by
+/// folding it into the fields below, so the log alone can rebuild the
+/// session with [`replay`](Self::replay).
 #[derive(Debug, Clone)]
-pub struct GameSession {
+pub struct GameSession<G: Game = TicTacToe> {
     /// Session ID.
     pub id: SessionId,
     /// The game state (in any phase).
-    pub game: AnyGame,
+    pub game: G::State,
     /// Player X.
     pub player_x: Option<Player>,
     /// Player O.
     pub player_o: Option<Player>,
+    /// People watching the session, in the order they joined.
+    pub spectators: Vec<Spectator>,
+    /// Player waiting for the opponent to allow taking back their last move.
+    pub undo_request: Option<Mark>,
+    /// Resigning player's verdict on the position they gave up.
+    ///
+    /// Kept so a resignation can be judged after the fact: anything but
+    /// [`Verdict::Loss`] means the player resigned a position they could
+    /// still hold.
+    pub resignation: Option<Verdict>,
+    /// Both players' clocks, under the time control chosen at creation.
+    pub clock: GameClock,
+    /// Who opens each game, and whether seats swap between games.
+    pub turn_order: TurnOrder,
+    /// Number of the current game in this session, starting at 0.
+    pub game_number: u32,
+    /// Running score of the match being played, if any.
+    pub match_play: Option<Match>,
+    /// Where the session is in its life.
+    pub status: SessionStatus,
+    /// Whether the session was closed; a closed session accepts nothing
+    /// more and is evicted once its manager's retention period passes.
+    pub closed: bool,
+    /// Everything that happened in the session, oldest first.
+    log: EventLog<G>,
+    /// Length of the stored log when this copy was read from its manager.
+    committed: usize,
 }
 
-impl GameSession {
-    /// Creates a new game session.
-    #[instrument]
+impl<G: Game> GameSession<G> {
+    /// Creates a new game session without a time limit.
     pub fn new(id: SessionId) -> Self {
-        info!(session_id = %id, "Creating new game session");
+        Self::with_clock(id, GameClock::unlimited())
+    }
+
+    /// Creates a new game session timed by the given clocks.
+    ///
+    /// The clocks start once both players are seated.
+    pub fn with_clock(id: SessionId, clock: GameClock) -> Self {
+        Self::with_turn_order(id, clock, TurnOrder::default())
+    }
+
+    /// Creates a new game session with the given clocks and turn order.
+    #[instrument(skip(clock), fields(time_control = %clock.control(), turn_order = %turn_order))]
+    pub fn with_turn_order(id: SessionId, clock: GameClock, turn_order: TurnOrder) -> Self {
+        info!(session_id = %id, game = G::NAME, "Creating new game session");
+        let time_control = clock.control();
+        let mut session = Self::blank(id, clock);
+        // Creation can't be rejected
+        let _ = session.record(SessionEvent::Created {
+            time_control,
+            turn_order,
+        });
+        session
+    }
+
+    /// Rebuilds a session by folding its logged events.
+    ///
+    /// The log must start with the session's creation and number its
+    /// events from 0 without gaps. Clocks read `source`, the same time
+    /// source the events were stamped with.
+    #[instrument(skip(source, events), fields(events = events.len()))]
+    pub fn replay(
+        id: SessionId,
+        source: Arc<dyn Clock>,
+        events: &[LoggedEvent<G>],
+    ) -> Result<Self, String> {
+        if !matches!(events.first(), Some(LoggedEvent { event: SessionEvent::Created { .. }, .. })) {
+            return Err("Event log must start with the session's creation".to_string());
+        }
+
+        let mut session = Self::blank(id, GameClock::new(TimeControl::Unlimited, source));
+        for (index, logged) in events.iter().enumerate() {
+            if logged.seq != index as u64 {
+                return Err(format!("Event log expects seq {} but has seq {}", index, logged.seq));
+            }
+            session.commit(logged.at, logged.event.clone()).map_err(|e| {
+                format!("Event {} ({}) could not be replayed: {}", logged.seq, logged.event, e)
+            })?;
+        }
+        debug!(events = events.len(), "Replayed session");
+        Ok(session)
+    }
+
+    /// Session with no events applied yet.
+    fn blank(id: SessionId, clock: GameClock) -> Self {
         Self {
             id,
-            game: GameSetup::new().start(crate::games::tictactoe::Player::X).into(),
+            game: G::new_game(Mark::X).into(),
             player_x: None,
             player_o: None,
+            spectators: Vec::new(),
+            undo_request: None,
+            resignation: None,
+            clock,
+            turn_order: TurnOrder::default(),
+            game_number: 0,
+            match_play: None,
+            status: SessionStatus::WaitingForPlayers,
+            closed: false,
+            log: EventLog::new(),
+            committed: 0,
+        }
+    }
+
+    /// Returns every event of the session, oldest first.
+    pub fn events(&self) -> &[LoggedEvent<G>] {
+        self.log.events()
+    }
+
+    /// Time of the session's latest event on its clock source.
+    ///
+    /// Watching isn't activity: spectators coming and going don't count.
+    pub fn last_activity(&self) -> Duration {
+        self.log
+            .events()
+            .iter()
+            .rev()
+            .find(|logged| {
+                !matches!(
+                    logged.event,
+                    SessionEvent::SpectatorJoined { .. } | SessionEvent::SpectatorLeft { .. }
+                )
+            })
+            .map(|logged| logged.at)
+            .unwrap_or_default()
+    }
+
+    /// Rebuilds the session as it stood right after the given event.
+    ///
+    /// Starts from the latest snapshot at or before the event, so only the
+    /// events after it are folded again.
+    #[instrument(skip(self), fields(session_id = %self.id))]
+    pub fn state_at(&self, seq: u64) -> Result<Self, String> {
+        let events = self.log.events();
+        if seq >= events.len() as u64 {
+            return Err(format!("No event {} in a log of {}", seq, events.len()));
         }
+
+        let mut state = match self.log.snapshot_before(seq) {
+            Some((snapshot_seq, snapshot)) => {
+                let mut state = snapshot.clone();
+                state.log = self.log.truncated(*snapshot_seq);
+                state
+            }
+            None => Self::blank(self.id.clone(), GameClock::new(TimeControl::Unlimited, self.clock.source())),
+        };
+        let from = state.log.len();
+        for logged in &events[from..=seq as usize] {
+            state.commit(logged.at, logged.event.clone())?;
+        }
+        // A past state is never a valid base for an update
+        state.committed = 0;
+        Ok(state)
+    }
+
+    /// Applies an event stamped with the current time, then logs it.
+    ///
+    /// A rejected event leaves the session and its log unchanged. Once
+    /// the session is closed, every event is rejected.
+    fn record(&mut self, event: SessionEvent<G>) -> Result<(), String> {
+        if self.closed {
+            return Err("Session is closed".to_string());
+        }
+        self.commit(self.clock.now(), event)
+    }
+
+    /// Applies an event stamped with the given time, then logs it.
+    fn commit(&mut self, at: Duration, event: SessionEvent<G>) -> Result<(), String> {
+        self.apply_event(&event, at)?;
+        let seq = self.log.append(at, event);
+        if EventLog::<G>::snapshot_due(seq) {
+            let mut snapshot = self.clone();
+            snapshot.log = EventLog::new();
+            self.log.store_snapshot(seq, snapshot);
+            debug!(seq, "Stored session snapshot");
+        }
+        Ok(())
+    }
+
+    /// Folds one event into the session.
+    ///
+    /// Anything that can fail is checked before a field changes, so an
+    /// error leaves the session as it was.
+    fn apply_event(&mut self, event: &SessionEvent<G>, at: Duration) -> Result<(), String> {
+        match event {
+            SessionEvent::Created { time_control, turn_order } => {
+                self.clock = GameClock::new(*time_control, self.clock.source());
+                self.turn_order = *turn_order;
+                self.game_number = 0;
+                self.game = G::new_game(self.first_mover()).into();
+            }
+            SessionEvent::PlayerRegistered { player } => {
+                match player.mark {
+                    Mark::X => self.player_x = Some(player.clone()),
+                    Mark::O => self.player_o = Some(player.clone()),
+                }
+                self.start_clock_if_ready(at);
+            }
+            SessionEvent::MoveApplied { mark, choice } => {
+                self.game = self.game.clone().apply(G::action(*mark, *choice))?;
+                // Playing on declines any pending take-back
+                self.undo_request = None;
+                self.clock.press_at(*mark, at);
+            }
+            SessionEvent::Resigned { mark, verdict } => {
+                self.game = self.game.clone().act(Action::Resign(*mark))?;
+                self.resignation = Some(*verdict);
+            }
+            SessionEvent::DrawOffered { mark } => {
+                self.game = self.game.clone().act(Action::OfferDraw(*mark))?;
+            }
+            SessionEvent::DrawAnswered { mark, accept } => {
+                let action = if *accept {
+                    Action::AcceptDraw(*mark)
+                } else {
+                    Action::DeclineDraw(*mark)
+                };
+                self.game = self.game.clone().act(action)?;
+            }
+            SessionEvent::UndoRequested { mark } => {
+                self.undo_request = Some(*mark);
+            }
+            SessionEvent::UndoAnswered { mark, accept } => {
+                let requester = mark.opponent();
+                if *accept {
+                    let mut game = self.game.clone();
+                    while let Some(&(player, _)) = game.moves().last() {
+                        game = game.undo()?;
+                        if player == requester {
+                            break;
+                        }
+                    }
+                    self.game = game;
+                    if let Some(match_play) = &mut self.match_play {
+                        match_play.unrecord(self.game_number);
+                    }
+                    // The player to move starts a fresh turn; time already used stays spent
+                    self.clock.stop();
+                    self.start_clock_if_ready(at);
+                }
+                self.undo_request = None;
+            }
+            SessionEvent::TimedOut { mark } => {
+                self.game = self.game.clone().act(Action::TimeOut(*mark))?;
+                self.undo_request = None;
+            }
+            SessionEvent::MatchStarted { format } => {
+                self.match_play = Some(Match::new(*format));
+            }
+            SessionEvent::Restarted => {
+                self.game_number += 1;
+                if self.seats_swap() {
+                    self.swap_seats();
+                }
+                self.game = G::new_game(self.first_mover()).into();
+                self.undo_request = None;
+                self.resignation = None;
+                self.clock.reset();
+                self.start_clock_if_ready(at);
+            }
+            SessionEvent::PlayersCleared => {
+                self.player_x = None;
+                self.player_o = None;
+                self.clock.stop();
+            }
+            SessionEvent::SpectatorJoined { spectator } => {
+                self.spectators.push(spectator.clone());
+            }
+            SessionEvent::SpectatorLeft { id } => {
+                self.spectators.retain(|spectator| spectator.id != *id);
+            }
+            SessionEvent::Closed => {
+                self.closed = true;
+                self.status = SessionStatus::Finished;
+                self.undo_request = None;
+                self.clock.stop();
+            }
+            SessionEvent::Abandoned => {
+                self.closed = true;
+                self.status = SessionStatus::Abandoned;
+                self.undo_request = None;
+                self.clock.stop();
+            }
+        }
+
+        // Bookkeeping derived from a finished game
+        if self.game.is_over() {
+            self.clock.stop();
+            self.record_match_game();
+        }
+        if !self.closed {
+            self.status = if self.player_x.is_none() || self.player_o.is_none() {
+                SessionStatus::WaitingForPlayers
+            } else if self.game.is_over() {
+                SessionStatus::Finished
+            } else {
+                SessionStatus::Active
+            };
+        }
+        Ok(())
+    }
+
+    /// Returns the mark that opens the current game.
+    ///
+    /// [`FirstMove::Alternate`] alternates the opening player. When seats
+    /// swap between games, as they do in a match, the swap alone hands the
+    /// first move to the other player, so the opening mark stays put.
+    pub fn first_mover(&self) -> Mark {
+        if self.turn_order.first_move == FirstMove::Alternate && self.seats_swap() {
+            return self.turn_order.first_move.mark_for_game(0);
+        }
+        self.turn_order.first_move.mark_for_game(self.game_number)
+    }
+
+    /// Whether the players trade seats each time the game restarts.
+    fn seats_swap(&self) -> bool {
+        self.turn_order.swap_seats || self.match_play.is_some()
     }
 
     /// Registers a player in the session.
@@ -70,35 +462,148 @@ impl GameSession {
         name: String,
         player_type: PlayerType,
     ) -> Result<Mark, String> {
-        // Check if player is already registered (idempotent)
-        if let Some(player) = self.get_player(&id) {
-            info!(player_id = %id, mark = ?player.mark, "Player already registered, returning existing mark");
-            return Ok(player.mark);
+        let difficulty = (player_type == PlayerType::Bot).then(Difficulty::default);
+        self.seat_player(id, name, player_type, difficulty)
+    }
+
+    /// Registers a built-in bot of the given difficulty in the session.
+    /// Returns the mark assigned to the bot (X or O).
+    #[instrument(skip(self), fields(session_id = %self.id))]
+    pub fn register_bot(
+        &mut self,
+        id: PlayerId,
+        name: String,
+        difficulty: Difficulty,
+    ) -> Result<Mark, String> {
+        self.seat_player(id, name, PlayerType::Bot, Some(difficulty))
+    }
+
+    /// Assigns a player to the first free seat.
+    fn seat_player(
+        &mut self,
+        id: PlayerId,
+        name: String,
+        player_type: PlayerType,
+        difficulty: Option<Difficulty>,
+    ) -> Result<Mark, String> {
+        // Joining again under a seated ID would hand out that seat's token
+        if self.get_player(&id).is_some() {
+            warn!(player_id = %id, "Player already registered");
+            return Err(format!(
+                "Player {} is already seated; act with the token issued when they joined",
+                id
+            ));
         }
-        
+
         // Assign to first available slot
-        if self.player_x.is_none() {
+        let mark = if self.player_x.is_none() {
             info!(player_id = %id, mark = "X", "Registering player as X");
-            self.player_x = Some(Player {
-                id,
-                name,
-                player_type,
-                mark: Mark::X,
-            });
-            Ok(Mark::X)
+            Mark::X
         } else if self.player_o.is_none() {
             info!(player_id = %id, mark = "O", "Registering player as O");
-            self.player_o = Some(Player {
+            Mark::O
+        } else {
+            warn!(player_id = %id, "Session already has 2 players");
+            return Err("Session already has 2 players; use spectate to watch it".to_string());
+        };
+
+        self.record(SessionEvent::PlayerRegistered {
+            player: Player {
                 id,
                 name,
                 player_type,
-                mark: Mark::O,
-            });
-            Ok(Mark::O)
-        } else {
-            warn!(player_id = %id, "Session already has 2 players");
-            Err("Session already has 2 players".to_string())
+                mark,
+                difficulty,
+                token: (player_type != PlayerType::Bot).then(PlayerToken::generate),
+            },
+        })?;
+        Ok(mark)
+    }
+
+    /// Adds a spectator, who can follow the session but not act in it.
+    ///
+    /// Returns the token the spectator must present to stop watching.
+    #[instrument(skip(self), fields(session_id = %self.id))]
+    pub fn add_spectator(&mut self, id: String, name: String) -> Result<PlayerToken, String> {
+        if self.spectators.iter().any(|spectator| spectator.id == id) {
+            warn!(spectator_id = %id, "Spectator already watching");
+            return Err(format!("Spectator {} is already watching", id));
+        }
+        info!(spectator_id = %id, "Spectator joined");
+        let token = PlayerToken::generate();
+        self.record(SessionEvent::SpectatorJoined {
+            spectator: Spectator { id, name, token: Some(token.clone()) },
+        })?;
+        Ok(token)
+    }
+
+    /// Removes a spectator presenting the token issued when they joined.
+    #[instrument(skip(self, token), fields(session_id = %self.id))]
+    pub fn remove_spectator(&mut self, id: &str, token: Option<&str>) -> Result<(), String> {
+        let spectator = self
+            .spectators
+            .iter()
+            .find(|spectator| spectator.id == id)
+            .ok_or_else(|| format!("Spectator {} is not watching", id))?;
+        if let Some(expected) = &spectator.token {
+            let token = token.ok_or_else(|| {
+                warn!(spectator_id = id, "Stop spectating without a token");
+                "Missing spectator token: pass the token spectate returned".to_string()
+            })?;
+            if !expected.matches(token) {
+                warn!(spectator_id = id, "Spectator token mismatch");
+                return Err(format!("Spectator token does not match spectator {}", id));
+            }
         }
+        info!(spectator_id = id, "Spectator left");
+        self.record(SessionEvent::SpectatorLeft { id: id.to_string() })
+    }
+
+    /// Checks that a token was issued to the given seated player.
+    ///
+    /// Returns the player the token authorizes to act.
+    #[instrument(skip(self, token), fields(session_id = %self.id))]
+    pub fn authorize(&self, player_id: &str, token: Option<&str>) -> Result<&Player, String> {
+        let player = self
+            .get_player(player_id)
+            .ok_or_else(|| format!("Player {} is not seated in this session", player_id))?;
+        let Some(expected) = &player.token else {
+            warn!(player_id, "Command sent for a bot");
+            return Err(format!("Player {} is a bot, moved by the server", player_id));
+        };
+        let token = token.ok_or_else(|| {
+            warn!(player_id, "Command without a player token");
+            "Missing player token: pass the token register_player returned".to_string()
+        })?;
+        if !expected.matches(token) {
+            warn!(player_id, "Player token mismatch");
+            return Err(format!("Player token does not match player {}", player_id));
+        }
+        Ok(player)
+    }
+
+    /// Checks that a token was issued to one of the seated players.
+    ///
+    /// Sessions nobody holds a token in (empty or bot-only ones) need none.
+    #[instrument(skip(self, token), fields(session_id = %self.id))]
+    pub fn authorize_any(&self, token: Option<&str>) -> Result<(), String> {
+        let tokens: Vec<&PlayerToken> = [&self.player_x, &self.player_o]
+            .into_iter()
+            .flatten()
+            .filter_map(|player| player.token.as_ref())
+            .collect();
+        if tokens.is_empty() {
+            return Ok(());
+        }
+        let token = token.ok_or_else(|| {
+            warn!("Session command without a player token");
+            "Missing player token: pass the token register_player returned".to_string()
+        })?;
+        if !tokens.iter().any(|expected| expected.matches(token)) {
+            warn!("Player token mismatch");
+            return Err("Player token does not belong to a player in this session".to_string());
+        }
+        Ok(())
     }
 
     /// Gets the player with the given ID.
@@ -113,6 +618,14 @@ impl GameSession {
         }
     }
 
+    /// Gets the player holding the given mark.
+    pub fn player_with_mark(&self, mark: Mark) -> Option<&Player> {
+        match mark {
+            Mark::X => self.player_x.as_ref(),
+            Mark::O => self.player_o.as_ref(),
+        }
+    }
+
     /// Checks if it's the given player's turn.
     #[instrument(skip(self), fields(session_id = %self.id))]
     pub fn is_players_turn(&self, player_id: &str) -> bool {
@@ -129,7 +642,7 @@ impl GameSession {
             return false;
         };
         let is_turn = player.mark == current_mark;
-        
+
         debug!(
             player_id,
             player_mark = ?player.mark,
@@ -137,19 +650,25 @@ impl GameSession {
             is_turn,
             "Checked if player's turn"
         );
-        
+
         is_turn
     }
 
     /// Makes a move for the given player.
     #[instrument(skip(self), fields(session_id = %self.id))]
-    pub fn make_move(&mut self, player_id: &str, position: Position) -> Result<(), String> {
+    pub fn make_move(&mut self, player_id: &str, choice: G::Choice) -> Result<(), String> {
         // Validate player exists
-        let player = self.get_player(player_id)
+        let mark = self.get_player(player_id)
             .ok_or_else(|| {
                 warn!(player_id, "Unknown player attempted move");
                 "Unknown player".to_string()
-            })?;
+            })?
+            .mark;
+
+        // A player who ran out of time loses before anyone moves again
+        if let Some(loser) = self.check_clock()? {
+            return Err(format!("Player {:?} ran out of time", loser));
+        }
 
         // Validate it's their turn
         if !self.is_players_turn(player_id) {
@@ -158,7 +677,7 @@ impl GameSession {
             warn!(
                 player_id,
                 expected_mark = ?expected,
-                player_mark = ?player.mark,
+                player_mark = ?mark,
                 "Player tried to move out of turn"
             );
             return Err(format!(
@@ -167,80 +686,582 @@ impl GameSession {
             ));
         }
 
-        // Construct Move action (first-class domain event)
-        let action = crate::games::tictactoe::Move::new(player.mark, position);
-        debug!(action = %action, "Applying Move action");
-
         // Make the move (consuming transition via wrapper)
-        let old_game = std::mem::replace(&mut self.game, GameSetup::new().start(crate::games::tictactoe::Player::X).into());
-        self.game = old_game.make_move_action(action).map_err(|e| {
-            warn!(player_id, action = %action, error = %e, "Invalid move");
+        debug!(mark = ?mark, choice = %choice, "Applying move");
+        self.record(SessionEvent::MoveApplied { mark, choice }).map_err(|e| {
+            warn!(player_id, choice = %choice, error = %e, "Invalid move");
             format!("Invalid move: {}", e)
         })?;
 
         info!(
             player_id,
-            position = ?position,
+            choice = %choice,
             status = %self.game.status_string(),
             "Move completed successfully"
         );
+        Ok(())
+    }
+
+    /// Asks the opponent to let the player take back their last move.
+    ///
+    /// A bot opponent accepts straight away; otherwise the request waits
+    /// for the opponent's [`respond_undo`](Self::respond_undo).
+    #[instrument(skip(self), fields(session_id = %self.id))]
+    pub fn request_undo(&mut self, player_id: &str) -> Result<(), String> {
+        let mark = self.get_player(player_id)
+            .ok_or_else(|| {
+                warn!(player_id, "Unknown player requested undo");
+                "Unknown player".to_string()
+            })?
+            .mark;
+
+        if self.undo_request.is_some() {
+            return Err("An undo request is already pending".to_string());
+        }
+        if !self.game.moves().iter().any(|&(player, _)| player == mark) {
+            return Err("You have no move to take back".to_string());
+        }
+
+        self.record(SessionEvent::UndoRequested { mark })?;
+        info!(player_id, mark = ?mark, "Undo requested");
+
+        let opponent = self.player_with_mark(mark.opponent());
+        if opponent.is_some_and(|p| p.player_type == PlayerType::Bot) {
+            debug!("Bot opponent accepts undo");
+            self.answer_undo(mark.opponent(), true)?;
+        }
+        Ok(())
+    }
+
+    /// Accepts or declines the opponent's pending undo request.
+    ///
+    /// Accepting takes back every move up to and including the
+    /// requester's last one, so it is the requester's turn again.
+    #[instrument(skip(self), fields(session_id = %self.id))]
+    pub fn respond_undo(&mut self, player_id: &str, accept: bool) -> Result<(), String> {
+        let mark = self.get_player(player_id)
+            .ok_or_else(|| {
+                warn!(player_id, "Unknown player responded to undo");
+                "Unknown player".to_string()
+            })?
+            .mark;
+
+        self.answer_undo(mark, accept)
+    }
+
+    /// Answers the pending undo request on behalf of `mark`.
+    fn answer_undo(&mut self, mark: Mark, accept: bool) -> Result<(), String> {
+        let requester = self.undo_request
+            .filter(|&requester| requester == mark.opponent())
+            .ok_or_else(|| "Your opponent has not asked to undo".to_string())?;
+        self.record(SessionEvent::UndoAnswered { mark, accept })?;
+
+        if !accept {
+            info!(requester = ?requester, "Undo declined");
+            return Ok(());
+        }
+
+        info!(
+            requester = ?requester,
+            status = %self.game.status_string(),
+            "Undo accepted"
+        );
+        Ok(())
+    }
+
+    /// Resigns the game on behalf of the given player.
+    ///
+    /// Records how the position stood for the resigning player in
+    /// [`resignation`](Self::resignation), searching on the calling
+    /// thread. Sessions kept by a [`SessionManager`] resign through its
+    /// [`resign`](SessionManager::resign), which searches off its lock.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn resign(&mut self, player_id: &str) -> Result<Verdict, String> {
+        let (mark, game) = self.resignation_position(player_id)?;
+        let verdict = analysis::position_verdict::<G>(&game, mark);
+        self.record_resignation(mark, verdict)?;
+        Ok(verdict)
+    }
+
+    /// Returns the resigning player's mark and the position they give up.
+    fn resignation_position(&self, player_id: &str) -> Result<(Mark, G::InProgress), String> {
+        let mark = self.mark_of(player_id)?;
+        let game = self.game.in_progress()
+            .ok_or_else(|| "Game is not in progress".to_string())?;
+        Ok((mark, game))
+    }
+
+    /// Records a resignation with the verdict on the position given up.
+    fn record_resignation(&mut self, mark: Mark, verdict: Verdict) -> Result<(), String> {
+        self.act(SessionEvent::Resigned { mark, verdict })?;
+        info!(mark = ?mark, verdict = %verdict, "Player resigned");
+        Ok(())
+    }
+
+    /// Offers the opponent a draw on behalf of the given player.
+    ///
+    /// The offer waits for the opponent's [`respond_draw`](Self::respond_draw)
+    /// or next move. A bot opponent answers in
+    /// [`play_bot_turns`](Self::play_bot_turns), accepting unless it has a
+    /// forced win.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn offer_draw(&mut self, player_id: &str) -> Result<(), String> {
+        let mark = self.mark_of(player_id)?;
+        self.act(SessionEvent::DrawOffered { mark })?;
+        info!(player_id, mark = ?mark, "Draw offered");
+        Ok(())
+    }
+
+    /// Accepts or declines the opponent's pending draw offer.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn respond_draw(&mut self, player_id: &str, accept: bool) -> Result<(), String> {
+        let mark = self.mark_of(player_id)?;
+        self.answer_draw(mark, accept)
+    }
+
+    /// Answers the pending draw offer on behalf of `mark`.
+    fn answer_draw(&mut self, mark: Mark, accept: bool) -> Result<(), String> {
+        self.act(SessionEvent::DrawAnswered { mark, accept })?;
+
+        info!(mark = ?mark, accept, status = %self.game.status_string(), "Draw offer answered");
+        Ok(())
+    }
+
+    /// Records a non-placement action.
+    fn act(&mut self, event: SessionEvent<G>) -> Result<(), String> {
+        self.record(event.clone()).map_err(|e| {
+            warn!(event = %event, error = %e, "Action rejected");
+            e
+        })
+    }
+
+    /// Looks up the mark of a seated player.
+    fn mark_of(&self, player_id: &str) -> Result<Mark, String> {
+        self.get_player(player_id)
+            .map(|player| player.mark)
+            .ok_or_else(|| {
+                warn!(player_id, "Unknown player");
+                "Unknown player".to_string()
+            })
+    }
+
+    /// Resets the game to a fresh board, keeping the players.
+    ///
+    /// Starts the session's next game: the turn order picks who opens
+    /// it, and the players trade marks if seats swap between games or a
+    /// match is being played.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn reset_game(&mut self) {
+        // A restart can't be rejected
+        let _ = self.record(SessionEvent::Restarted);
+    }
+
+    /// Empties both seats, e.g. before a fresh game with new players.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn clear_players(&mut self) {
+        // Clearing the seats can't be rejected
+        let _ = self.record(SessionEvent::PlayersCleared);
+    }
+
+    /// Closes the session: no more moves, players or games.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn close(&mut self) -> Result<(), String> {
+        self.record(SessionEvent::Closed)?;
+        info!("Session closed");
+        Ok(())
+    }
+
+    /// Closes the session after it has sat idle past `expiry`.
+    ///
+    /// A player who ran out of time loses first; a session whose game is
+    /// then over closes as finished, any other as abandoned. Does nothing
+    /// to a session that is closed already or not idle for long enough.
+    fn expire(&mut self, now: Duration, expiry: &Expiry) -> Result<(), String> {
+        if self.closed || now.saturating_sub(self.last_activity()) < expiry.idle_timeout {
+            return Ok(());
+        }
+        self.check_clock()?;
+        let event = if self.status == SessionStatus::Finished {
+            SessionEvent::Closed
+        } else {
+            SessionEvent::Abandoned
+        };
+        self.record(event)?;
+        info!(status = %self.status, "Idle session closed");
+        Ok(())
+    }
+
+    /// Starts a match, replacing any earlier one.
+    ///
+    /// The current game counts as the match's first if it hasn't finished.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn start_match(&mut self, format: MatchFormat) {
+        info!(format = %format, "Starting match");
+        // Starting a match can't be rejected
+        let _ = self.record(SessionEvent::MatchStarted { format });
+    }
+
+    /// Returns true if the session is playing a match that has been decided.
+    pub fn match_over(&self) -> bool {
+        self.match_play.as_ref().is_some_and(Match::is_over)
+    }
+
+    /// Records the finished game in the match, unless already done.
+    fn record_match_game(&mut self) {
+        if !self.game.is_over() {
+            return;
+        }
+        let (Some(match_play), Some(player_x), Some(player_o)) =
+            (self.match_play.as_mut(), self.player_x.as_ref(), self.player_o.as_ref())
+        else {
+            return;
+        };
+        match_play.record(self.game_number, player_x, player_o, self.game.winner());
+    }
+
+    /// Moves player X to seat O and vice versa.
+    fn swap_seats(&mut self) {
+        std::mem::swap(&mut self.player_x, &mut self.player_o);
+        if let Some(player) = &mut self.player_x {
+            player.mark = Mark::X;
+        }
+        if let Some(player) = &mut self.player_o {
+            player.mark = Mark::O;
+        }
+        debug!(
+            player_x = ?self.player_x.as_ref().map(|p| &p.id),
+            player_o = ?self.player_o.as_ref().map(|p| &p.id),
+            "Swapped seats"
+        );
+    }
+
+    /// Starts the clock of the player to move once both seats are filled.
+    fn start_clock_if_ready(&mut self, at: Duration) {
+        if self.clock.running().is_some() || self.player_x.is_none() || self.player_o.is_none() {
+            return;
+        }
+        if let Some(mark) = self.game.to_move() {
+            debug!(mark = ?mark, "Starting clock");
+            self.clock.start_at(mark, at);
+        }
+    }
 
+    /// Ends the game if the player to move has run out of time.
+    ///
+    /// Returns the player who lost on time, if any.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn check_clock(&mut self) -> Result<Option<Mark>, String> {
+        let Some(mark) = self.clock.flagged() else {
+            return Ok(None);
+        };
+
+        self.record(SessionEvent::TimedOut { mark })?;
+
+        info!(mark = ?mark, "Player ran out of time");
+        Ok(Some(mark))
+    }
+
+    /// Annotates the finished game's moves, searching on the calling thread.
+    ///
+    /// Empty while the game is still in progress. Sessions kept by a
+    /// [`SessionManager`] are annotated through its
+    /// [`annotations`](SessionManager::annotations), which searches off its
+    /// lock and keeps the result.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn annotate(&self) -> Vec<AnnotatedMove<G::Choice>> {
+        if !self.game.is_over() {
+            return Vec::new();
+        }
+        annotation::annotate::<G>(&self.game.moves())
+    }
+
+    /// Evaluates every legal choice for the player to move, searching on
+    /// the calling thread.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn analyze(&self) -> Result<Vec<ChoiceAnalysis<G::Choice>>, String> {
+        let game = self.game.in_progress()
+            .ok_or_else(|| "Game is not in progress".to_string())?;
+        Ok(analysis::analyze::<G>(&game))
+    }
+
+    /// Plays every bot reply that is due, searching on the calling thread.
+    ///
+    /// Bots answer draw offers made to them and move while it is their
+    /// turn. Session methods never do this themselves, so the searches
+    /// run only where the caller chooses; sessions kept by a
+    /// [`SessionManager`] are driven by its
+    /// [`play_bot_turns`](SessionManager::play_bot_turns) instead.
+    #[instrument(skip(self), fields(session_id = %self.id, game = G::NAME))]
+    pub fn play_bot_turns(&mut self) -> Result<(), String> {
+        while let Some(turn) = self.bot_turn()? {
+            let event = turn.decide()?;
+            self.apply_bot_decision(event)?;
+        }
         Ok(())
     }
+
+    /// Returns the search a bot has to run next, if a bot is due to act.
+    fn bot_turn(&self) -> Result<Option<BotTurn<G>>, String> {
+        let bot_difficulty = |mark: Mark| {
+            self.player_with_mark(mark)
+                .filter(|player| player.player_type == PlayerType::Bot)
+                .map(|player| player.difficulty.unwrap_or_default())
+        };
+
+        // A draw offered to a bot is answered before anyone moves
+        let (mark, answer_draw) = match self.game.draw_offer() {
+            Some(offerer) if bot_difficulty(offerer.opponent()).is_some() => (offerer.opponent(), true),
+            _ => match self.game.to_move() {
+                Some(mark) => (mark, false),
+                None => return Ok(None),
+            },
+        };
+        let Some(difficulty) = bot_difficulty(mark) else {
+            return Ok(None);
+        };
+
+        let game = self.game.in_progress()
+            .ok_or_else(|| "Game state could not be rebuilt for the bot".to_string())?;
+        Ok(Some(BotTurn { mark, difficulty, answer_draw, game }))
+    }
+
+    /// Records what a bot decided.
+    fn apply_bot_decision(&mut self, event: SessionEvent<G>) -> Result<(), String> {
+        self.record(event).map_err(|e| {
+            warn!(error = %e, "Bot decision rejected");
+            format!("Bot move rejected: {}", e)
+        })
+    }
 }
 
-/// Manages all game sessions.
+/// A search a bot has to run, over its own copy of the game.
+///
+/// Holds nothing of the session, so it can run on another thread while
+/// the session stays free to change.
+struct BotTurn<G: Game> {
+    /// The bot's seat.
+    mark: Mark,
+    /// The bot's strength.
+    difficulty: Difficulty,
+    /// Whether the bot answers a draw offer rather than moving.
+    answer_draw: bool,
+    /// The game as it stood when the turn was read.
+    game: G::InProgress,
+}
+
+impl<G: Game> BotTurn<G> {
+    /// Runs the bot's search and returns the event it decided on.
+    fn decide(self) -> Result<SessionEvent<G>, String> {
+        let mark = self.mark;
+        if self.answer_draw {
+            let accept = analysis::position_verdict::<G>(&self.game, mark) != Verdict::Win;
+            debug!(mark = ?mark, accept, "Bot answers draw offer");
+            return Ok(SessionEvent::DrawAnswered { mark, accept });
+        }
+
+        let choice = self.difficulty.choose::<G>(&self.game)
+            .ok_or_else(|| "Bot found no legal move".to_string())?;
+        debug!(mark = ?mark, difficulty = %self.difficulty, choice = %choice, "Bot chose move");
+        Ok(SessionEvent::MoveApplied { mark, choice })
+    }
+}
+
+/// Manages all game sessions of one game type.
+///
+/// Sessions are served from memory; every event they log is also handed
+/// to the manager's [`SessionStore`], from which they are reloaded when a
+/// manager is created over it.
+#[derive(Debug, Clone)]
+pub struct SessionManager<G: Game = TicTacToe> {
+    sessions: Arc<Mutex<HashMap<SessionId, GameSession<G>>>>,
+    /// Time source for the clocks of new sessions.
+    clock: Arc<dyn Clock>,
+    /// Where the sessions' event logs are kept.
+    store: Arc<dyn SessionStore<G>>,
+    /// Store writes waiting to be made outside the sessions lock.
+    journal: Arc<Journal<G>>,
+    /// Wakes everyone waiting for events whenever a session logs some.
+    updates: Arc<Notify>,
+    /// Annotations of each session's latest finished game.
+    annotations: Arc<Mutex<HashMap<SessionId, Annotations<G>>>>,
+}
+
+/// Store writes queued under the sessions lock, made after it is released.
+///
+/// Writes are queued in the order the events were logged and made in that
+/// order by whichever caller takes the writing lock first, so slow storage
+/// never holds up the sessions lock.
+#[derive(Debug)]
+struct Journal<G: Game> {
+    /// Writes not yet made, oldest first.
+    queued: Mutex<Vec<(SessionId, StoreWrite<G>)>>,
+    /// Held while making writes, so they reach the store in order.
+    writing: Mutex<()>,
+    /// Sessions whose stored log missed events after a failed write.
+    ///
+    /// Their next change replaces the stored log with the whole event log.
+    dirty: Mutex<HashSet<SessionId>>,
+}
+
+impl<G: Game> Default for Journal<G> {
+    fn default() -> Self {
+        Self {
+            queued: Mutex::new(Vec::new()),
+            writing: Mutex::new(()),
+            dirty: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+/// One queued change to a session's stored log.
+#[derive(Debug)]
+enum StoreWrite<G: Game> {
+    /// Newly logged events to append.
+    Append(Vec<LoggedEvent<G>>),
+    /// The whole event log, replacing a stored log that missed events.
+    Replace(Vec<LoggedEvent<G>>),
+    /// The session was evicted or deleted.
+    Remove,
+}
+
+/// Move-by-move annotation of one finished game of a session.
 #[derive(Debug, Clone)]
-pub struct SessionManager {
-    sessions: Arc<Mutex<HashMap<SessionId, GameSession>>>,
+struct Annotations<G: Game> {
+    /// Number of the annotated game in its session.
+    game_number: u32,
+    /// The moves annotated; an undo after the game ended changes them.
+    moves: Vec<(Mark, G::Choice)>,
+    /// One annotation per move.
+    record: Vec<AnnotatedMove<G::Choice>>,
 }
 
-impl SessionManager {
+impl<G: Game> SessionManager<G> {
     /// Creates a new session manager.
-    #[instrument]
     pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock::new()))
+    }
+
+    /// Creates a session manager whose game clocks read the given time source.
+    #[instrument]
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
         info!("Creating session manager");
         Self {
             sessions: Arc::new(Mutex::new(HashMap::new())),
+            clock,
+            store: Arc::new(MemoryStore::new()),
+            journal: Arc::new(Journal::default()),
+            updates: Arc::new(Notify::new()),
+            annotations: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    /// Creates a new game session.
-    #[instrument(skip(self))]
+    /// Creates a session manager backed by the given store.
+    ///
+    /// Every session in the store is rebuilt by replaying its event log;
+    /// a session whose log no longer replays is skipped with a warning.
+    #[instrument(skip(store))]
+    pub fn with_store(clock: Arc<dyn Clock>, store: Arc<dyn SessionStore<G>>) -> Result<Self, String> {
+        let mut sessions = HashMap::new();
+        for (id, events) in store.load()? {
+            match GameSession::replay(id.clone(), clock.clone(), &events) {
+                Ok(session) => {
+                    sessions.insert(id, session);
+                }
+                Err(e) => warn!(session_id = %id, error = %e, "Skipping session that could not be replayed"),
+            }
+        }
+
+        info!(sessions = sessions.len(), game = G::NAME, "Restored stored sessions");
+        Ok(Self {
+            sessions: Arc::new(Mutex::new(sessions)),
+            clock,
+            store,
+            journal: Arc::new(Journal::default()),
+            updates: Arc::new(Notify::new()),
+            annotations: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// Creates a new game session without a time limit.
     pub fn create_session(&self, id: SessionId) -> Result<SessionId, String> {
-        let mut sessions = self.sessions.lock().unwrap();
-        
-        if sessions.contains_key(&id) {
-            warn!(session_id = %id, "Session already exists");
-            return Err("Session already exists".to_string());
+        self.create_timed_session(id, TimeControl::Unlimited)
+    }
+
+    /// Creates a new game session under the given time control.
+    pub fn create_timed_session(
+        &self,
+        id: SessionId,
+        time_control: TimeControl,
+    ) -> Result<SessionId, String> {
+        self.create_ordered_session(id, time_control, TurnOrder::default())
+    }
+
+    /// Creates a new game session under the given time control and turn order.
+    #[instrument(skip(self))]
+    pub fn create_ordered_session(
+        &self,
+        id: SessionId,
+        time_control: TimeControl,
+        turn_order: TurnOrder,
+    ) -> Result<SessionId, String> {
+        {
+            let mut sessions = self.sessions.lock().unwrap();
+
+            if sessions.contains_key(&id) {
+                warn!(session_id = %id, "Session already exists");
+                return Err("Session already exists".to_string());
+            }
+
+            let clock = GameClock::new(time_control, self.clock.clone());
+            let session = GameSession::with_turn_order(id.clone(), clock, turn_order);
+            self.queue_events(&session, 0);
+            sessions.insert(id.clone(), session);
         }
+        self.persist();
 
-        let session = GameSession::new(id.clone());
-        sessions.insert(id.clone(), session);
-        
         info!(session_id = %id, "Created new session");
         Ok(id)
     }
 
     /// Gets a session by ID.
     #[instrument(skip(self))]
-    pub fn get_session(&self, id: &str) -> Option<GameSession> {
+    pub fn get_session(&self, id: &str) -> Option<GameSession<G>> {
         let sessions = self.sessions.lock().unwrap();
-        let session = sessions.get(id).cloned();
-        
+        let session = sessions.get(id).cloned().map(|mut session| {
+            session.committed = session.log.len();
+            session
+        });
+
         if session.is_none() {
             debug!(session_id = id, "Session not found");
         }
-        
+
         session
     }
 
-    /// Updates a session.
+    /// Stores a session read with [`get_session`](Self::get_session).
+    ///
+    /// Fails if the stored session has logged events since it was read,
+    /// so a stale copy can't overwrite them.
     #[instrument(skip(self, session), fields(session_id = %session.id))]
-    pub fn update_session(&self, session: GameSession) {
-        let mut sessions = self.sessions.lock().unwrap();
-        sessions.insert(session.id.clone(), session);
+    pub fn update_session(&self, session: GameSession<G>) -> Result<(), String> {
+        {
+            let mut sessions = self.sessions.lock().unwrap();
+            let stored_events = sessions.get(&session.id).map(|stored| stored.log.len());
+            if stored_events.is_some_and(|len| len != session.committed) {
+                warn!(
+                    stored_events = ?stored_events,
+                    read_events = session.committed,
+                    "Session changed since it was read"
+                );
+                return Err("Session changed since it was read; get it again and retry".to_string());
+            }
+            self.queue_events(&session, stored_events.unwrap_or(0));
+            sessions.insert(session.id.clone(), session);
+        }
+        self.persist();
         debug!("Session updated");
+        Ok(())
     }
 
     /// Lists all active session IDs.
@@ -262,54 +1283,502 @@ impl SessionManager {
         name: String,
         player_type: PlayerType,
     ) -> Result<Mark, String> {
-        let mut sessions = self.sessions.lock().unwrap();
-        
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| "Session not found".to_string())?;
-        
         // Register player while holding the lock
-        session.register_player(player_id, name, player_type)
+        self.modify(session_id, |session| session.register_player(player_id, name, player_type))
+    }
+
+    /// Atomically registers a built-in bot in a session (thread-safe).
+    /// Returns the assigned mark (X or O).
+    #[instrument(skip(self))]
+    pub fn register_bot_atomic(
+        &self,
+        session_id: &str,
+        bot_id: String,
+        name: String,
+        difficulty: Difficulty,
+    ) -> Result<Mark, String> {
+        self.modify(session_id, |session| session.register_bot(bot_id, name, difficulty))
+    }
+
+    /// Atomically seats a player, or a bot of the given difficulty, in a
+    /// session (thread-safe).
+    ///
+    /// Returns the assigned mark and the token issued to the player; bots
+    /// get no token.
+    #[instrument(skip(self))]
+    pub fn register_atomic(
+        &self,
+        session_id: &str,
+        player_id: String,
+        name: String,
+        player_type: PlayerType,
+        difficulty: Option<Difficulty>,
+    ) -> Result<(Mark, Option<PlayerToken>), String> {
+        self.modify(session_id, |session| {
+            let mark = match difficulty {
+                Some(difficulty) => session.register_bot(player_id.clone(), name, difficulty)?,
+                None => session.register_player(player_id.clone(), name, player_type)?,
+            };
+            let token = session.get_player(&player_id).and_then(|player| player.token.clone());
+            Ok((mark, token))
+        })
+    }
+
+    /// Atomically adds a spectator to a session (thread-safe).
+    /// Returns the token the spectator stops watching with.
+    #[instrument(skip(self))]
+    pub fn add_spectator_atomic(
+        &self,
+        session_id: &str,
+        spectator_id: String,
+        name: String,
+    ) -> Result<PlayerToken, String> {
+        self.modify(session_id, |session| session.add_spectator(spectator_id, name))
+    }
+
+    /// Atomically removes a spectator from a session (thread-safe).
+    #[instrument(skip(self, token))]
+    pub fn remove_spectator_atomic(
+        &self,
+        session_id: &str,
+        spectator_id: &str,
+        token: Option<&str>,
+    ) -> Result<(), String> {
+        self.modify(session_id, |session| session.remove_spectator(spectator_id, token))
+    }
+
+    /// Waits until a session has logged more than `after` events, or the
+    /// timeout passes.
+    ///
+    /// Returns the session as it then stands, or `None` once it doesn't
+    /// exist. This is how spectators follow a game live.
+    #[instrument(skip(self))]
+    pub async fn wait_for_events(
+        &self,
+        session_id: &str,
+        after: usize,
+        timeout: Duration,
+    ) -> Option<GameSession<G>> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            // Listen before looking, so events logged in between still wake us
+            let notified = self.updates.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            let session = self.get_session(session_id)?;
+            if session.events().len() > after {
+                return Some(session);
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                debug!("No new events before the timeout");
+                return Some(session);
+            }
+        }
     }
-    
-    /// Atomically updates game state without overwriting player registrations.
-    #[instrument(skip(self, game))]
-    pub fn update_game_atomic(
+
+    /// Atomically makes a move for a player (thread-safe).
+    #[instrument(skip(self))]
+    pub fn make_move_atomic(
         &self,
         session_id: &str,
-        game: AnyGame,
+        player_id: &str,
+        choice: G::Choice,
     ) -> Result<(), String> {
-        let mut sessions = self.sessions.lock().unwrap();
-        
-        let session = sessions
-            .get_mut(session_id)
+        self.modify(session_id, |session| session.make_move(player_id, choice))
+    }
+
+    /// Plays every bot reply that is due in a session.
+    ///
+    /// Each search runs on the blocking thread pool over a copy of the
+    /// game, so neither the manager's lock nor the async executor waits
+    /// for it. A decision is committed only if the session hasn't logged
+    /// anything meanwhile; otherwise the bot looks at the session again.
+    #[instrument(skip(self))]
+    pub async fn play_bot_turns(&self, session_id: &str) -> Result<(), String> {
+        loop {
+            let session = self
+                .get_session(session_id)
+                .ok_or_else(|| "Session not found".to_string())?;
+            let Some(turn) = session.bot_turn()? else {
+                return Ok(());
+            };
+
+            let event = tokio::task::spawn_blocking(move || turn.decide())
+                .await
+                .map_err(|e| format!("Bot search failed: {}", e))??;
+
+            let committed = self.modify(session_id, |stored| {
+                if stored.log.len() != session.committed {
+                    return Ok(false);
+                }
+                stored.apply_bot_decision(event).map(|()| true)
+            })?;
+            if !committed {
+                debug!("Session changed during the bot's search; deciding again");
+            }
+        }
+    }
+
+    /// Resigns a session's game on behalf of a player.
+    ///
+    /// The verdict on the position given up is searched on the blocking
+    /// thread pool over a copy of the game, like a bot's move. The
+    /// resignation is committed only if the session hasn't logged anything
+    /// meanwhile; otherwise the position is judged again.
+    #[instrument(skip(self))]
+    pub async fn resign(&self, session_id: &str, player_id: &str) -> Result<Verdict, String> {
+        loop {
+            let session = self
+                .get_session(session_id)
+                .ok_or_else(|| "Session not found".to_string())?;
+            let (mark, game) = session.resignation_position(player_id)?;
+
+            let verdict = tokio::task::spawn_blocking(move || analysis::position_verdict::<G>(&game, mark))
+                .await
+                .map_err(|e| format!("Resignation search failed: {}", e))?;
+
+            let committed = self.modify(session_id, |stored| {
+                if stored.log.len() != session.committed {
+                    return Ok(false);
+                }
+                stored.record_resignation(mark, verdict).map(|()| true)
+            })?;
+            if committed {
+                return Ok(verdict);
+            }
+            debug!("Session changed while judging the resignation; judging again");
+        }
+    }
+
+    /// Returns the move-by-move annotation of a session's finished game.
+    ///
+    /// Empty while the game is in progress. The game is annotated on the
+    /// blocking thread pool the first time it is asked for, and the record
+    /// is kept until the session starts another game or takes a move back.
+    #[instrument(skip(self))]
+    pub async fn annotations(&self, session_id: &str) -> Result<Vec<AnnotatedMove<G::Choice>>, String> {
+        let session = self
+            .get_session(session_id)
             .ok_or_else(|| "Session not found".to_string())?;
-        
-        session.game = game;
-        debug!("Game state updated atomically");
-        Ok(())
+        if !session.game.is_over() {
+            return Ok(Vec::new());
+        }
+
+        let game_number = session.game_number;
+        let moves = session.game.moves();
+        let cached = self.annotations.lock().unwrap().get(session_id).cloned();
+        if let Some(cached) = cached.filter(|c| c.game_number == game_number && c.moves == moves) {
+            return Ok(cached.record);
+        }
+
+        let record = tokio::task::spawn_blocking(move || session.annotate())
+            .await
+            .map_err(|e| format!("Annotation failed: {}", e))?;
+        info!(moves = record.len(), game_number, "Annotated finished game");
+        self.annotations.lock().unwrap().insert(
+            session_id.to_string(),
+            Annotations { game_number, moves, record: record.clone() },
+        );
+        Ok(record)
+    }
+
+    /// Atomically ends the game if the player to move is out of time.
+    ///
+    /// Returns the player who lost on time, if any.
+    #[instrument(skip(self))]
+    pub fn check_clock_atomic(&self, session_id: &str) -> Result<Option<Mark>, String> {
+        self.modify(session_id, GameSession::check_clock)
     }
-    
+
+    /// Atomically starts a match in a session (thread-safe).
+    #[instrument(skip(self))]
+    pub fn start_match_atomic(&self, session_id: &str, format: MatchFormat) -> Result<(), String> {
+        self.modify(session_id, |session| {
+            if session.closed {
+                return Err("Session is closed".to_string());
+            }
+            session.start_match(format);
+            Ok(())
+        })
+    }
+
     /// Restarts game in session (keeps players registered).
+    ///
+    /// Fails once the session's match has been decided.
     #[instrument(skip(self))]
     pub fn restart_game(
         &self,
         session_id: &str,
     ) -> Result<(), String> {
-        let mut sessions = self.sessions.lock().unwrap();
-        
-        let session = sessions
-            .get_mut(session_id)
-            .ok_or_else(|| "Session not found".to_string())?;
-        
-        session.game = GameSetup::new().start(crate::games::tictactoe::Player::X).into();
-        info!("Game restarted with same players");
+        self.modify(session_id, |session| {
+            if session.closed {
+                return Err("Session is closed".to_string());
+            }
+            if let Some(match_play) = session.match_play.as_ref().filter(|m| m.is_over()) {
+                return Err(format!("Match is over. {}", match_play.summary()));
+            }
+            session.reset_game();
+            info!("Game restarted with same players");
+            Ok(())
+        })
+    }
+
+    /// Atomically closes a session (thread-safe).
+    ///
+    /// The session stays listed, finished, until it is evicted by
+    /// [`reap`](Self::reap) or [`delete_session`](Self::delete_session).
+    #[instrument(skip(self))]
+    pub fn close_session(&self, session_id: &str) -> Result<(), String> {
+        self.modify(session_id, GameSession::close)
+    }
+
+    /// Removes a session and its stored event log straight away.
+    #[instrument(skip(self))]
+    pub fn delete_session(&self, session_id: &str) -> Result<(), String> {
+        {
+            let mut sessions = self.sessions.lock().unwrap();
+            sessions
+                .remove(session_id)
+                .ok_or_else(|| "Session not found".to_string())?;
+            self.forget(session_id);
+        }
+        self.persist();
+        info!("Session deleted");
         Ok(())
     }
+
+    /// Closes sessions idle past the expiry's timeout, and evicts closed
+    /// sessions kept past its retention period.
+    ///
+    /// Returns the IDs of the evicted sessions.
+    #[instrument(skip(self), fields(game = G::NAME))]
+    pub fn reap(&self, expiry: &Expiry) -> Vec<SessionId> {
+        let now = self.clock.now();
+        let mut evicted = Vec::new();
+        {
+            let mut sessions = self.sessions.lock().unwrap();
+
+            for (id, session) in sessions.iter_mut() {
+                if session.closed {
+                    if now.saturating_sub(session.last_activity()) >= expiry.retention {
+                        evicted.push(id.clone());
+                    }
+                    continue;
+                }
+                let from = session.log.len();
+                if let Err(e) = session.expire(now, expiry) {
+                    warn!(session_id = %id, error = %e, "Could not expire idle session");
+                }
+                self.queue_events(session, from);
+            }
+
+            for id in &evicted {
+                sessions.remove(id);
+                self.forget(id);
+            }
+        }
+        self.persist();
+
+        if !evicted.is_empty() {
+            info!(evicted = evicted.len(), "Evicted closed sessions");
+        }
+        evicted
+    }
+
+    /// Changes a session while holding the lock, then persists what it logged.
+    ///
+    /// Events are persisted even if the change fails part-way, e.g. a
+    /// move refused because the mover's time ran out.
+    fn modify<T>(
+        &self,
+        session_id: &str,
+        change: impl FnOnce(&mut GameSession<G>) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let result = {
+            let mut sessions = self.sessions.lock().unwrap();
+
+            let session = sessions
+                .get_mut(session_id)
+                .ok_or_else(|| "Session not found".to_string())?;
+
+            let from = session.log.len();
+            let result = change(session);
+            self.queue_events(session, from);
+            result
+        };
+        self.persist();
+        result
+    }
+
+    /// Queues the session's events from `from` on for the store.
+    ///
+    /// Called with the sessions lock held, so events are queued in the
+    /// order they were logged. A session whose stored log missed events
+    /// queues its whole log instead.
+    fn queue_events(&self, session: &GameSession<G>, from: usize) {
+        let events = &session.events()[from..];
+        if events.is_empty() {
+            return;
+        }
+        let write = if self.journal.dirty.lock().unwrap().contains(&session.id) {
+            StoreWrite::Replace(session.events().to_vec())
+        } else {
+            StoreWrite::Append(events.to_vec())
+        };
+        self.journal.queued.lock().unwrap().push((session.id.clone(), write));
+    }
+
+    /// Drops a session's annotations and queues the removal of its stored log.
+    ///
+    /// Called with the sessions lock held.
+    fn forget(&self, session_id: &str) {
+        self.annotations.lock().unwrap().remove(session_id);
+        self.journal
+            .queued
+            .lock()
+            .unwrap()
+            .push((session_id.to_string(), StoreWrite::Remove));
+    }
+
+    /// Makes the queued store writes, in order.
+    ///
+    /// Called after the sessions lock is released. Returns once every write
+    /// queued so far is made, whether by this caller or by another that
+    /// took the writing lock first. A failed append marks its session dirty:
+    /// the session stays playable from memory, its later appends are
+    /// dropped, and its next change rewrites the whole stored log.
+    fn persist(&self) {
+        let _writing = self.journal.writing.lock().unwrap();
+        let queued = std::mem::take(&mut *self.journal.queued.lock().unwrap());
+        if queued.is_empty() {
+            return;
+        }
+
+        for (session_id, write) in queued {
+            let stale = self.journal.dirty.lock().unwrap().contains(&session_id);
+            match write {
+                StoreWrite::Append(_) if stale => {
+                    // Appending past the missing events would leave a gap;
+                    // the session's next change replaces the log whole
+                    debug!(session_id = %session_id, "Skipping append to a stale session log");
+                }
+                StoreWrite::Append(events) => {
+                    if let Err(e) = self.store.append(&session_id, &events) {
+                        error!(session_id = %session_id, error = %e, "Failed to persist session events");
+                        self.journal.dirty.lock().unwrap().insert(session_id);
+                    }
+                }
+                StoreWrite::Replace(events) => match self.store.replace(&session_id, &events) {
+                    Ok(()) => {
+                        info!(session_id = %session_id, events = events.len(), "Rewrote stale session log");
+                        self.journal.dirty.lock().unwrap().remove(&session_id);
+                    }
+                    Err(e) => error!(session_id = %session_id, error = %e, "Failed to rewrite session log"),
+                },
+                StoreWrite::Remove => {
+                    if let Err(e) = self.store.remove(&session_id) {
+                        error!(session_id = %session_id, error = %e, "Failed to remove stored session");
+                    }
+                    self.journal.dirty.lock().unwrap().remove(&session_id);
+                }
+            }
+        }
+        // Whoever waits on a session learns of its events, or that it is gone
+        self.updates.notify_waiters();
+    }
 }
 
-impl Default for SessionManager {
+impl<G: Game> Default for SessionManager<G> {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Session managers for every hosted game, shared by the MCP and REST layers.
+///
+/// Cloning is cheap: each manager clones its internal `Arc`.
+#[derive(Debug, Clone, Default)]
+pub struct GameSessions {
+    /// Tic-tac-toe sessions.
+    pub tictactoe: SessionManager<TicTacToe>,
+    /// Connect Four sessions.
+    pub connect_four: SessionManager<ConnectFour>,
+    /// Gomoku sessions.
+    pub gomoku: SessionManager<Gomoku>,
+    /// Matchmaking queue that creates sessions in the managers above.
+    pub lobby: Lobby,
+}
+
+impl GameSessions {
+    /// Creates empty session managers for every game.
+    #[instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates empty session managers whose game clocks read the given time source.
+    #[instrument]
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            tictactoe: SessionManager::with_clock(clock.clone()),
+            connect_four: SessionManager::with_clock(clock.clone()),
+            gomoku: SessionManager::with_clock(clock.clone()),
+            lobby: Lobby::with_clock(clock),
+        }
+    }
+
+    /// Opens session managers that keep their sessions in a directory.
+    ///
+    /// Each game's sessions live in a subdirectory named after the game
+    /// (e.g. `tic-tac-toe`), as one JSON-lines event log per session.
+    /// Sessions already there are reloaded.
+    #[instrument(skip(clock), fields(dir = %dir.display()))]
+    pub fn open(dir: &Path, clock: Arc<dyn Clock>) -> Result<Self, String> {
+        let store = |kind: GameKind| JsonlStore::open(dir.join(kind.to_string())).map(Arc::new);
+        Ok(Self {
+            tictactoe: SessionManager::with_store(clock.clone(), store(GameKind::TicTacToe)?)?,
+            connect_four: SessionManager::with_store(clock.clone(), store(GameKind::ConnectFour)?)?,
+            gomoku: SessionManager::with_store(clock.clone(), store(GameKind::Gomoku)?)?,
+            lobby: Lobby::with_clock(clock),
+        })
+    }
+
+    /// Expires idle sessions and evicts old closed ones in every manager.
+    ///
+    /// Returns the IDs of the evicted sessions. The lobby forgets the
+    /// pairings that led to them.
+    #[instrument(skip(self))]
+    pub fn reap(&self, expiry: &Expiry) -> Vec<SessionId> {
+        let mut evicted = self.tictactoe.reap(expiry);
+        evicted.extend(self.connect_four.reap(expiry));
+        evicted.extend(self.gomoku.reap(expiry));
+        self.lobby.forget_sessions(&evicted);
+        evicted
+    }
+
+    /// Returns which game an existing session hosts.
+    #[instrument(skip(self))]
+    pub fn kind_of(&self, session_id: &str) -> Option<GameKind> {
+        if self.tictactoe.get_session(session_id).is_some() {
+            Some(GameKind::TicTacToe)
+        } else if self.connect_four.get_session(session_id).is_some() {
+            Some(GameKind::ConnectFour)
+        } else if self.gomoku.get_session(session_id).is_some() {
+            Some(GameKind::Gomoku)
+        } else {
+            None
+        }
+    }
+
+    /// Plays every bot reply that is due in a session of any game.
+    #[instrument(skip(self))]
+    pub async fn play_bot_turns(&self, session_id: &str) -> Result<(), String> {
+        match self.kind_of(session_id) {
+            Some(GameKind::TicTacToe) => self.tictactoe.play_bot_turns(session_id).await,
+            Some(GameKind::ConnectFour) => self.connect_four.play_bot_turns(session_id).await,
+            Some(GameKind::Gomoku) => self.gomoku.play_bot_turns(session_id).await,
+            None => Err("Session not found".to_string()),
+        }
+    }
+}
@@ -0,0 +1,281 @@
+//! Storage backends for session event logs.
+//!
+//! A [`SessionManager`](crate::SessionManager) keeps its sessions in
+//! memory and hands every newly logged [`LoggedEvent`] to its
+//! [`SessionStore`]. On startup it loads the stored logs and replays them,
+//! so a durable store such as [`JsonlStore`] brings sessions, players and
+//! game histories back after a restart or crash.
+
+use crate::events::LoggedEvent;
+use crate::games::Game;
+use crate::session::SessionId;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{debug, info, instrument, warn};
+
+/// Where session event logs are kept.
+pub trait SessionStore<G: Game>: Debug + Send + Sync {
+    /// Loads the event log of every stored session.
+    ///
+    /// A log that can't be read is skipped with a warning; only a store
+    /// that can't be read at all fails the load.
+    fn load(&self) -> Result<Vec<(SessionId, Vec<LoggedEvent<G>>)>, String>;
+
+    /// Appends newly logged events to a session's stored log.
+    fn append(&self, session_id: &str, events: &[LoggedEvent<G>]) -> Result<(), String>;
+
+    /// Replaces a session's stored log with its whole event log.
+    ///
+    /// Used to catch a log up after an append to it failed.
+    fn replace(&self, session_id: &str, events: &[LoggedEvent<G>]) -> Result<(), String>;
+
+    /// Removes a session's stored log, if there is one.
+    fn remove(&self, session_id: &str) -> Result<(), String>;
+}
+
+/// Keeps event logs in memory; they are lost when the process exits.
+///
+/// Managers sharing one store see each other's sessions when created,
+/// which is how tests simulate a restart.
+#[derive(Debug)]
+pub struct MemoryStore<G: Game> {
+    logs: Mutex<HashMap<SessionId, Vec<LoggedEvent<G>>>>,
+}
+
+impl<G: Game> MemoryStore<G> {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self {
+            logs: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<G: Game> Default for MemoryStore<G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G: Game> SessionStore<G> for MemoryStore<G> {
+    fn load(&self) -> Result<Vec<(SessionId, Vec<LoggedEvent<G>>)>, String> {
+        let logs = self.logs.lock().unwrap();
+        Ok(logs.iter().map(|(id, events)| (id.clone(), events.clone())).collect())
+    }
+
+    fn append(&self, session_id: &str, events: &[LoggedEvent<G>]) -> Result<(), String> {
+        let mut logs = self.logs.lock().unwrap();
+        logs.entry(session_id.to_string()).or_default().extend_from_slice(events);
+        Ok(())
+    }
+
+    fn replace(&self, session_id: &str, events: &[LoggedEvent<G>]) -> Result<(), String> {
+        let mut logs = self.logs.lock().unwrap();
+        logs.insert(session_id.to_string(), events.to_vec());
+        Ok(())
+    }
+
+    fn remove(&self, session_id: &str) -> Result<(), String> {
+        self.logs.lock().unwrap().remove(session_id);
+        Ok(())
+    }
+}
+
+/// Keeps each session's event log in a JSON-lines file of a directory.
+///
+/// Every event is one line of `<dir>/<session id>.jsonl`, appended and
+/// synced to disk as it happens. A log is replaced by writing a temporary
+/// file beside it and renaming it over the old one. A last line torn by a crash mid-write
+/// is dropped when the store is loaded; a log with any other unreadable
+/// line is skipped with a warning and left untouched for inspection.
+#[derive(Debug, Clone)]
+pub struct JsonlStore {
+    dir: PathBuf,
+}
+
+impl JsonlStore {
+    /// Opens the store in the given directory, creating it if needed.
+    #[instrument(fields(dir = %dir.as_ref().display()))]
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, String> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        info!("Opened session store");
+        Ok(Self { dir })
+    }
+
+    /// Returns the directory holding the logs.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of a session's log file.
+    fn path(&self, session_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.jsonl", encode_file_name(session_id)))
+    }
+
+    /// Reads one log file, dropping a torn last line.
+    ///
+    /// Only the last line can be torn, and only while it lacks its
+    /// newline; it is cut from the file. A broken line anywhere else
+    /// means the log is corrupt, so it is reported and the file is left
+    /// as it is.
+    fn read_log<G: Game>(path: &Path) -> Result<Vec<LoggedEvent<G>>, String> {
+        let contents = fs::read(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        let mut events = Vec::new();
+        let mut intact = 0;
+        for (index, line) in contents.split_inclusive(|&byte| byte == b'\n').enumerate() {
+            // A write that never reached its newline never finished
+            let Some(json) = line.strip_suffix(b"\n") else {
+                break;
+            };
+            let event = serde_json::from_slice::<LoggedEvent<G>>(json).map_err(|e| {
+                format!("{} is corrupt at line {}: {}", path.display(), index + 1, e)
+            })?;
+            events.push(event);
+            intact += line.len();
+        }
+
+        if intact < contents.len() {
+            warn!(
+                path = %path.display(),
+                dropped_bytes = contents.len() - intact,
+                "Dropping torn last line of session log"
+            );
+            let file = OpenOptions::new()
+                .write(true)
+                .open(path)
+                .map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+            file.set_len(intact as u64)
+                .map_err(|e| format!("Could not truncate {}: {}", path.display(), e))?;
+        }
+        Ok(events)
+    }
+}
+
+impl<G: Game> SessionStore<G> for JsonlStore {
+    #[instrument(skip(self), fields(dir = %self.dir.display()))]
+    fn load(&self) -> Result<Vec<(SessionId, Vec<LoggedEvent<G>>)>, String> {
+        let entries = fs::read_dir(&self.dir)
+            .map_err(|e| format!("Could not read {}: {}", self.dir.display(), e))?;
+
+        let mut logs = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| format!("Could not read {}: {}", self.dir.display(), e))?
+                .path();
+            if path.extension().is_none_or(|ext| ext != "jsonl") {
+                continue;
+            }
+            let Some(session_id) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(decode_file_name)
+            else {
+                warn!(path = %path.display(), "Skipping log with an unrecognised name");
+                continue;
+            };
+
+            let events = match Self::read_log(&path) {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!(session_id = %session_id, error = %e, "Skipping unreadable session log");
+                    continue;
+                }
+            };
+            debug!(session_id = %session_id, events = events.len(), "Loaded session log");
+            logs.push((session_id, events));
+        }
+
+        info!(sessions = logs.len(), "Loaded session logs");
+        Ok(logs)
+    }
+
+    fn append(&self, session_id: &str, events: &[LoggedEvent<G>]) -> Result<(), String> {
+        let path = self.path(session_id);
+        let lines = encode_lines(events)?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+        file.write_all(lines.as_bytes())
+            .and_then(|()| file.sync_data())
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+        Ok(())
+    }
+
+    fn replace(&self, session_id: &str, events: &[LoggedEvent<G>]) -> Result<(), String> {
+        let path = self.path(session_id);
+        let staging = path.with_extension("jsonl.tmp");
+        let lines = encode_lines(events)?;
+
+        // The old log stays whole until the new one is on disk
+        let mut file = File::create(&staging)
+            .map_err(|e| format!("Could not create {}: {}", staging.display(), e))?;
+        file.write_all(lines.as_bytes())
+            .and_then(|()| file.sync_data())
+            .map_err(|e| format!("Could not write {}: {}", staging.display(), e))?;
+        fs::rename(&staging, &path)
+            .map_err(|e| format!("Could not replace {}: {}", path.display(), e))?;
+        Ok(())
+    }
+
+    fn remove(&self, session_id: &str) -> Result<(), String> {
+        let path = self.path(session_id);
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(format!("Could not remove {}: {}", path.display(), e)),
+        }
+    }
+}
+
+/// Serializes events as JSON lines, each ending in a newline.
+fn encode_lines<G: Game>(events: &[LoggedEvent<G>]) -> Result<String, String> {
+    let mut lines = String::new();
+    for event in events {
+        let json = serde_json::to_string(event)
+            .map_err(|e| format!("Could not serialize event {}: {}", event.seq, e))?;
+        lines.push_str(&json);
+        lines.push('\n');
+    }
+    Ok(lines)
+}
+
+/// Turns a session ID into a safe file name.
+///
+/// Letters, digits, `-` and `_` are kept; every other byte becomes `%XX`.
+fn encode_file_name(session_id: &str) -> String {
+    session_id
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Recovers the session ID from a file name made by [`encode_file_name`].
+fn decode_file_name(name: &str) -> Option<SessionId> {
+    let mut bytes = Vec::with_capacity(name.len());
+    let mut rest = name.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
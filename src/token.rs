@@ -0,0 +1,55 @@
+//! Secret tokens that prove a caller holds a seat.
+//!
+//! Player IDs are derived from names and shown to everyone, so they can't
+//! authorize anything. Registering a player issues a [`PlayerToken`]
+//! instead: a random secret bound to the seat, which every command acting
+//! for that player must present.
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Number of random bytes in a token.
+const TOKEN_BYTES: usize = 32;
+
+/// Random secret issued to a player when they take a seat.
+///
+/// `Debug` hides the secret so tokens never end up in logs.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PlayerToken(String);
+
+impl PlayerToken {
+    /// Draws a fresh token from the thread's cryptographically secure generator.
+    pub fn generate() -> Self {
+        Self(random_secret())
+    }
+
+    /// Returns the secret, to hand to the player it was issued to.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true if the presented secret is this token.
+    ///
+    /// Takes the same time wherever the first difference is, so response
+    /// times don't reveal how much of a guess was right.
+    pub fn matches(&self, presented: &str) -> bool {
+        let (ours, theirs) = (self.0.as_bytes(), presented.as_bytes());
+        ours.len() == theirs.len()
+            && ours.iter().zip(theirs).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+}
+
+/// Returns a fresh random secret as lowercase hex.
+///
+/// Also used for matchmaking tickets, which are redeemed for a token.
+pub(crate) fn random_secret() -> String {
+    let bytes: [u8; TOKEN_BYTES] = rand::rng().random();
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+impl std::fmt::Debug for PlayerToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PlayerToken(..)")
+    }
+}
@@ -0,0 +1,198 @@
+//! Connect Four game loop for the TUI.
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::Terminal;
+use tracing::{error, info, instrument};
+
+use super::input;
+use super::rest_client::RestGameClient;
+use crate::games::connect_four::{Board, Column, Player, Square};
+
+/// Type-safe Connect Four loop using REST API.
+///
+/// Left/Right choose a column, Enter drops a piece into it.
+#[instrument(skip_all, fields(session_id = %client.session_id, player_id = %client.player_id))]
+pub async fn run_connect_four_game<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    mut client: RestGameClient,
+) -> Result<()>
+where
+    <B as ratatui::backend::Backend>::Error: Send + Sync + 'static,
+{
+    use tokio::time::{sleep, Duration};
+
+    info!("Starting Connect Four game loop");
+
+    let mut cursor = Column::Four;
+
+    loop {
+        // Get game state (type-safe!)
+        let game = client.get_connect_four_game().await?;
+        // Match score for the status bar; single games have none
+        let match_play = client.get_match().await.ok().flatten();
+
+        // Render UI
+        terminal.draw(|f| {
+            use ratatui::{
+                layout::{Alignment, Constraint, Direction, Layout},
+                style::{Color, Modifier, Style},
+                widgets::{Block, Borders, Paragraph},
+            };
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3),  // Title
+                    Constraint::Min(0),     // Board
+                    Constraint::Length(3),  // Status
+                    Constraint::Length(3),  // Help
+                ])
+                .split(f.area());
+
+            // Title
+            let title = Paragraph::new("Strictly Games - Connect Four (Type-Safe)")
+                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL));
+            f.render_widget(title, chunks[0]);
+
+            // Board (with column cursor!)
+            let board_lines = render_board_with_cursor(game.board(), cursor);
+            let board = Paragraph::new(board_lines)
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL).title("Board"));
+            f.render_widget(board, chunks[1]);
+
+            // Status (type-safe!)
+            let status_text = if game.is_over() {
+                if let Some(winner) = game.winner() {
+                    format!("Game Over! {} wins! Press 'r' to restart, 'u' to undo, 'q' to quit",
+                        if winner == Player::X { "X" } else { "O" })
+                } else {
+                    "Game Over! Draw! Press 'r' to restart, 'u' to undo, 'q' to quit".to_string()
+                }
+            } else if let Some(player) = game.to_move() {
+                format!("Player {} to move. Use arrow keys + Enter",
+                    if player == Player::X { "X" } else { "O" })
+            } else {
+                "Waiting...".to_string()
+            };
+
+            // Color status based on errors
+            let status_color = if client.last_error.is_some() {
+                Color::Red
+            } else {
+                Color::Yellow
+            };
+
+            let status_title = match_play.as_ref()
+                .map_or_else(|| "Status".to_string(), |m| format!("Status | Match: {}", m.summary()));
+            let status = Paragraph::new(status_text)
+                .style(Style::default().fg(status_color))
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL).title(status_title));
+            f.render_widget(status, chunks[2]);
+
+            // Help / Error message
+            let help_text = if let Some(ref error) = client.last_error {
+                format!("ERROR: {}", error)
+            } else {
+                "Left/Right: Choose column | Enter: Drop | U: Undo | Q: Quit | R: Restart".to_string()
+            };
+
+            let help_color = if client.last_error.is_some() {
+                Color::Red
+            } else {
+                Color::DarkGray
+            };
+
+            let help = Paragraph::new(help_text)
+                .style(Style::default().fg(help_color))
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL));
+            f.render_widget(help, chunks[3]);
+        })?;
+
+        // Handle game over
+        if game.is_over() {
+            if event::poll(Duration::from_millis(100))?
+                && let Event::Key(key) = event::read()?
+            {
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(()),
+                    KeyCode::Char('r') | KeyCode::Char('R') => {
+                        info!("Restarting game");
+                        if let Err(e) = client.restart_game().await {
+                            error!(error = %e, "Restart failed");
+                        }
+                        sleep(Duration::from_millis(200)).await; // Let server process
+                    }
+                    KeyCode::Char('u' | 'U' | 'y' | 'Y' | 'n' | 'N') => {
+                        super::handle_undo_key(&mut client, key.code).await;
+                        sleep(Duration::from_millis(200)).await; // Let server process
+                    }
+                    _ => {}
+                }
+            }
+            sleep(Duration::from_millis(100)).await;
+            continue;
+        }
+
+        // Handle input
+        if event::poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+        {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(()),
+                KeyCode::Enter | KeyCode::Down => {
+                    info!(column = ?cursor, "Dropping piece");
+                    if let Err(e) = client.drop_piece(cursor).await {
+                        error!(error = %e, "Move failed");
+                    }
+                    sleep(Duration::from_millis(200)).await; // Let server process
+                }
+                KeyCode::Left | KeyCode::Right => {
+                    cursor = input::move_column_cursor(cursor, key.code);
+                }
+                KeyCode::Char('u' | 'U' | 'y' | 'Y' | 'n' | 'N') => {
+                    super::handle_undo_key(&mut client, key.code).await;
+                    sleep(Duration::from_millis(200)).await; // Let server process
+                }
+                _ => {}
+            }
+        }
+
+        sleep(Duration::from_millis(50)).await;
+    }
+}
+
+/// Renders board with a drop marker above the cursor column.
+fn render_board_with_cursor(board: &Board, cursor: Column) -> String {
+    let mut lines = Vec::new();
+
+    // Drop marker row
+    let marker: String = Column::ALL
+        .iter()
+        .map(|&column| if column == cursor { " v " } else { "   " })
+        .collect();
+    lines.push(marker);
+
+    // Top row first so pieces appear to fall downwards
+    for row in (0..Board::ROWS).rev() {
+        let line: String = Column::ALL
+            .iter()
+            .map(|&column| match board.get(row, column) {
+                Square::Empty => " . ",
+                Square::Occupied(Player::X) => " X ",
+                Square::Occupied(Player::O) => " O ",
+            })
+            .collect();
+        lines.push(line);
+    }
+
+    let footer: String = (1..=Board::COLUMNS).map(|n| format!(" {} ", n)).collect();
+    lines.push(footer);
+
+    lines.join("\n")
+}
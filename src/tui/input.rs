@@ -1,5 +1,7 @@
 //! Cursor movement for keyboard navigation.
 
+use crate::games::connect_four::Column;
+use crate::games::mnk::{Coord, MnkConfig};
 use crate::games::tictactoe::Position;
 use crossterm::event::KeyCode;
 
@@ -44,3 +46,30 @@ pub fn move_cursor(cursor: Position, key: KeyCode) -> Position {
         _ => cursor,
     }
 }
+
+/// Moves a Connect Four column cursor based on arrow keys.
+pub fn move_column_cursor(cursor: Column, key: KeyCode) -> Column {
+    let index = cursor.to_index();
+
+    let next = match key {
+        KeyCode::Left => index.checked_sub(1),
+        KeyCode::Right => Some(index + 1),
+        _ => None,
+    };
+
+    // No change for other keys or at the board edges
+    next.and_then(Column::from_index).unwrap_or(cursor)
+}
+
+/// Moves an m,n,k cell cursor based on arrow keys, staying on the board.
+pub fn move_coord_cursor(cursor: Coord, key: KeyCode, config: MnkConfig) -> Coord {
+    let Coord { row, column } = cursor;
+
+    match key {
+        KeyCode::Up => Coord::new(row.saturating_sub(1), column),
+        KeyCode::Down if row + 1 < config.rows => Coord::new(row + 1, column),
+        KeyCode::Left => Coord::new(row, column.saturating_sub(1)),
+        KeyCode::Right if column + 1 < config.columns => Coord::new(row, column + 1),
+        _ => cursor,
+    }
+}
@@ -5,6 +5,9 @@
 mod rest_client;  // Type-safe REST client
 mod standalone;
 mod input;  // Cursor movement
+mod connect_four;  // Connect Four game loop
+mod gomoku;  // Gomoku game loop
+mod spectate;  // Read-only spectator view
 
 use anyhow::Result;
 use crossterm::{
@@ -16,12 +19,26 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use std::{io, path::PathBuf};
 use tracing::{error, info, instrument};
 
-use crate::games::tictactoe::Position;
-use rest_client::RestGameClient;
+use crate::games::{Difficulty, GameKind};
+use crate::games::connect_four::ConnectFour;
+use crate::games::mnk::Gomoku;
+use crate::games::tictactoe::{Position, TicTacToe};
+use rest_client::{RestGameClient, SpectatorClient};
 
 /// Run the TUI client
-#[instrument(skip_all, fields(server_url = ?server_url, port, agent_config = %agent_config.display()))]
-pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -> Result<()> {
+///
+/// With `bot` set, a built-in bot of that difficulty takes the other seat
+/// and standalone mode doesn't spawn an agent. With `spectate` set, the
+/// given session on the server is watched instead of played.
+#[instrument(skip_all, fields(server_url = ?server_url, port, agent_config = %agent_config.display(), game = %game, bot = ?bot, spectate = ?spectate))]
+pub async fn run(
+    server_url: Option<String>,
+    port: u16,
+    agent_config: PathBuf,
+    game: GameKind,
+    bot: Option<Difficulty>,
+    spectate: Option<String>,
+) -> Result<()> {
     // Setup logging to file to avoid interfering with TUI
     let log_file = std::fs::File::create("strictly_games_tui.log")?;
     let _ = tracing_subscriber::fmt()
@@ -34,6 +51,12 @@ pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -
         .try_init(); // Don't panic if already initialized
 
     info!("Starting Strictly Games TUI");
+
+    if let Some(session_id) = spectate {
+        let url = server_url
+            .ok_or_else(|| anyhow::anyhow!("Spectating needs the --server-url of the game server"))?;
+        return run_spectator(url, session_id).await;
+    }
     
     let session_id = "tui_session".to_string();
     
@@ -43,9 +66,10 @@ pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -
         info!(server_url = %url, "Connecting to remote server");
         (url, None)
     } else {
-        // Standalone mode: spawn server and agent
+        // Standalone mode: spawn server (and agent, unless playing a bot)
         info!(port, "Starting standalone mode");
-        let guards = standalone::spawn_standalone(port, agent_config).await?;
+        let spawn_agent = bot.is_none();
+        let guards = standalone::spawn_standalone(port, agent_config, game, spawn_agent).await?;
         let url = format!("http://localhost:{}", port);
         info!(server_url = %url, "Standalone mode initialized");
         (url, Some(guards))
@@ -54,10 +78,11 @@ pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -
     info!(server_url = %actual_server_url, session_id = %session_id, "Connecting to game server");
     
     // Register as human player using REST client
-    let client = match RestGameClient::register(
+    let mut client = match RestGameClient::register(
         actual_server_url,
         session_id,
         "Human".to_string(),
+        game,
     ).await {
         Ok(c) => {
             info!("Successfully registered with server");
@@ -69,6 +94,11 @@ pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -
         }
     };
     
+    if let Some(difficulty) = bot {
+        client.register_bot(difficulty).await?;
+        info!(difficulty = %difficulty, "Bot opponent seated");
+    }
+    
     // Setup terminal after server connection succeeds
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -78,8 +108,12 @@ pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -
 
     info!("Registered with server, starting game loop");
     
-    // Run type-safe game loop
-    let res = run_typesafe_game(&mut terminal, client).await;
+    // Run type-safe game loop for the selected game
+    let res = match game {
+        GameKind::TicTacToe => run_typesafe_game(&mut terminal, client).await,
+        GameKind::ConnectFour => connect_four::run_connect_four_game(&mut terminal, client).await,
+        GameKind::Gomoku => gomoku::run_gomoku_game(&mut terminal, client).await,
+    };
     
     // Restore terminal
     disable_raw_mode()?;
@@ -100,6 +134,38 @@ pub async fn run(server_url: Option<String>, port: u16, agent_config: PathBuf) -
     Ok(())
 }
 
+/// Watches a session on a running server until the user quits.
+#[instrument(skip_all, fields(server_url = %server_url, session_id = %session_id))]
+async fn run_spectator(server_url: String, session_id: String) -> Result<()> {
+    let client = SpectatorClient::join(server_url, session_id, "Spectator".to_string()).await?;
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let res = match client.game {
+        GameKind::TicTacToe => spectate::run_spectator::<TicTacToe, _>(&mut terminal, &client).await,
+        GameKind::ConnectFour => spectate::run_spectator::<ConnectFour, _>(&mut terminal, &client).await,
+        GameKind::Gomoku => spectate::run_spectator::<Gomoku, _>(&mut terminal, &client).await,
+    };
+
+    // Restore terminal
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    if let Err(e) = client.leave().await {
+        error!(error = %e, "Failed to stop spectating");
+    }
+    res
+}
+
 /// Type-safe game loop using REST API.
 #[instrument(skip_all, fields(session_id = %client.session_id, player_id = %client.player_id))]
 async fn run_typesafe_game<B: ratatui::backend::Backend>(
@@ -119,6 +185,8 @@ where
     loop {
         // Get game state (type-safe!)
         let game = client.get_game().await?;
+        // Match score for the status bar; single games have none
+        let match_play = client.get_match().await.ok().flatten();
         
         // Render UI
         terminal.draw(|f| {
@@ -155,10 +223,10 @@ where
             // Status (type-safe!)
             let status_text = if game.is_over() {
                 if let Some(winner) = game.winner() {
-                    format!("Game Over! {} wins! Press 'r' to restart, 'q' to quit", 
+                    format!("Game Over! {} wins! Press 'r' to restart, 'u' to undo, 'q' to quit", 
                         if winner == Player::X { "X" } else { "O" })
                 } else {
-                    "Game Over! Draw! Press 'r' to restart, 'q' to quit".to_string()
+                    "Game Over! Draw! Press 'r' to restart, 'u' to undo, 'q' to quit".to_string()
                 }
             } else if let Some(player) = game.to_move() {
                 format!("Player {} to move. Use arrow keys + Enter", 
@@ -174,17 +242,19 @@ where
                 Color::Yellow
             };
             
+            let status_title = match_play.as_ref()
+                .map_or_else(|| "Status".to_string(), |m| format!("Status | Match: {}", m.summary()));
             let status = Paragraph::new(status_text)
                 .style(Style::default().fg(status_color))
                 .alignment(Alignment::Center)
-                .block(Block::default().borders(Borders::ALL).title("Status"));
+                .block(Block::default().borders(Borders::ALL).title(status_title));
             f.render_widget(status, chunks[2]);
             
             // Help / Error message
             let help_text = if let Some(ref error) = client.last_error {
                 format!("ERROR: {}", error)
             } else {
-                "Arrow keys: Move | Enter: Place | Q: Quit | R: Restart".to_string()
+                "Arrow keys: Move | Enter: Place | U: Undo | Q: Quit | R: Restart".to_string()
             };
             
             let help_color = if client.last_error.is_some() {
@@ -213,6 +283,10 @@ where
                             }
                             sleep(Duration::from_millis(200)).await; // Let server process
                         }
+                        KeyCode::Char('u' | 'U' | 'y' | 'Y' | 'n' | 'N') => {
+                            handle_undo_key(&mut client, key.code).await;
+                            sleep(Duration::from_millis(200)).await; // Let server process
+                        }
                         _ => {}
                     }
                 }
@@ -236,6 +310,10 @@ where
                     KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => {
                         cursor = input::move_cursor(cursor, key.code);
                     }
+                    KeyCode::Char('u' | 'U' | 'y' | 'Y' | 'n' | 'N') => {
+                        handle_undo_key(&mut client, key.code).await;
+                        sleep(Duration::from_millis(200)).await; // Let server process
+                    }
                     _ => {}
                 }
             }
@@ -245,6 +323,26 @@ where
     }
 }
 
+/// Handles the take-back keys shared by every game loop.
+///
+/// U asks the opponent to undo our last move; Y and N accept or decline
+/// the opponent's own request. Failures are shown via `last_error`.
+async fn handle_undo_key(client: &mut RestGameClient, code: KeyCode) {
+    let result = match code {
+        KeyCode::Char('u') | KeyCode::Char('U') => {
+            info!("Requesting undo");
+            client.request_undo().await
+        }
+        KeyCode::Char('y') | KeyCode::Char('Y') => client.respond_undo(true).await,
+        KeyCode::Char('n') | KeyCode::Char('N') => client.respond_undo(false).await,
+        _ => return,
+    };
+
+    if let Err(e) = result {
+        error!(error = %e, "Undo failed");
+    }
+}
+
 /// Renders board with cursor highlighting.
 fn render_board_with_cursor(board: &crate::games::tictactoe::Board, cursor: Position) -> String {
     use crate::games::tictactoe::{Player, Square};
@@ -1,7 +1,12 @@
 //! Type-safe HTTP client using REST API.
 
 use anyhow::{Context, Result};
+use crate::events::LoggedEvent;
+use crate::games::{Difficulty, Game, GameKind};
+use crate::games::connect_four::{self, Column};
+use crate::games::mnk::{self, Coord};
 use crate::games::tictactoe::{AnyGame, Position};
+use crate::series::Match;
 use tracing::{debug, info, instrument};
 
 /// Type-safe HTTP game client.
@@ -11,88 +16,51 @@ pub struct RestGameClient {
     client: reqwest::Client,
     pub session_id: String,
     pub player_id: String,
+    token: String,  // Proves this client holds the seat
     pub last_error: Option<String>,  // Track last error for display
+    pub game: GameKind,
     mcp_session_id: String,  // For MCP tool calls
 }
 
 impl RestGameClient {
     /// Creates a new REST client by registering with the server via MCP.
-    #[instrument(skip_all, fields(base_url = %base_url, session_id = %session_id, name = %name))]
+    #[instrument(skip_all, fields(base_url = %base_url, session_id = %session_id, name = %name, game = %game))]
     pub async fn register(
         base_url: String,
         session_id: String,
         name: String,
+        game: GameKind,
     ) -> Result<Self> {
         info!("Registering with server");
         
         let client = reqwest::Client::new();
         
         // Register via MCP (keep this for player setup)
-        let (player_id, mcp_session_id) = Self::mcp_register(&client, &base_url, &session_id, &name).await?;
+        let (player_id, token, mcp_session_id) = Self::mcp_register(&client, &base_url, &session_id, &name, game).await?;
         
         Ok(Self {
             base_url,
             client,
             session_id,
             player_id,
+            token,
             last_error: None,
+            game,
             mcp_session_id,
         })
     }
     
     /// MCP registration (creates player association).
+    ///
+    /// Returns the player ID and token issued by the server, and the MCP session ID.
     async fn mcp_register(
         client: &reqwest::Client,
         base_url: &str,
         session_id: &str,
         name: &str,
-    ) -> Result<(String, String)> {
-        // Initialize MCP session
-        let init_req = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "initialize",
-            "params": {
-                "protocolVersion": "2024-11-05",
-                "capabilities": {},
-                "clientInfo": {
-                    "name": "strictly-games-tui",
-                    "version": "0.1.0"
-                }
-            }
-        });
-        
-        let response = client
-            .post(&format!("{}/message", base_url))
-            .header("Content-Type", "application/json")
-            .header("Accept", "application/json, text/event-stream")
-            .json(&init_req)
-            .send()
-            .await?;
-            
-        let mcp_session_id = response
-            .headers()
-            .get("mcp-session-id")
-            .and_then(|h| h.to_str().ok())
-            .context("No MCP session ID in response headers")?
-            .to_string();
-        
-        debug!(mcp_session_id = %mcp_session_id, "MCP session initialized");
-        
-        // Send initialized notification
-        let init_notif = serde_json::json!({
-            "jsonrpc": "2.0",
-            "method": "notifications/initialized"
-        });
-        
-        client
-            .post(&format!("{}/message", base_url))
-            .header("Content-Type", "application/json")
-            .header("Accept", "application/json, text/event-stream")
-            .header("mcp-session-id", &mcp_session_id)
-            .json(&init_notif)
-            .send()
-            .await?;
+        game: GameKind,
+    ) -> Result<(String, String, String)> {
+        let mcp_session_id = mcp_initialize(client, base_url).await?;
         
         // Register player
         let register_req = serde_json::json!({
@@ -104,7 +72,8 @@ impl RestGameClient {
                 "arguments": {
                     "session_id": session_id,
                     "name": name,
-                    "type": "human"
+                    "type": "human",
+                    "game": game
                 }
             }
         });
@@ -126,10 +95,13 @@ impl RestGameClient {
             return Err(anyhow::anyhow!("Registration failed: {}", response_text));
         }
         
-        let player_id = format!("{}_{}", session_id, name.to_lowercase());
+        let text = tool_result_text(&response_text)
+            .context("No tool result in registration response")?;
+        let player_id = response_field(&text, "Player ID: ")?;
+        let token = response_field(&text, "Token: ")?;
         info!(player_id = %player_id, "Registered successfully");
         
-        Ok((player_id, mcp_session_id))
+        Ok((player_id, token, mcp_session_id))
     }
     
     /// Gets the current game state (type-safe!).
@@ -137,7 +109,7 @@ impl RestGameClient {
     pub async fn get_game(&self) -> Result<AnyGame> {
         debug!("Getting game state via REST");
         
-        let url = format!("{}/api/sessions/{}/game", self.base_url, self.session_id);
+        let url = format!("{}/game", self.session_url());
         let game: AnyGame = self.client
             .get(&url)
             .send()
@@ -149,28 +121,155 @@ impl RestGameClient {
         Ok(game)
     }
     
+    /// Gets the current Connect Four game state (type-safe!).
+    #[instrument(skip(self))]
+    pub async fn get_connect_four_game(&self) -> Result<connect_four::AnyGame> {
+        debug!("Getting Connect Four game state via REST");
+        
+        let url = format!("{}/game", self.session_url());
+        let game: connect_four::AnyGame = self.client
+            .get(&url)
+            .send()
+            .await?
+            .json()
+            .await?;
+        
+        debug!(is_over = game.is_over(), "Got game state");
+        Ok(game)
+    }
+    
+    /// Gets the current Gomoku game state (type-safe!).
+    #[instrument(skip(self))]
+    pub async fn get_gomoku_game(&self) -> Result<mnk::AnyGame> {
+        debug!("Getting Gomoku game state via REST");
+        
+        let url = format!("{}/game", self.session_url());
+        let game: mnk::AnyGame = self.client
+            .get(&url)
+            .send()
+            .await?
+            .json()
+            .await?;
+        
+        debug!(is_over = game.is_over(), "Got game state");
+        Ok(game)
+    }
+    
+    /// Gets the session's match, or `None` if it plays single games.
+    #[instrument(skip(self))]
+    pub async fn get_match(&self) -> Result<Option<Match>> {
+        let url = format!("{}/match", self.session_url());
+        let match_play: Option<Match> = self.client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        debug!(has_match = match_play.is_some(), "Got match");
+        Ok(match_play)
+    }
+    
     /// Makes a move via MCP tool.
     #[instrument(skip(self), fields(position = ?position))]
     pub async fn make_move(&mut self, position: Position) -> Result<()> {
         info!("Making move");
-        self.last_error = None;  // Clear previous error
         
         // Serialize Position properly using serde
         let position_value = serde_json::to_value(&position)?;
         debug!(position_json = %position_value, "Serialized position");
         
-        // Use MCP tool for making moves (triggers elicitation)
+        self.call_move_tool("make_move", serde_json::json!({
+            "session_id": self.session_id,
+            "player_id": self.player_id,
+            "token": self.token,
+            "position": position_value
+        })).await
+    }
+    
+    /// Drops a Connect Four piece via MCP tool.
+    #[instrument(skip(self), fields(column = ?column))]
+    pub async fn drop_piece(&mut self, column: Column) -> Result<()> {
+        info!("Dropping piece");
+        
+        let column_value = serde_json::to_value(&column)?;
+        debug!(column_json = %column_value, "Serialized column");
+        
+        self.call_move_tool("drop_piece", serde_json::json!({
+            "session_id": self.session_id,
+            "player_id": self.player_id,
+            "token": self.token,
+            "column": column_value
+        })).await
+    }
+    
+    /// Places a Gomoku stone via MCP tool.
+    #[instrument(skip(self), fields(coord = %coord))]
+    pub async fn place_stone(&mut self, coord: Coord) -> Result<()> {
+        info!("Placing stone");
+        
+        let coord_value = serde_json::to_value(coord)?;
+        debug!(coord_json = %coord_value, "Serialized coord");
+        
+        self.call_move_tool("place_stone", serde_json::json!({
+            "session_id": self.session_id,
+            "player_id": self.player_id,
+            "token": self.token,
+            "coord": coord_value
+        })).await
+    }
+    
+    /// Asks the opponent to take back this player's last move via MCP tool.
+    #[instrument(skip(self))]
+    pub async fn request_undo(&mut self) -> Result<()> {
+        info!("Requesting undo");
+        
+        self.call_move_tool("request_undo", serde_json::json!({
+            "session_id": self.session_id,
+            "player_id": self.player_id,
+            "token": self.token
+        })).await
+    }
+    
+    /// Accepts or declines the opponent's undo request via MCP tool.
+    #[instrument(skip(self))]
+    pub async fn respond_undo(&mut self, accept: bool) -> Result<()> {
+        info!("Responding to undo request");
+        
+        self.call_move_tool("respond_undo", serde_json::json!({
+            "session_id": self.session_id,
+            "player_id": self.player_id,
+            "token": self.token,
+            "accept": accept
+        })).await
+    }
+    
+    /// Seats a built-in bot of the given difficulty in this session via MCP tool.
+    #[instrument(skip(self), fields(difficulty = %difficulty))]
+    pub async fn register_bot(&mut self, difficulty: Difficulty) -> Result<()> {
+        info!("Registering bot opponent");
+        
+        self.call_move_tool("register_player", serde_json::json!({
+            "session_id": self.session_id,
+            "name": format!("Bot ({})", difficulty),
+            "type": "bot",
+            "game": self.game,
+            "difficulty": difficulty
+        })).await
+    }
+    
+    /// Calls a move tool via MCP, recording any error for display.
+    async fn call_move_tool(&mut self, tool: &str, arguments: serde_json::Value) -> Result<()> {
+        self.last_error = None;  // Clear previous error
+        
         let request = serde_json::json!({
             "jsonrpc": "2.0",
             "id": 3,
             "method": "tools/call",
             "params": {
-                "name": "make_move",
-                "arguments": {
-                    "session_id": self.session_id,
-                    "player_id": self.player_id,
-                    "position": position_value
-                }
+                "name": tool,
+                "arguments": arguments
             }
         });
         
@@ -215,7 +314,8 @@ impl RestGameClient {
         self.last_error = None;
         
         let response = self.client
-            .post(&format!("{}/api/sessions/{}/restart", self.base_url, self.session_id))
+            .post(&format!("{}/restart", self.session_url()))
+            .bearer_auth(&self.token)
             .send()
             .await?;
         
@@ -226,4 +326,234 @@ impl RestGameClient {
         
         Ok(())
     }
+    
+    /// REST URL of this session, under the route for its game.
+    fn session_url(&self) -> String {
+        session_url(&self.base_url, self.game, &self.session_id)
+    }
+}
+
+/// Read-only HTTP client for watching a session as a spectator.
+#[derive(Debug, Clone)]
+pub struct SpectatorClient {
+    base_url: String,
+    client: reqwest::Client,
+    pub session_id: String,
+    pub spectator_id: String,
+    pub game: GameKind,
+    token: String,  // Proves this client is the spectator when leaving
+    mcp_session_id: String,  // For MCP tool calls
+}
+
+impl SpectatorClient {
+    /// Joins a session as a spectator via MCP.
+    ///
+    /// The session's game is taken from the server's answer.
+    #[instrument(skip_all, fields(base_url = %base_url, session_id = %session_id, name = %name))]
+    pub async fn join(base_url: String, session_id: String, name: String) -> Result<Self> {
+        info!("Joining as spectator");
+
+        let client = reqwest::Client::new();
+        let mcp_session_id = mcp_initialize(&client, &base_url).await?;
+
+        let spectate_req = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": {
+                "name": "spectate",
+                "arguments": {
+                    "session_id": session_id,
+                    "name": name
+                }
+            }
+        });
+
+        let response_text = client
+            .post(&format!("{}/message", base_url))
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json, text/event-stream")
+            .header("mcp-session-id", &mcp_session_id)
+            .json(&spectate_req)
+            .send()
+            .await?
+            .text()
+            .await?;
+        debug!(response = %response_text, "Spectate response");
+
+        if response_text.contains("\"error\"") {
+            anyhow::bail!("Spectating failed: {}", response_text);
+        }
+
+        let text = tool_result_text(&response_text)
+            .context("No tool result in spectate response")?;
+        let spectator_id = response_field(&text, "Spectator ID: ")?;
+        let token = response_field(&text, "Token: ")?;
+        let game: GameKind = response_field(&text, "Game: ")?
+            .parse()
+            .context("Unknown game in spectate response")?;
+        info!(spectator_id = %spectator_id, game = %game, "Watching session");
+
+        Ok(Self {
+            base_url,
+            client,
+            session_id,
+            spectator_id,
+            game,
+            token,
+            mcp_session_id,
+        })
+    }
+
+    /// Gets the current state of the session's game.
+    #[instrument(skip(self), fields(game = G::NAME))]
+    pub async fn get_state<G: Game>(&self) -> Result<G::State> {
+        let url = format!("{}/game", self.session_url());
+        let state: G::State = self.client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(state)
+    }
+
+    /// Gets the session's events from `from` on.
+    ///
+    /// If there are none yet, the server holds the request for up to
+    /// `wait_secs` until one is logged.
+    #[instrument(skip(self), fields(game = G::NAME))]
+    pub async fn get_events<G: Game>(&self, from: usize, wait_secs: u64) -> Result<Vec<LoggedEvent<G>>> {
+        let url = format!("{}/events?from={}&wait_secs={}", self.session_url(), from, wait_secs);
+        let events: Vec<LoggedEvent<G>> = self.client
+            .get(&url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        debug!(count = events.len(), "Got events");
+        Ok(events)
+    }
+
+    /// Stops watching the session via MCP tool.
+    #[instrument(skip(self))]
+    pub async fn leave(&self) -> Result<()> {
+        info!("Leaving as spectator");
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "tools/call",
+            "params": {
+                "name": "stop_spectating",
+                "arguments": {
+                    "session_id": self.session_id,
+                    "spectator_id": self.spectator_id,
+                    "token": self.token
+                }
+            }
+        });
+
+        self.client
+            .post(&format!("{}/message", self.base_url))
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json, text/event-stream")
+            .header("mcp-session-id", &self.mcp_session_id)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    /// REST URL of this session, under the route for its game.
+    fn session_url(&self) -> String {
+        session_url(&self.base_url, self.game, &self.session_id)
+    }
+}
+
+/// REST URL of a session, under the route for its game.
+fn session_url(base_url: &str, game: GameKind, session_id: &str) -> String {
+    match game {
+        GameKind::TicTacToe => format!("{}/api/sessions/{}", base_url, session_id),
+        GameKind::ConnectFour => format!("{}/api/connect-four/sessions/{}", base_url, session_id),
+        GameKind::Gomoku => format!("{}/api/gomoku/sessions/{}", base_url, session_id),
+    }
+}
+
+/// Returns the rest of the line starting with `label` in a tool result.
+fn response_field(text: &str, label: &str) -> Result<String> {
+    text.lines()
+        .find_map(|line| line.strip_prefix(label))
+        .map(str::to_string)
+        .with_context(|| format!("No {} in server response", label.trim_end_matches(": ")))
+}
+
+/// Extracts the text of a tool result from an MCP response body.
+///
+/// The body is either plain JSON or a server-sent event stream whose
+/// `data:` lines carry the JSON.
+fn tool_result_text(body: &str) -> Option<String> {
+    body.lines()
+        .map(|line| line.strip_prefix("data:").unwrap_or(line).trim())
+        .filter_map(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+        .find_map(|message| {
+            message.pointer("/result/content/0/text")
+                .and_then(|text| text.as_str())
+                .map(str::to_string)
+        })
+}
+
+/// Opens an MCP session with the server and returns its ID.
+async fn mcp_initialize(client: &reqwest::Client, base_url: &str) -> Result<String> {
+    // Initialize MCP session
+    let init_req = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": {
+                "name": "strictly-games-tui",
+                "version": "0.1.0"
+            }
+        }
+    });
+    
+    let response = client
+        .post(&format!("{}/message", base_url))
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .json(&init_req)
+        .send()
+        .await?;
+        
+    let mcp_session_id = response
+        .headers()
+        .get("mcp-session-id")
+        .and_then(|h| h.to_str().ok())
+        .context("No MCP session ID in response headers")?
+        .to_string();
+    
+    debug!(mcp_session_id = %mcp_session_id, "MCP session initialized");
+    
+    // Send initialized notification
+    let init_notif = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/initialized"
+    });
+    
+    client
+        .post(&format!("{}/message", base_url))
+        .header("Content-Type", "application/json")
+        .header("Accept", "application/json, text/event-stream")
+        .header("mcp-session-id", &mcp_session_id)
+        .json(&init_notif)
+        .send()
+        .await?;
+    
+    Ok(mcp_session_id)
 }
@@ -0,0 +1,95 @@
+//! Spectator view for the TUI: follows a session without a seat.
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::Terminal;
+use tracing::{info, instrument};
+
+use super::rest_client::SpectatorClient;
+use crate::games::{Game, GameState};
+
+/// Number of events shown in the feed.
+const FEED_LINES: usize = 12;
+
+/// Seconds the server may hold each request for new events.
+///
+/// Kept short so the view still answers the quit key promptly.
+const WAIT_SECS: u64 = 1;
+
+/// Read-only loop for any game: shows the board and the session's events
+/// as they are logged.
+#[instrument(skip_all, fields(session_id = %client.session_id, spectator_id = %client.spectator_id, game = G::NAME))]
+pub async fn run_spectator<G: Game, B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    client: &SpectatorClient,
+) -> Result<()>
+where
+    <B as ratatui::backend::Backend>::Error: Send + Sync + 'static,
+{
+    use tokio::time::Duration;
+
+    info!("Starting spectator loop");
+
+    let mut feed: Vec<String> = Vec::new();
+    let mut seen = 0;
+
+    loop {
+        // Waits briefly on the server for the next event
+        let events = client.get_events::<G>(seen, WAIT_SECS).await?;
+        seen += events.len();
+        feed.extend(events.iter().map(|logged| format!("#{} {}", logged.seq, logged.event)));
+        let game = client.get_state::<G>().await?;
+
+        terminal.draw(|f| {
+            use ratatui::{
+                layout::{Alignment, Constraint, Direction, Layout},
+                style::{Color, Modifier, Style},
+                widgets::{Block, Borders, Paragraph},
+            };
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3),                      // Title
+                    Constraint::Min(0),                         // Board
+                    Constraint::Length(FEED_LINES as u16 + 2),  // Events
+                    Constraint::Length(3),                      // Status
+                ])
+                .split(f.area());
+
+            // Title
+            let title = Paragraph::new(format!("Strictly Games - Watching {} ({})", client.session_id, G::NAME))
+                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL));
+            f.render_widget(title, chunks[0]);
+
+            // Board, as the server renders it
+            let board = Paragraph::new(game.render())
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL).title("Board"));
+            f.render_widget(board, chunks[1]);
+
+            // Latest events
+            let latest = feed[feed.len().saturating_sub(FEED_LINES)..].join("\n");
+            let events = Paragraph::new(latest)
+                .style(Style::default().fg(Color::DarkGray))
+                .block(Block::default().borders(Borders::ALL).title("Events"));
+            f.render_widget(events, chunks[2]);
+
+            // Status
+            let status = Paragraph::new(format!("{} | Q: Stop watching", game.status_string()))
+                .style(Style::default().fg(Color::Yellow))
+                .alignment(Alignment::Center)
+                .block(Block::default().borders(Borders::ALL).title("Status"));
+            f.render_widget(status, chunks[3]);
+        })?;
+
+        if event::poll(Duration::from_millis(50))?
+            && let Event::Key(key) = event::read()?
+            && matches!(key.code, KeyCode::Char('q') | KeyCode::Char('Q'))
+        {
+            return Ok(());
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Standalone mode subprocess management.
 
+use crate::games::GameKind;
 use anyhow::{Context, Result};
 use std::path::PathBuf;
 use tokio::process::{Child, Command};
@@ -13,10 +14,10 @@ pub struct ProcessGuards {
 }
 
 impl ProcessGuards {
-    fn new(server: Child, agent: Child) -> Self {
+    fn new(server: Child, agent: Option<Child>) -> Self {
         Self {
             server: Some(server),
-            agent: Some(agent),
+            agent,
         }
     }
 }
@@ -38,8 +39,15 @@ impl Drop for ProcessGuards {
 }
 
 /// Spawns server and agent subprocesses for standalone mode.
-#[instrument(skip_all, fields(port, agent_config = %agent_config.display()))]
-pub async fn spawn_standalone(port: u16, agent_config: PathBuf) -> Result<ProcessGuards> {
+///
+/// The agent is skipped when `spawn_agent` is false (e.g. a bot takes its seat).
+#[instrument(skip_all, fields(port, agent_config = %agent_config.display(), game = %game, spawn_agent))]
+pub async fn spawn_standalone(
+    port: u16,
+    agent_config: PathBuf,
+    game: GameKind,
+    spawn_agent: bool,
+) -> Result<ProcessGuards> {
     info!("Starting standalone mode: spawning server and agent");
     
     // Get the path to the current executable
@@ -66,6 +74,11 @@ pub async fn spawn_standalone(port: u16, agent_config: PathBuf) -> Result<Proces
     wait_for_server_ready(&server_url).await
         .context("Server failed to become ready")?;
     
+    if !spawn_agent {
+        info!("Server is ready, no agent requested");
+        return Ok(ProcessGuards::new(server, None));
+    }
+    
     info!("Server is ready, spawning agent");
     
     // Spawn agent connected to the server, joining the TUI session
@@ -78,6 +91,8 @@ pub async fn spawn_standalone(port: u16, agent_config: PathBuf) -> Result<Proces
         .arg("--test-play")
         .arg("--test-session")
         .arg("tui_session")
+        .arg("--test-game")
+        .arg(game.to_string())
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::inherit())  // Let agent logs flow to same output
         .spawn()
@@ -90,7 +105,7 @@ pub async fn spawn_standalone(port: u16, agent_config: PathBuf) -> Result<Proces
     
     info!("Standalone mode initialized successfully");
     
-    Ok(ProcessGuards::new(server, agent))
+    Ok(ProcessGuards::new(server, Some(agent)))
 }
 
 /// Polls server health endpoint until ready or timeout.
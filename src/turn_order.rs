@@ -0,0 +1,87 @@
+//! Who moves first in each game of a session.
+//!
+//! A session's [`TurnOrder`] decides which mark opens each game, and
+//! whether the players swap seats when the game is restarted, so a
+//! series of games between the same two players doesn't favour whoever
+//! joined first.
+
+use crate::games::Mark;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Rule for choosing the mark that moves first in each game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case", tag = "kind")]
+pub enum FirstMove {
+    /// The same mark opens every game.
+    Fixed {
+        /// Mark that moves first.
+        mark: Mark,
+    },
+    /// A coin flip per game, reproducible from the seed.
+    Random {
+        /// Seed for the sequence of coin flips.
+        seed: u64,
+    },
+    /// The players take turns opening: X opens the first game, O the
+    /// second, and so on, unless seats swap between games (as they do in
+    /// a match), in which case X opens every game.
+    Alternate,
+}
+
+impl FirstMove {
+    /// Returns the mark that moves first in the given game (0 for the first).
+    pub fn mark_for_game(&self, game_number: u32) -> Mark {
+        match self {
+            FirstMove::Fixed { mark } => *mark,
+            FirstMove::Random { seed } => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+                for _ in 0..game_number {
+                    rng.random::<bool>();
+                }
+                if rng.random() { Mark::X } else { Mark::O }
+            }
+            FirstMove::Alternate if game_number % 2 == 0 => Mark::X,
+            FirstMove::Alternate => Mark::O,
+        }
+    }
+}
+
+impl Default for FirstMove {
+    fn default() -> Self {
+        FirstMove::Fixed { mark: Mark::X }
+    }
+}
+
+impl std::fmt::Display for FirstMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FirstMove::Fixed { mark } => write!(f, "{:?} moves first", mark),
+            FirstMove::Random { seed } => write!(f, "random first move (seed {})", seed),
+            FirstMove::Alternate => write!(f, "alternating first move"),
+        }
+    }
+}
+
+/// Turn order for the games of a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TurnOrder {
+    /// Which mark opens each game.
+    #[serde(default)]
+    pub first_move: FirstMove,
+    /// Whether the players trade marks each time the game is restarted.
+    #[serde(default)]
+    pub swap_seats: bool,
+}
+
+impl std::fmt::Display for TurnOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.first_move)?;
+        if self.swap_seats {
+            write!(f, ", seats swap between games")?;
+        }
+        Ok(())
+    }
+}
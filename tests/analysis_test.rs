@@ -0,0 +1,150 @@
+//! Tests for position analysis and move annotation.
+
+mod common;
+
+use common::tictactoe_after;
+use strictly_games::{
+    ChoiceAnalysis, Game, GameSession, GameState, MoveQuality, PlayerType, Position,
+    SessionManager, TicTacToe, TicTacToePlayer as Player, Verdict, WIN_SCORE, analysis,
+    annotation,
+};
+
+#[test]
+fn test_empty_board_is_all_draws() {
+    let game = TicTacToe::new_game(Player::X);
+    let result = analysis::analyze::<TicTacToe>(&game);
+
+    assert_eq!(result.len(), 9);
+    for choice in &result {
+        assert_eq!(choice.verdict, Verdict::Draw, "{}", choice);
+        assert_eq!(choice.distance, None);
+        assert!(choice.optimal);
+    }
+}
+
+#[test]
+fn test_immediate_win_and_losing_moves() {
+    // X: TopLeft, TopCenter; O: MiddleLeft, Center — X to move wins at TopRight
+    let game = tictactoe_after(&[
+        Position::TopLeft,
+        Position::MiddleLeft,
+        Position::TopCenter,
+        Position::Center,
+    ]);
+    let result = analysis::analyze::<TicTacToe>(&game);
+
+    let win = result.iter().find(|c| c.choice == Position::TopRight).expect("Legal");
+    assert_eq!(win.verdict, Verdict::Win);
+    assert_eq!(win.distance, Some(1));
+    assert!(win.optimal);
+    assert_eq!(win.to_string(), "Top-right: win in 1 (optimal)");
+
+    // Ignoring O's threat lets O complete the middle row on the next ply
+    let blunder = result.iter().find(|c| c.choice == Position::BottomLeft).expect("Legal");
+    assert_eq!(blunder.verdict, Verdict::Loss);
+    assert_eq!(blunder.distance, Some(2));
+    assert!(!blunder.optimal);
+}
+
+#[test]
+fn test_session_analysis_requires_game_in_progress() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("hints".to_string());
+    session
+        .register_player("x".to_string(), "X".to_string(), PlayerType::Human)
+        .expect("X joins");
+    assert_eq!(session.analyze().expect("In progress").len(), 9);
+
+    session.game = TicTacToe::new_game(Player::X).into();
+    for position in [
+        Position::TopLeft,
+        Position::MiddleLeft,
+        Position::TopCenter,
+        Position::Center,
+        Position::TopRight,
+    ] {
+        let mark = session.game.to_move().expect("Game running");
+        session.game = session.game.clone().apply(TicTacToe::action(mark, position)).expect("Valid");
+    }
+    assert!(session.analyze().is_err());
+}
+
+#[test]
+fn test_annotation_flags_blunder() {
+    // O ignores X's top-row threat, turning a draw into a loss
+    let moves = [
+        (Player::X, Position::TopLeft),
+        (Player::O, Position::Center),
+        (Player::X, Position::TopCenter),
+        (Player::O, Position::BottomLeft),
+        (Player::X, Position::TopRight),
+    ];
+    let record = annotation::annotate::<TicTacToe>(&moves);
+
+    assert_eq!(record.len(), 5);
+    assert_eq!(record[0].quality, MoveQuality::Best);
+    assert_eq!(record[3].quality, MoveQuality::Blunder);
+    assert_eq!(record[3].best.choice, Position::TopRight);
+    assert_eq!(record[3].played.verdict, Verdict::Loss);
+    assert_eq!(record[4].quality, MoveQuality::Best);
+    assert_eq!(record[4].played.distance, Some(1));
+}
+
+#[test]
+fn test_only_proven_results_make_blunders() {
+    let choice = |verdict, distance, score, optimal| ChoiceAnalysis {
+        choice: Position::Center,
+        verdict,
+        distance,
+        score,
+        optimal,
+    };
+    let win = choice(Verdict::Win, Some(1), WIN_SCORE, true);
+
+    // Beyond a search horizon the move may still win
+    let unknown = choice(Verdict::Unknown, None, 0, false);
+    assert_eq!(MoveQuality::of(&unknown, &win), MoveQuality::Inaccuracy);
+
+    let draw = choice(Verdict::Draw, None, 0, false);
+    assert_eq!(MoveQuality::of(&draw, &win), MoveQuality::Blunder);
+    let slower = choice(Verdict::Win, Some(3), WIN_SCORE - 2, false);
+    assert_eq!(MoveQuality::of(&slower, &win), MoveQuality::Inaccuracy);
+    assert_eq!(MoveQuality::of(&win, &win), MoveQuality::Best);
+}
+
+#[tokio::test]
+async fn test_finished_session_is_annotated_on_request() {
+    let sessions: SessionManager<TicTacToe> = SessionManager::new();
+    sessions.create_session("record".to_string()).expect("Session created");
+    sessions
+        .register_player_atomic("record", "x".to_string(), "X".to_string(), PlayerType::Human)
+        .expect("X joins");
+    sessions
+        .register_player_atomic("record", "o".to_string(), "O".to_string(), PlayerType::Human)
+        .expect("O joins");
+
+    for (player, position) in [
+        ("x", Position::TopLeft),
+        ("o", Position::MiddleLeft),
+        ("x", Position::TopCenter),
+        ("o", Position::Center),
+    ] {
+        sessions.make_move_atomic("record", player, position).expect("Valid move");
+        assert!(sessions.annotations("record").await.expect("Session exists").is_empty());
+    }
+    sessions.make_move_atomic("record", "x", Position::TopRight).expect("Winning move");
+
+    let annotations = sessions.annotations("record").await.expect("Session exists");
+    assert_eq!(annotations.len(), 5);
+    // Only Center holds the draw against a corner opening
+    assert_eq!(annotations[1].quality, MoveQuality::Blunder);
+    // Already lost; blocking at Top-right would only have delayed it
+    assert_eq!(annotations[3].quality, MoveQuality::Inaccuracy);
+    // Asking again serves the same record
+    assert_eq!(sessions.annotations("record").await.expect("Session exists"), annotations);
+    let session = sessions.get_session("record").expect("Session exists");
+    assert_eq!(session.annotate(), annotations);
+
+    sessions.restart_game("record").expect("Restarted");
+    assert!(sessions.annotations("record").await.expect("Session exists").is_empty());
+    assert!(sessions.annotations("missing").await.is_err());
+}
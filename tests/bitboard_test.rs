@@ -0,0 +1,71 @@
+//! Tests for the bitboard-backed boards: serde compatibility and win masks.
+
+use strictly_games::connect_four::{self, Column};
+use strictly_games::{Board, Position, Square, TicTacToePlayer as Player};
+
+#[test]
+fn test_tictactoe_board_serializes_as_squares() {
+    let mut board = Board::new();
+    board.set(Position::Center, Square::Occupied(Player::X));
+    board.set(Position::BottomRight, Square::Occupied(Player::O));
+
+    let json = serde_json::to_value(&board).expect("Serializes");
+    let squares = json["squares"].as_array().expect("Squares array");
+    assert_eq!(squares.len(), 9);
+    assert_eq!(squares[0], serde_json::json!("Empty"));
+    assert_eq!(squares[4], serde_json::json!({"Occupied": "X"}));
+    assert_eq!(squares[8], serde_json::json!({"Occupied": "O"}));
+
+    let round_trip: Board = serde_json::from_value(json).expect("Deserializes");
+    assert_eq!(round_trip, board);
+    let squares: &[Square; 9] = round_trip.squares();
+    assert_eq!(squares[4], Square::Occupied(Player::X));
+}
+
+#[test]
+fn test_tictactoe_set_overwrites_square() {
+    let mut board = Board::new();
+    board.set(Position::TopLeft, Square::Occupied(Player::X));
+    board.set(Position::TopLeft, Square::Occupied(Player::O));
+    assert_eq!(board.get(Position::TopLeft), Square::Occupied(Player::O));
+    assert_eq!(board.mask(Player::X), 0);
+
+    board.set(Position::TopLeft, Square::Empty);
+    assert!(board.is_empty(Position::TopLeft));
+    assert_eq!(board, Board::new());
+}
+
+#[test]
+fn test_connect_four_board_serializes_as_rows() {
+    let mut board = connect_four::Board::new();
+    board.drop_piece(Column::Four, Player::X);
+    board.drop_piece(Column::Four, Player::O);
+
+    let json = serde_json::to_value(&board).expect("Serializes");
+    let rows = json["rows"].as_array().expect("Rows array");
+    assert_eq!(rows.len(), connect_four::Board::ROWS);
+    assert_eq!(rows[0][3], serde_json::json!({"Occupied": "X"}));
+    assert_eq!(rows[1][3], serde_json::json!({"Occupied": "O"}));
+    assert_eq!(rows[2][3], serde_json::json!("Empty"));
+
+    let round_trip: connect_four::Board = serde_json::from_value(json).expect("Deserializes");
+    assert_eq!(round_trip, board);
+    assert_eq!(round_trip.drop_row(Column::Four), Some(2));
+}
+
+#[test]
+fn test_connect_four_lines_do_not_wrap_between_columns() {
+    let mut board = connect_four::Board::new();
+    // Top two cells of column one, bottom two of column two: adjacent bits, no line
+    for _ in 0..4 {
+        board.drop_piece(Column::One, Player::O);
+    }
+    board.drop_piece(Column::One, Player::X);
+    board.drop_piece(Column::One, Player::X);
+    board.drop_piece(Column::Two, Player::X);
+    board.drop_piece(Column::Two, Player::X);
+
+    assert_eq!(connect_four::rules::check_winner(&board), Some(Player::O));
+    assert_eq!(connect_four::rules::check_winner_at(&board, 5, Column::One), None);
+    assert_eq!(connect_four::rules::check_winner_at(&board, 0, Column::Two), None);
+}
@@ -0,0 +1,95 @@
+//! Tests for the built-in negamax bot.
+
+mod common;
+
+use common::tictactoe_after;
+use strictly_games::connect_four::{Column, Move as DropMove};
+use strictly_games::{
+    ConnectFour, Game, GameSession, GameState, PlayerType, Position, Solver, TicTacToe,
+    TicTacToePlayer as Player, Transition, WIN_SCORE,
+};
+
+#[test]
+fn test_solver_takes_immediate_win() {
+    // X: TopLeft, TopCenter; O: MiddleLeft, Center — X to move wins at TopRight
+    let game = tictactoe_after(&[
+        Position::TopLeft,
+        Position::MiddleLeft,
+        Position::TopCenter,
+        Position::Center,
+    ]);
+
+    let solver = Solver::perfect();
+    assert_eq!(solver.best_choice::<TicTacToe>(&game), Some(Position::TopRight));
+    assert_eq!(solver.evaluate::<TicTacToe>(&game), WIN_SCORE);
+}
+
+#[test]
+fn test_solver_blocks_loss() {
+    // X threatens the top row; O must block at TopRight
+    let game = tictactoe_after(&[Position::TopLeft, Position::Center, Position::TopCenter]);
+
+    let solver = Solver::perfect();
+    assert_eq!(solver.best_choice::<TicTacToe>(&game), Some(Position::TopRight));
+}
+
+#[test]
+fn test_empty_board_is_a_draw_under_perfect_play() {
+    let game = TicTacToe::new_game(Player::X);
+    assert_eq!(Solver::perfect().evaluate::<TicTacToe>(&game), 0);
+}
+
+#[test]
+fn test_bot_vs_bot_draws() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("bots".to_string());
+    session
+        .register_player("bot_x".to_string(), "Bot X".to_string(), PlayerType::Bot)
+        .expect("X joins");
+    // A bot holding X opens as soon as it is asked to play
+    session.play_bot_turns().expect("X opens");
+    assert_eq!(session.game.move_count(), 1);
+
+    session
+        .register_player("bot_o".to_string(), "Bot O".to_string(), PlayerType::Bot)
+        .expect("O joins");
+    session.play_bot_turns().expect("Bots play out the game");
+
+    assert!(session.game.is_over());
+    assert_eq!(session.game.winner(), None);
+    assert_eq!(session.game.move_count(), 9);
+}
+
+#[test]
+fn test_bot_replies_to_human_move() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("vs_bot".to_string());
+    session
+        .register_player("human".to_string(), "Human".to_string(), PlayerType::Human)
+        .expect("X joins");
+    session
+        .register_player("bot".to_string(), "Bot".to_string(), PlayerType::Bot)
+        .expect("O joins");
+
+    session.make_move("human", Position::TopLeft).expect("Valid move");
+    assert_eq!(session.game.move_count(), 1);
+    session.play_bot_turns().expect("Bot replies");
+
+    // The bot answered immediately and it is the human's turn again
+    assert_eq!(session.game.move_count(), 2);
+    assert_eq!(GameState::to_move(&session.game), Some(Player::X));
+    assert!(session.is_players_turn("human"));
+}
+
+#[test]
+fn test_connect_four_bot_takes_vertical_win() {
+    let mut game = ConnectFour::new_game(Player::X);
+    for column in [Column::One, Column::Two, Column::One, Column::Two, Column::One, Column::Two] {
+        let action = DropMove::new(ConnectFour::to_move(&game), column);
+        game = match ConnectFour::make_move(game, action).expect("Valid move") {
+            Transition::InProgress(g) => g,
+            Transition::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+
+    let solver = Solver::for_game::<ConnectFour>();
+    assert_eq!(solver.best_choice::<ConnectFour>(&game), Some(Column::One));
+}
@@ -0,0 +1,188 @@
+//! Tests for game clocks and timeout losses.
+
+mod common;
+
+use common::{SessionOptions, seated_session};
+use std::sync::Arc;
+use std::time::Duration;
+use strictly_games::{
+    Difficulty, GameClock, GameState, ManualClock, Outcome, PlayerType, Position, SessionManager,
+    TicTacToe, TicTacToePlayer as Player, TimeControl,
+};
+
+const TEN_SECONDS_PER_MOVE: TimeControl = TimeControl::PerMove { secs: 10 };
+
+#[test]
+fn test_increment_charges_turn_and_adds_bonus() {
+    let source = ManualClock::new();
+    let control = TimeControl::Increment {
+        initial_secs: 60,
+        increment_secs: 2,
+    };
+    let mut clock = GameClock::new(control, Arc::new(source.clone()));
+
+    clock.start(Player::X);
+    source.advance(Duration::from_secs(5));
+    assert_eq!(clock.remaining(Player::X), Some(Duration::from_secs(55)));
+
+    clock.press(Player::X);
+    assert_eq!(clock.remaining(Player::X), Some(Duration::from_secs(57)));
+    assert_eq!(clock.running(), Some(Player::O));
+    assert_eq!(clock.summary().as_deref(), Some("X 0:57 | O 1:00"));
+}
+
+#[test]
+fn test_per_move_limit_renews_each_turn() {
+    let source = ManualClock::new();
+    let mut clock = GameClock::new(TEN_SECONDS_PER_MOVE, Arc::new(source.clone()));
+
+    clock.start(Player::X);
+    source.advance(Duration::from_secs(9));
+    clock.press(Player::X);
+    assert_eq!(clock.remaining(Player::X), Some(Duration::from_secs(10)));
+
+    source.advance(Duration::from_secs(10));
+    assert_eq!(clock.flagged(), Some(Player::O));
+}
+
+#[test]
+fn test_unlimited_never_flags() {
+    let source = ManualClock::new();
+    let mut clock = GameClock::new(TimeControl::Unlimited, Arc::new(source.clone()));
+
+    clock.start(Player::X);
+    source.advance(Duration::from_secs(86_400));
+    assert_eq!(clock.remaining(Player::X), None);
+    assert_eq!(clock.flagged(), None);
+}
+
+#[test]
+fn test_late_move_loses_on_time() {
+    let (sessions, clock) = seated_session::<TicTacToe>(
+        "timed",
+        SessionOptions { time_control: TEN_SECONDS_PER_MOVE, ..Default::default() },
+    );
+    sessions
+        .make_move_atomic("timed", "alice", Position::Center)
+        .expect("X moves in time");
+
+    clock.advance(Duration::from_secs(11));
+    let err = sessions
+        .make_move_atomic("timed", "bob", Position::TopLeft)
+        .expect_err("O is out of time");
+    assert!(err.contains("ran out of time"));
+
+    let session = sessions.get_session("timed").expect("Session exists");
+    assert_eq!(session.game.winner(), Some(Player::X));
+    assert!(session.game.status_string().contains("Player O ran out of time"));
+    assert_eq!(session.clock.running(), None);
+}
+
+#[test]
+fn test_hung_player_is_flagged_without_moving() {
+    let (sessions, clock) = seated_session::<TicTacToe>(
+        "timed",
+        SessionOptions { time_control: TEN_SECONDS_PER_MOVE, ..Default::default() },
+    );
+
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(sessions.check_clock_atomic("timed"), Ok(None));
+
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(sessions.check_clock_atomic("timed"), Ok(Some(Player::X)));
+    let session = sessions.get_session("timed").expect("Session exists");
+    assert!(session.game.is_over());
+    assert_eq!(session.game.winner(), Some(Player::O));
+}
+
+#[test]
+fn test_clock_waits_for_both_players() {
+    let clock = ManualClock::new();
+    let sessions: SessionManager<TicTacToe> = SessionManager::with_clock(Arc::new(clock.clone()));
+    sessions
+        .create_timed_session("lonely".to_string(), TEN_SECONDS_PER_MOVE)
+        .expect("Session created");
+    sessions
+        .register_player_atomic("lonely", "alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(sessions.check_clock_atomic("lonely"), Ok(None));
+
+    sessions
+        .register_player_atomic("lonely", "bob".to_string(), "Bob".to_string(), PlayerType::Human)
+        .expect("Bob joins");
+    let session = sessions.get_session("lonely").expect("Session exists");
+    assert_eq!(session.clock.running(), Some(Player::X));
+    assert_eq!(session.clock.remaining(Player::X), Some(Duration::from_secs(10)));
+}
+
+#[tokio::test]
+async fn test_bot_moves_without_using_time() {
+    let clock = ManualClock::new();
+    let sessions: SessionManager<TicTacToe> = SessionManager::with_clock(Arc::new(clock.clone()));
+    sessions
+        .create_timed_session("bot".to_string(), TEN_SECONDS_PER_MOVE)
+        .expect("Session created");
+    sessions
+        .register_player_atomic("bot", "alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    sessions
+        .register_bot_atomic("bot", "bot".to_string(), "Bot".to_string(), Difficulty::Perfect)
+        .expect("Bot joins");
+
+    clock.advance(Duration::from_secs(4));
+    sessions
+        .make_move_atomic("bot", "alice", Position::Center)
+        .expect("X moves");
+    sessions.play_bot_turns("bot").await.expect("Bot replies");
+
+    let session = sessions.get_session("bot").expect("Session exists");
+    assert_eq!(session.game.move_count(), 2);
+    assert_eq!(session.clock.running(), Some(Player::X));
+    assert_eq!(session.clock.remaining(Player::O), Some(Duration::from_secs(10)));
+}
+
+#[test]
+fn test_timeout_cannot_be_undone() {
+    let (sessions, clock) = seated_session::<TicTacToe>(
+        "timed",
+        SessionOptions { time_control: TEN_SECONDS_PER_MOVE, ..Default::default() },
+    );
+    sessions
+        .make_move_atomic("timed", "alice", Position::Center)
+        .expect("X moves in time");
+    clock.advance(Duration::from_secs(10));
+    sessions.check_clock_atomic("timed").expect("O flagged");
+
+    let mut session = sessions.get_session("timed").expect("Session exists");
+    session.request_undo("alice").expect("Alice asks");
+    assert!(session.respond_undo("bob", true).is_err());
+    assert!(session.game.is_over());
+}
+
+#[test]
+fn test_timed_out_outcome() {
+    let outcome = Outcome::TimedOut(Player::O);
+    assert_eq!(outcome.winner(), Some(Player::X));
+    assert!(outcome.is_off_board());
+    assert!(!outcome.is_draw());
+}
+
+#[test]
+fn test_time_control_wire_format() {
+    let control: TimeControl =
+        serde_json::from_str(r#"{"kind":"increment","initial_secs":300,"increment_secs":5}"#)
+            .expect("Valid time control");
+    assert_eq!(
+        control,
+        TimeControl::Increment {
+            initial_secs: 300,
+            increment_secs: 5
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&TEN_SECONDS_PER_MOVE).expect("Serializes"),
+        r#"{"kind":"per-move","secs":10}"#
+    );
+}
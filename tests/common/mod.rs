@@ -0,0 +1,74 @@
+//! Fixtures shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use strictly_games::{
+    Game, GameSession, ManualClock, MatchFormat, PlayerType, Position, SessionManager, TicTacToe,
+    TicTacToePlayer as Player, TimeControl, Transition, TurnOrder,
+};
+
+/// How [`seated_session`] sets up its session.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SessionOptions {
+    /// Time control of the session's clocks.
+    pub time_control: TimeControl,
+    /// Who opens each game, and whether seats swap between games.
+    pub turn_order: TurnOrder,
+    /// Match started before anyone is seated, if any.
+    pub format: Option<MatchFormat>,
+}
+
+/// Creates a session with alice as X and bob as O.
+///
+/// The manager's clocks read the returned manual clock.
+pub fn seated_session<G: Game>(
+    session_id: &str,
+    options: SessionOptions,
+) -> (SessionManager<G>, ManualClock) {
+    let clock = ManualClock::new();
+    let sessions = SessionManager::with_clock(Arc::new(clock.clone()));
+    sessions
+        .create_ordered_session(session_id.to_string(), options.time_control, options.turn_order)
+        .expect("Session created");
+    if let Some(format) = options.format {
+        sessions.start_match_atomic(session_id, format).expect("Match started");
+    }
+    seat_players(&sessions, session_id);
+    (sessions, clock)
+}
+
+/// Seats alice as X and bob as O in an existing session.
+pub fn seat_players<G: Game>(sessions: &SessionManager<G>, session_id: &str) {
+    sessions
+        .register_player_atomic(session_id, "alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    sessions
+        .register_player_atomic(session_id, "bob".to_string(), "Bob".to_string(), PlayerType::Human)
+        .expect("Bob joins");
+}
+
+/// Seats two humans, alice as X and bob as O, in a session without a manager.
+pub fn two_humans<G: Game>(session_id: &str) -> GameSession<G> {
+    let mut session = GameSession::new(session_id.to_string());
+    session
+        .register_player("alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    session
+        .register_player("bob".to_string(), "Bob".to_string(), PlayerType::Human)
+        .expect("Bob joins");
+    session
+}
+
+/// Plays the given positions alternately starting with X.
+pub fn tictactoe_after(positions: &[Position]) -> <TicTacToe as Game>::InProgress {
+    let mut game = TicTacToe::new_game(Player::X);
+    for &position in positions {
+        let action = TicTacToe::action(TicTacToe::to_move(&game), position);
+        game = match TicTacToe::make_move(game, action).expect("Valid move") {
+            Transition::InProgress(g) => g,
+            Transition::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+    game
+}
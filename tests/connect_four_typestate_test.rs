@@ -0,0 +1,117 @@
+//! Tests for the Connect Four typestate architecture.
+
+use strictly_games::connect_four::{
+    AnyGame, Board, Column, GameInProgress, GameResult, GameSetup, Move, MoveError, Player, Square,
+};
+use strictly_games::Outcome;
+
+/// Plays the given columns alternately starting with X, expecting no finish.
+fn play(columns: &[Column]) -> GameInProgress {
+    let mut game = GameSetup::new().start(Player::X);
+    for &column in columns {
+        let action = Move::new(game.to_move(), column);
+        game = match game.make_move(action).expect("Valid move") {
+            GameResult::InProgress(g) => g,
+            GameResult::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+    game
+}
+
+#[test]
+fn test_pieces_fall_to_lowest_row() {
+    let game = play(&[Column::Four, Column::Four]);
+
+    assert_eq!(game.board().get(0, Column::Four), Square::Occupied(Player::X));
+    assert_eq!(game.board().get(1, Column::Four), Square::Occupied(Player::O));
+    assert_eq!(game.board().get(2, Column::Four), Square::Empty);
+    assert_eq!(game.to_move(), Player::X);
+}
+
+#[test]
+fn test_full_column_rejected() {
+    let game = play(&[Column::One; Board::ROWS]);
+    assert!(!game.valid_moves().contains(&Column::One));
+
+    let action = Move::new(Player::X, Column::One);
+    let result = game.make_move(action);
+    assert!(matches!(result, Err(MoveError::ColumnFull(Column::One))));
+}
+
+#[test]
+fn test_wrong_player_rejected() {
+    let game = GameSetup::new().start(Player::X);
+
+    let action = Move::new(Player::O, Column::Four);
+    let result = game.make_move(action);
+    assert!(matches!(result, Err(MoveError::WrongPlayer(_))));
+}
+
+#[test]
+fn test_vertical_win() {
+    let game = play(&[
+        Column::One, Column::Two,
+        Column::One, Column::Two,
+        Column::One, Column::Two,
+    ]);
+
+    match game.make_move(Move::new(Player::X, Column::One)).expect("Valid move") {
+        GameResult::Finished(finished) => {
+            assert_eq!(*finished.outcome(), Outcome::Winner(Player::X));
+            assert_eq!(finished.history().len(), 7);
+        }
+        GameResult::InProgress(_) => panic!("X should have won"),
+    }
+}
+
+#[test]
+fn test_diagonal_win() {
+    // X builds a rising diagonal from column one to column four
+    let game = play(&[
+        Column::One, Column::Two,
+        Column::Two, Column::Three,
+        Column::Three, Column::Four,
+        Column::Three, Column::Four,
+        Column::Four, Column::Seven,
+    ]);
+
+    match game.make_move(Move::new(Player::X, Column::Four)).expect("Valid move") {
+        GameResult::Finished(finished) => {
+            assert_eq!(*finished.outcome(), Outcome::Winner(Player::X));
+        }
+        GameResult::InProgress(_) => panic!("X should have won"),
+    }
+}
+
+#[test]
+fn test_replay_rejects_moves_after_game_over() {
+    let mut moves: Vec<Move> = [Column::One, Column::Two]
+        .iter()
+        .cycle()
+        .take(7)
+        .zip([Player::X, Player::O].iter().cycle())
+        .map(|(&column, &player)| Move::new(player, column))
+        .collect();
+
+    let result = GameInProgress::replay(Player::X, &moves).expect("Valid replay");
+    assert!(matches!(result, GameResult::Finished(_)));
+
+    moves.push(Move::new(Player::O, Column::Two));
+    let result = GameInProgress::replay(Player::X, &moves);
+    assert!(matches!(result, Err(MoveError::GameOver)));
+}
+
+#[test]
+fn test_wrapper_round_trips_through_json() {
+    let game: AnyGame = play(&[Column::Four, Column::Three]).into();
+    let game = game
+        .make_move_action(Move::new(Player::X, Column::Four))
+        .expect("Valid move");
+
+    let json = serde_json::to_string(&game).expect("Serialize");
+    let restored: AnyGame = serde_json::from_str(&json).expect("Deserialize");
+
+    assert_eq!(restored.board(), game.board());
+    assert_eq!(restored.history().len(), 3);
+    assert_eq!(restored.to_move(), Some(Player::O));
+}
@@ -0,0 +1,99 @@
+//! Tests for the bot difficulty tiers.
+
+mod common;
+
+use common::tictactoe_after;
+use strictly_games::{
+    ConnectFour, Difficulty, Game, GameSession, GameState, Gomoku, Mnk, PlayerType, Position,
+    TicTacToe,
+};
+
+#[test]
+fn test_difficulty_parses_kebab_case() {
+    assert_eq!("lookahead".parse::<Difficulty>(), Ok(Difficulty::Lookahead));
+    assert_eq!(Difficulty::Greedy.to_string(), "greedy");
+    assert_eq!(Difficulty::default(), Difficulty::Perfect);
+}
+
+#[test]
+fn test_random_plays_legal_moves() {
+    let game = tictactoe_after(&[Position::Center]);
+    let legal = TicTacToe::legal_choices(&game);
+
+    for _ in 0..20 {
+        let choice = Difficulty::Random.choose::<TicTacToe>(&game).expect("Has a move");
+        assert!(legal.contains(&choice));
+    }
+}
+
+#[test]
+fn test_greedy_takes_win_and_blocks() {
+    // X: TopLeft, TopCenter; O: MiddleLeft, Center — X to move wins at TopRight
+    let win = tictactoe_after(&[
+        Position::TopLeft,
+        Position::MiddleLeft,
+        Position::TopCenter,
+        Position::Center,
+    ]);
+    assert_eq!(Difficulty::Greedy.choose::<TicTacToe>(&win), Some(Position::TopRight));
+
+    // X threatens the top row; O must block at TopRight
+    let block = tictactoe_after(&[Position::TopLeft, Position::Center, Position::TopCenter]);
+    assert_eq!(Difficulty::Greedy.choose::<TicTacToe>(&block), Some(Position::TopRight));
+}
+
+#[test]
+fn test_perfect_never_loses_to_random() {
+    for _ in 0..10 {
+        let mut session: GameSession<TicTacToe> = GameSession::new("ladder".to_string());
+        session
+            .register_bot("random".to_string(), "Random".to_string(), Difficulty::Random)
+            .expect("X joins");
+        let mark = session
+            .register_bot("perfect".to_string(), "Perfect".to_string(), Difficulty::Perfect)
+            .expect("O joins");
+        session.play_bot_turns().expect("Bots play out the game");
+
+        assert!(session.game.is_over());
+        assert_ne!(session.game.winner(), Some(mark.opponent()));
+    }
+}
+
+#[test]
+fn test_registered_bot_keeps_difficulty() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("tiers".to_string());
+    session
+        .register_player("human".to_string(), "Human".to_string(), PlayerType::Human)
+        .expect("Human joins");
+    session
+        .register_bot("bot".to_string(), "Bot".to_string(), Difficulty::Greedy)
+        .expect("Bot joins");
+
+    let bot = session.player_o.as_ref().expect("Bot seated as O");
+    assert_eq!(bot.player_type, PlayerType::Bot);
+    assert_eq!(bot.difficulty, Some(Difficulty::Greedy));
+    assert_eq!(session.player_x.as_ref().and_then(|p| p.difficulty), None);
+}
+
+/// Plies each tier searches in `G`, weakest first; a full search counts as deepest.
+fn tier_depths<G: Game>() -> Vec<usize> {
+    [Difficulty::Random, Difficulty::Greedy, Difficulty::Lookahead, Difficulty::Perfect]
+        .into_iter()
+        .map(|difficulty| match difficulty.solver::<G>() {
+            None => 0,
+            Some(solver) => solver.max_depth().unwrap_or(usize::MAX),
+        })
+        .collect()
+}
+
+#[test]
+fn test_tiers_search_strictly_deeper_in_every_game() {
+    for (name, depths) in [
+        ("tic-tac-toe", tier_depths::<TicTacToe>()),
+        ("connect-four", tier_depths::<ConnectFour>()),
+        ("gomoku", tier_depths::<Gomoku>()),
+        ("mnk", tier_depths::<Mnk<4, 4, 3>>()),
+    ] {
+        assert!(depths.windows(2).all(|pair| pair[0] < pair[1]), "{name}: {depths:?}");
+    }
+}
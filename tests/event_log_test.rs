@@ -0,0 +1,245 @@
+//! Tests for the session event log: folding, replay and snapshots.
+
+mod common;
+
+use common::{SessionOptions, seated_session};
+use std::sync::Arc;
+use std::time::Duration;
+use strictly_games::{
+    Difficulty, GameSession, GameState, LoggedEvent, MatchFormat, PlayerType, Position,
+    SessionEvent, SessionManager, TicTacToe, TicTacToePlayer as Player, TimeControl,
+    SNAPSHOT_EVERY,
+};
+
+const BLITZ: TimeControl = TimeControl::Increment {
+    initial_secs: 60,
+    increment_secs: 2,
+};
+
+/// Asserts that two sessions are in the same state.
+fn assert_same_state(left: &GameSession<TicTacToe>, right: &GameSession<TicTacToe>) {
+    assert_eq!(left.game.moves(), right.game.moves());
+    assert_eq!(left.game.status_string(), right.game.status_string());
+    assert_eq!(left.player_x, right.player_x);
+    assert_eq!(left.player_o, right.player_o);
+    assert_eq!(left.game_number, right.game_number);
+    assert_eq!(left.undo_request, right.undo_request);
+    assert_eq!(left.resignation, right.resignation);
+    assert_eq!(left.match_play, right.match_play);
+    for mark in [Player::X, Player::O] {
+        assert_eq!(left.clock.remaining(mark), right.clock.remaining(mark));
+    }
+}
+
+#[test]
+fn test_replay_rebuilds_live_session() {
+    let (sessions, clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    for (player, position) in [
+        ("alice", Position::Center),
+        ("bob", Position::TopLeft),
+        ("alice", Position::TopRight),
+    ] {
+        clock.advance(Duration::from_secs(5));
+        sessions.make_move_atomic("log", player, position).expect("Valid move");
+    }
+    let mut session = sessions.get_session("log").expect("Session exists");
+    session.request_undo("alice").expect("Alice asks");
+    session.respond_undo("bob", true).expect("Bob allows it");
+    clock.advance(Duration::from_secs(3));
+    session.resign("alice").expect("Alice resigns");
+
+    let replayed = GameSession::replay("log".to_string(), Arc::new(clock.clone()), session.events())
+        .expect("Log replays");
+    assert_same_state(&session, &replayed);
+    assert_eq!(replayed.events().len(), session.events().len());
+    // Two 5s turns with a 2s increment each; the turn cut short by resigning isn't charged
+    assert_eq!(replayed.clock.remaining(Player::X), Some(Duration::from_secs(54)));
+}
+
+#[test]
+fn test_log_records_domain_events_in_order() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    sessions.make_move_atomic("log", "alice", Position::Center).expect("Valid move");
+    sessions.restart_game("log").expect("Restarts");
+
+    let session = sessions.get_session("log").expect("Session exists");
+    let events: Vec<_> = session.events().iter().map(|logged| &logged.event).collect();
+    assert!(matches!(events[0], SessionEvent::Created { time_control: BLITZ, .. }));
+    assert!(matches!(events[1], SessionEvent::PlayerRegistered { player } if player.id == "alice"));
+    assert!(matches!(events[2], SessionEvent::PlayerRegistered { player } if player.mark == Player::O));
+    assert!(matches!(
+        events[3],
+        SessionEvent::MoveApplied { mark: Player::X, choice: Position::Center }
+    ));
+    assert!(matches!(events[4], SessionEvent::Restarted));
+    assert_eq!(events.len(), 5);
+
+    let seqs: Vec<_> = session.events().iter().map(|logged| logged.seq).collect();
+    assert_eq!(seqs, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_rejected_commands_are_not_logged() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    sessions.make_move_atomic("log", "alice", Position::Center).expect("Valid move");
+
+    sessions
+        .make_move_atomic("log", "bob", Position::Center)
+        .expect_err("Square is taken");
+    sessions
+        .make_move_atomic("log", "alice", Position::TopLeft)
+        .expect_err("Not alice's turn");
+
+    let session = sessions.get_session("log").expect("Session exists");
+    assert_eq!(session.events().len(), 4);
+}
+
+#[tokio::test]
+async fn test_bot_moves_are_logged() {
+    let sessions: SessionManager<TicTacToe> = SessionManager::new();
+    sessions.create_session("bot".to_string()).expect("Session created");
+    sessions
+        .register_player_atomic("bot", "alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    sessions
+        .register_bot_atomic("bot", "bot".to_string(), "Bot".to_string(), Difficulty::Perfect)
+        .expect("Bot joins");
+    sessions.make_move_atomic("bot", "alice", Position::Center).expect("Valid move");
+    sessions.play_bot_turns("bot").await.expect("Bot replies");
+
+    let session = sessions.get_session("bot").expect("Session exists");
+    let bot_moves = session
+        .events()
+        .iter()
+        .filter(|logged| matches!(logged.event, SessionEvent::MoveApplied { mark: Player::O, .. }))
+        .count();
+    assert_eq!(bot_moves, 1);
+
+    // Replaying doesn't ask the bot again: its logged move is folded in
+    let replayed = GameSession::replay("bot".to_string(), session.clock.source(), session.events())
+        .expect("Log replays");
+    assert_eq!(replayed.game.moves(), session.game.moves());
+}
+
+#[test]
+fn test_state_at_rebuilds_intermediate_states() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    for (player, position) in [
+        ("alice", Position::Center),
+        ("bob", Position::TopLeft),
+        ("alice", Position::TopRight),
+    ] {
+        sessions.make_move_atomic("log", player, position).expect("Valid move");
+    }
+
+    let session = sessions.get_session("log").expect("Session exists");
+    let after_first_move = session.state_at(3).expect("Event exists");
+    assert_eq!(after_first_move.game.moves(), vec![(Player::X, Position::Center)]);
+    assert_eq!(after_first_move.events().len(), 4);
+
+    let before_players = session.state_at(0).expect("Event exists");
+    assert!(before_players.player_x.is_none());
+
+    assert!(session.state_at(6).is_err());
+}
+
+#[test]
+fn test_state_at_starts_from_snapshots() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    sessions.start_match_atomic("log", MatchFormat::Fixed { games: 100 }).expect("Match started");
+    // Each game: X wins the top row in five moves, then a restart
+    for _ in 0..12 {
+        let session = sessions.get_session("log").expect("Session exists");
+        let x = session.player_x.expect("X seated").id;
+        let o = session.player_o.expect("O seated").id;
+        for (player, position) in [
+            (&x, Position::TopLeft),
+            (&o, Position::MiddleLeft),
+            (&x, Position::TopCenter),
+            (&o, Position::Center),
+            (&x, Position::TopRight),
+        ] {
+            sessions.make_move_atomic("log", player, position).expect("Valid move");
+        }
+        sessions.restart_game("log").expect("Next game");
+    }
+
+    let session = sessions.get_session("log").expect("Session exists");
+    let total = session.events().len() as u64;
+    assert!(total > 2 * SNAPSHOT_EVERY);
+
+    for seq in [SNAPSHOT_EVERY - 1, SNAPSHOT_EVERY + 3, total - 1] {
+        let from_snapshot = session.state_at(seq).expect("Event exists");
+        let prefix = &session.events()[..=seq as usize];
+        let replayed = GameSession::replay("log".to_string(), session.clock.source(), prefix)
+            .expect("Prefix replays");
+        assert_same_state(&from_snapshot, &replayed);
+    }
+    assert_same_state(&session.state_at(total - 1).expect("Event exists"), &session);
+}
+
+#[test]
+fn test_stale_update_is_rejected() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    let mut stale = sessions.get_session("log").expect("Session exists");
+
+    sessions.make_move_atomic("log", "alice", Position::Center).expect("Valid move");
+    stale.make_move("alice", Position::TopLeft).expect("Valid in the stale copy");
+
+    let err = sessions.update_session(stale).expect_err("Session changed since read");
+    assert!(err.contains("changed"));
+    let session = sessions.get_session("log").expect("Session exists");
+    assert_eq!(session.game.moves(), vec![(Player::X, Position::Center)]);
+}
+
+#[test]
+fn test_log_round_trips_through_json() {
+    let (sessions, clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    clock.advance(Duration::from_secs(4));
+    sessions.make_move_atomic("log", "alice", Position::Center).expect("Valid move");
+
+    let session = sessions.get_session("log").expect("Session exists");
+    let json = serde_json::to_value(session.events()).expect("Serializes");
+    assert_eq!(json[3]["type"], "move_applied");
+    assert_eq!(json[3]["mark"], "X");
+    assert_eq!(json[3]["seq"], 3);
+
+    let events: Vec<LoggedEvent<TicTacToe>> = serde_json::from_value(json).expect("Deserializes");
+    let replayed = GameSession::replay("log".to_string(), Arc::new(clock.clone()), &events)
+        .expect("Log replays");
+    assert_same_state(&session, &replayed);
+}
+
+#[test]
+fn test_replay_requires_creation_first() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "log",
+        SessionOptions { time_control: BLITZ, ..Default::default() },
+    );
+    let session = sessions.get_session("log").expect("Session exists");
+
+    let err = GameSession::replay("log".to_string(), session.clock.source(), &session.events()[1..])
+        .expect_err("Creation missing");
+    assert!(err.contains("creation"));
+}
@@ -0,0 +1,87 @@
+//! Tests for the game-agnostic `Game` trait and generic sessions.
+
+use strictly_games::{
+    ConnectFour, Game, GameKind, GameSession, GameState, Gomoku, Mnk, PlayerType, Position,
+    SessionManager, TicTacToe, TicTacToePlayer as Player, Transition,
+};
+
+#[test]
+fn test_trait_drives_typestate() {
+    let game = TicTacToe::new_game(Player::X);
+    assert_eq!(TicTacToe::to_move(&game), Player::X);
+    assert_eq!(TicTacToe::legal_choices(&game).len(), 9);
+
+    let action = TicTacToe::action(Player::X, Position::Center);
+    let game = match TicTacToe::make_move(game, action).expect("Valid move") {
+        Transition::InProgress(g) => g,
+        Transition::Finished(_) => panic!("Game shouldn't finish after one move"),
+    };
+
+    assert_eq!(TicTacToe::to_move(&game), Player::O);
+    assert!(!TicTacToe::legal_choices(&game).contains(&Position::Center));
+}
+
+#[test]
+fn test_state_wrapper_applies_actions() {
+    let state: <TicTacToe as Game>::State = TicTacToe::new_game(Player::X).into();
+    assert_eq!(state.move_count(), 0);
+
+    let state = state
+        .apply(TicTacToe::action(Player::X, Position::TopLeft))
+        .expect("Valid move");
+    assert_eq!(state.move_count(), 1);
+    assert_eq!(GameState::to_move(&state), Some(Player::O));
+
+    let result = state.apply(TicTacToe::action(Player::X, Position::Center));
+    assert!(result.is_err(), "Out-of-turn move should be rejected");
+}
+
+#[test]
+fn test_generic_session_plays_to_completion() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("generic".to_string());
+    session
+        .register_player("x".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Seat X available");
+    session
+        .register_player("o".to_string(), "Bob".to_string(), PlayerType::Agent)
+        .expect("Seat O available");
+
+    for (player_id, position) in [
+        ("x", Position::TopLeft),
+        ("o", Position::Center),
+        ("x", Position::TopCenter),
+        ("o", Position::BottomLeft),
+        ("x", Position::TopRight),
+    ] {
+        session.make_move(player_id, position).expect("Valid move");
+    }
+
+    assert!(session.game.is_over());
+    assert_eq!(GameState::winner(&session.game), Some(Player::X));
+}
+
+#[test]
+fn test_session_manager_restart() {
+    let sessions: SessionManager<TicTacToe> = SessionManager::new();
+    sessions.create_session("restart".to_string()).expect("New session");
+
+    let mut session = sessions.get_session("restart").expect("Session exists");
+    session
+        .register_player("x".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Seat X available");
+    session.make_move("x", Position::Center).expect("Valid move");
+    sessions.update_session(session).expect("Session unchanged since read");
+
+    sessions.restart_game("restart").expect("Restart succeeds");
+    let session = sessions.get_session("restart").expect("Session exists");
+    assert_eq!(session.game.move_count(), 0);
+    assert!(session.player_x.is_some(), "Players survive restart");
+}
+
+#[test]
+fn test_game_names_match_game_kinds() {
+    assert_eq!(TicTacToe::NAME, GameKind::TicTacToe.to_string());
+    assert_eq!(ConnectFour::NAME, GameKind::ConnectFour.to_string());
+    assert_eq!(Gomoku::NAME, GameKind::Gomoku.to_string());
+    assert_eq!(Mnk::<4, 4, 3>::NAME, "mnk");
+}
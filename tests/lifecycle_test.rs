@@ -0,0 +1,152 @@
+//! Tests for session lifecycle states, idle expiry and eviction.
+
+mod common;
+
+use common::seat_players;
+use std::sync::Arc;
+use std::time::Duration;
+use strictly_games::{
+    Expiry, GameState, ManualClock, MemoryStore, Position, SessionManager,
+    SessionStatus, SessionStore, TicTacToe, TicTacToePlayer as Player, TimeControl,
+};
+
+const EXPIRY: Expiry = Expiry {
+    idle_timeout: Duration::from_secs(60),
+    retention: Duration::from_secs(30),
+};
+
+/// Creates a session manager driven by a manual clock, over a shared store.
+fn manager() -> (SessionManager<TicTacToe>, ManualClock, Arc<MemoryStore<TicTacToe>>) {
+    let clock = ManualClock::new();
+    let store = Arc::new(MemoryStore::new());
+    let sessions =
+        SessionManager::with_store(Arc::new(clock.clone()), store.clone()).expect("Empty store");
+    (sessions, clock, store)
+}
+
+/// Returns the session's lifecycle state.
+fn status(sessions: &SessionManager<TicTacToe>, session_id: &str) -> SessionStatus {
+    sessions.get_session(session_id).expect("Session exists").status
+}
+
+#[test]
+fn test_status_follows_seats_and_game() {
+    let (sessions, _clock, _store) = manager();
+    sessions.create_session("life".to_string()).expect("Session created");
+    assert_eq!(status(&sessions, "life"), SessionStatus::WaitingForPlayers);
+
+    seat_players(&sessions, "life");
+    assert_eq!(status(&sessions, "life"), SessionStatus::Active);
+
+    let mut session = sessions.get_session("life").expect("Session exists");
+    session.resign("alice").expect("Alice resigns");
+    sessions.update_session(session).expect("Session unchanged since read");
+    assert_eq!(status(&sessions, "life"), SessionStatus::Finished);
+
+    sessions.restart_game("life").expect("Restarts");
+    assert_eq!(status(&sessions, "life"), SessionStatus::Active);
+}
+
+#[test]
+fn test_idle_session_is_abandoned_then_evicted() {
+    let (sessions, clock, store) = manager();
+    sessions.create_session("idle".to_string()).expect("Session created");
+    seat_players(&sessions, "idle");
+
+    clock.advance(Duration::from_secs(59));
+    assert!(sessions.reap(&EXPIRY).is_empty());
+    assert_eq!(status(&sessions, "idle"), SessionStatus::Active);
+
+    clock.advance(Duration::from_secs(1));
+    assert!(sessions.reap(&EXPIRY).is_empty());
+    let session = sessions.get_session("idle").expect("Still listed");
+    assert_eq!(session.status, SessionStatus::Abandoned);
+    assert!(session.closed);
+
+    // Retention counts from the abandonment
+    clock.advance(Duration::from_secs(30));
+    assert_eq!(sessions.reap(&EXPIRY), vec!["idle".to_string()]);
+    assert!(sessions.get_session("idle").is_none());
+    assert!(store.load().expect("Store loads").is_empty());
+}
+
+#[test]
+fn test_idle_finished_session_closes_as_finished() {
+    let (sessions, clock, _store) = manager();
+    sessions.create_session("done".to_string()).expect("Session created");
+    seat_players(&sessions, "done");
+    let mut session = sessions.get_session("done").expect("Session exists");
+    session.offer_draw("alice").expect("Alice offers");
+    session.respond_draw("bob", true).expect("Bob accepts");
+    sessions.update_session(session).expect("Session unchanged since read");
+
+    clock.advance(Duration::from_secs(60));
+    sessions.reap(&EXPIRY);
+    let session = sessions.get_session("done").expect("Still listed");
+    assert_eq!(session.status, SessionStatus::Finished);
+    assert!(session.closed);
+}
+
+#[test]
+fn test_flagged_player_loses_before_expiry() {
+    let (sessions, clock, _store) = manager();
+    sessions
+        .create_timed_session("timed".to_string(), TimeControl::PerMove { secs: 10 })
+        .expect("Session created");
+    seat_players(&sessions, "timed");
+
+    clock.advance(Duration::from_secs(60));
+    sessions.reap(&EXPIRY);
+    let session = sessions.get_session("timed").expect("Still listed");
+    assert_eq!(session.game.winner(), Some(Player::O));
+    let last = session.events().last().map(|logged| logged.event.to_string());
+    assert_eq!(last, Some("Session closed".to_string()));
+    assert_eq!(session.status, SessionStatus::Finished);
+}
+
+#[test]
+fn test_closed_session_refuses_play() {
+    let (sessions, _clock, _store) = manager();
+    sessions.create_session("shut".to_string()).expect("Session created");
+    seat_players(&sessions, "shut");
+    sessions.close_session("shut").expect("Closes");
+
+    let err = sessions
+        .make_move_atomic("shut", "alice", Position::Center)
+        .expect_err("Closed");
+    assert!(err.contains("closed"));
+    assert!(sessions.restart_game("shut").is_err());
+    assert!(sessions.close_session("shut").is_err());
+
+    let session = sessions.get_session("shut").expect("Still listed");
+    assert_eq!(session.status, SessionStatus::Finished);
+    assert_eq!(session.game.move_count(), 0);
+}
+
+#[test]
+fn test_delete_session_removes_it_and_its_log() {
+    let (sessions, _clock, store) = manager();
+    sessions.create_session("gone".to_string()).expect("Session created");
+    sessions.create_session("kept".to_string()).expect("Session created");
+
+    sessions.delete_session("gone").expect("Deletes");
+    assert!(sessions.get_session("gone").is_none());
+    assert!(sessions.delete_session("gone").is_err());
+
+    let stored: Vec<_> = store.load().expect("Store loads").into_iter().map(|(id, _)| id).collect();
+    assert_eq!(stored, vec!["kept".to_string()]);
+}
+
+#[test]
+fn test_lifecycle_survives_reload() {
+    let (sessions, clock, store) = manager();
+    sessions.create_session("reload".to_string()).expect("Session created");
+    clock.advance(Duration::from_secs(60));
+    sessions.reap(&EXPIRY);
+
+    let restarted =
+        SessionManager::with_store(Arc::new(clock.clone()), store).expect("Store loads");
+    let session = restarted.get_session("reload").expect("Session restored");
+    assert_eq!(session.status, SessionStatus::Abandoned);
+    assert!(session.closed);
+}
@@ -0,0 +1,193 @@
+//! Tests for the matchmaking lobby: pairing seekers into fresh sessions.
+
+use std::sync::Arc;
+use std::time::Duration;
+use strictly_games::{
+    Difficulty, GameKind, GameSessions, ManualClock, PlayerType, SEEKER_TIMEOUT, SeekConstraints,
+    Seeker, SessionStatus,
+};
+
+/// Returns an agent seeker with the given constraints.
+fn agent(name: &str, constraints: SeekConstraints) -> Seeker {
+    Seeker {
+        name: name.to_string(),
+        player_type: PlayerType::Agent,
+        rating: None,
+        constraints,
+    }
+}
+
+#[test]
+fn test_seekers_agree_on_game_and_opponent() {
+    let anything = agent("Alice", SeekConstraints::default());
+    let gomoku = agent("Bob", SeekConstraints { game: Some(GameKind::Gomoku), ..Default::default() });
+    let connect_four =
+        agent("Carol", SeekConstraints { game: Some(GameKind::ConnectFour), ..Default::default() });
+    let humans_only =
+        agent("Dave", SeekConstraints { opponent_type: Some(PlayerType::Human), ..Default::default() });
+
+    assert_eq!(anything.pairs_with(&anything.clone()), Some(GameKind::TicTacToe));
+    assert_eq!(anything.pairs_with(&gomoku), Some(GameKind::Gomoku));
+    assert_eq!(gomoku.pairs_with(&connect_four), None);
+    // Both sides must accept each other
+    assert_eq!(humans_only.pairs_with(&anything), None);
+    assert_eq!(anything.pairs_with(&humans_only), None);
+}
+
+#[test]
+fn test_rating_range_needs_a_declared_rating() {
+    let picky = agent(
+        "Alice",
+        SeekConstraints { min_rating: Some(1200), max_rating: Some(1600), ..Default::default() },
+    );
+    let mut other = agent("Bob", SeekConstraints::default());
+
+    assert_eq!(picky.pairs_with(&other), None);
+    other.rating = Some(1500);
+    assert_eq!(picky.pairs_with(&other), Some(GameKind::TicTacToe));
+    other.rating = Some(1700);
+    assert_eq!(picky.pairs_with(&other), None);
+}
+
+#[test]
+fn test_two_seekers_are_seated_in_a_fresh_session() {
+    let sessions = GameSessions::new();
+    let (alice_ticket, pairing) = sessions
+        .join_queue(agent("Alice", SeekConstraints { game: Some(GameKind::ConnectFour), ..Default::default() }))
+        .expect("Alice queues");
+    assert!(pairing.is_none());
+    assert_eq!(sessions.lobby.waiting(), 1);
+
+    let (_, bob) = sessions
+        .join_queue(agent("Bob", SeekConstraints::default()))
+        .expect("Bob queues");
+    let bob = bob.expect("Bob is paired at once");
+    let alice = sessions
+        .lobby
+        .pairing(&alice_ticket)
+        .expect("Ticket known")
+        .expect("Alice is paired too");
+    assert_eq!(sessions.lobby.waiting(), 0);
+
+    assert_eq!(alice.session_id, bob.session_id);
+    assert_eq!(alice.game, GameKind::ConnectFour);
+    assert_eq!(sessions.kind_of(&alice.session_id), Some(GameKind::ConnectFour));
+    assert_ne!(alice.mark, bob.mark);
+    assert_eq!(alice.opponent, "Bob");
+    assert_eq!(bob.opponent, "Alice");
+
+    let session = sessions.connect_four.get_session(&bob.session_id).expect("Session exists");
+    assert_eq!(session.status, SessionStatus::Active);
+    session.authorize(&alice.player_id, Some(alice.token.as_str())).expect("Alice's seat");
+    session.authorize(&bob.player_id, Some(bob.token.as_str())).expect("Bob's seat");
+}
+
+#[test]
+fn test_incompatible_seekers_keep_waiting_until_they_leave() {
+    let sessions = GameSessions::new();
+    let (gomoku, _) = sessions
+        .join_queue(agent("Alice", SeekConstraints { game: Some(GameKind::Gomoku), ..Default::default() }))
+        .expect("Alice queues");
+    let (tictactoe, pairing) = sessions
+        .join_queue(agent("Bob", SeekConstraints { game: Some(GameKind::TicTacToe), ..Default::default() }))
+        .expect("Bob queues");
+    assert!(pairing.is_none());
+    assert_eq!(sessions.lobby.waiting(), 2);
+
+    sessions.lobby.leave(&gomoku).expect("Alice leaves");
+    assert!(sessions.lobby.leave(&gomoku).is_err());
+    assert!(sessions.lobby.pairing(&gomoku).is_err());
+    assert!(matches!(sessions.lobby.pairing(&tictactoe), Ok(None)));
+}
+
+#[test]
+fn test_same_names_get_distinct_seats() {
+    let sessions = GameSessions::new();
+    sessions.join_queue(agent("Agent", SeekConstraints::default())).expect("First queues");
+    let (_, second) = sessions.join_queue(agent("Agent", SeekConstraints::default())).expect("Second queues");
+    let second = second.expect("Paired");
+
+    let session = sessions.tictactoe.get_session(&second.session_id).expect("Session exists");
+    assert_eq!(session.status, SessionStatus::Active);
+    assert!(second.player_id.ends_with("_2"));
+    assert!(session.get_player(&second.player_id).is_some());
+}
+
+#[test]
+fn test_bot_opponent_is_seated_at_once() {
+    let sessions = GameSessions::new();
+    let (_, pairing) = sessions
+        .join_queue(agent(
+            "Alice",
+            SeekConstraints {
+                game: Some(GameKind::Gomoku),
+                opponent_type: Some(PlayerType::Bot),
+                bot_difficulty: Some(Difficulty::Random),
+                ..Default::default()
+            },
+        ))
+        .expect("Alice queues");
+    let pairing = pairing.expect("Paired with a bot");
+
+    assert_eq!(pairing.opponent, "Bot (random)");
+    assert_eq!(sessions.lobby.waiting(), 0);
+    let session = sessions.gomoku.get_session(&pairing.session_id).expect("Session exists");
+    assert_eq!(session.status, SessionStatus::Active);
+
+    let bot = Seeker { player_type: PlayerType::Bot, ..agent("Bot", SeekConstraints::default()) };
+    assert!(sessions.join_queue(bot).is_err());
+}
+
+#[tokio::test]
+async fn test_waiting_ticket_wakes_when_paired() {
+    let sessions = GameSessions::new();
+    let (ticket, _) = sessions.join_queue(agent("Alice", SeekConstraints::default())).expect("Alice queues");
+
+    let lobby = sessions.lobby.clone();
+    let waiter = tokio::spawn(async move { lobby.wait(&ticket, Duration::from_secs(10)).await });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    sessions.join_queue(agent("Bob", SeekConstraints::default())).expect("Bob queues");
+
+    let pairing = waiter.await.expect("Waiter finishes").expect("Ticket known").expect("Paired");
+    assert_eq!(pairing.opponent, "Bob");
+}
+
+#[tokio::test]
+async fn test_waiting_ticket_times_out_unpaired() {
+    let sessions = GameSessions::new();
+    let (ticket, _) = sessions.join_queue(agent("Alice", SeekConstraints::default())).expect("Alice queues");
+
+    let pairing = sessions.lobby.wait(&ticket, Duration::from_millis(20)).await.expect("Ticket known");
+    assert!(pairing.is_none());
+    assert!(sessions.lobby.wait("guess", Duration::from_millis(20)).await.is_err());
+}
+
+#[test]
+fn test_stale_seekers_are_dropped_before_pairing() {
+    let clock = ManualClock::new();
+    let sessions = GameSessions::with_clock(Arc::new(clock.clone()));
+    let (gone, _) = sessions.join_queue(agent("Alice", SeekConstraints::default())).expect("Alice queues");
+
+    // Alice never checks the ticket again
+    clock.advance(SEEKER_TIMEOUT);
+    let (bob, pairing) = sessions.join_queue(agent("Bob", SeekConstraints::default())).expect("Bob queues");
+    assert!(pairing.is_none());
+    assert_eq!(sessions.lobby.waiting(), 1);
+    assert!(sessions.lobby.pairing(&gone).is_err());
+    assert!(matches!(sessions.lobby.pairing(&bob), Ok(None)));
+}
+
+#[tokio::test]
+async fn test_checking_a_ticket_keeps_it_queued() {
+    let clock = ManualClock::new();
+    let sessions = GameSessions::with_clock(Arc::new(clock.clone()));
+    let (ticket, _) = sessions.join_queue(agent("Alice", SeekConstraints::default())).expect("Alice queues");
+
+    clock.advance(SEEKER_TIMEOUT - Duration::from_secs(1));
+    let pairing = sessions.lobby.wait(&ticket, Duration::from_millis(10)).await.expect("Ticket known");
+    assert!(pairing.is_none());
+
+    clock.advance(Duration::from_secs(2));
+    let (_, bob) = sessions.join_queue(agent("Bob", SeekConstraints::default())).expect("Bob queues");
+    assert_eq!(bob.expect("Alice is still there").opponent, "Alice");
+}
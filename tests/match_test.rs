@@ -0,0 +1,218 @@
+//! Tests for matches: series of games with a running score.
+
+mod common;
+
+use common::{SessionOptions, seated_session};
+use strictly_games::{
+    FirstMove, Match, MatchFormat, MatchResult, Position, SessionManager, TicTacToe,
+    TicTacToePlayer as Mark, TurnOrder,
+};
+
+/// Returns the IDs of the players seated as X and O.
+fn seats(sessions: &SessionManager<TicTacToe>) -> (String, String) {
+    let session = sessions.get_session("match").expect("Session exists");
+    (
+        session.player_x.expect("X seated").id,
+        session.player_o.expect("O seated").id,
+    )
+}
+
+/// Plays a game that X wins along the top row.
+fn x_wins(sessions: &SessionManager<TicTacToe>) {
+    let (x, o) = seats(sessions);
+    for (player, position) in [
+        (&x, Position::TopLeft),
+        (&o, Position::MiddleLeft),
+        (&x, Position::TopCenter),
+        (&o, Position::Center),
+        (&x, Position::TopRight),
+    ] {
+        sessions.make_move_atomic("match", player, position).expect("Valid move");
+    }
+}
+
+/// Plays a game to a draw.
+fn draw(sessions: &SessionManager<TicTacToe>) {
+    let (x, o) = seats(sessions);
+    for (player, position) in [
+        (&x, Position::TopLeft),
+        (&o, Position::Center),
+        (&x, Position::BottomRight),
+        (&o, Position::TopCenter),
+        (&x, Position::BottomCenter),
+        (&o, Position::BottomLeft),
+        (&x, Position::TopRight),
+        (&o, Position::MiddleRight),
+        (&x, Position::MiddleLeft),
+    ] {
+        sessions.make_move_atomic("match", player, position).expect("Valid move");
+    }
+}
+
+/// Returns the ID of the player to move.
+fn player_to_move(sessions: &SessionManager<TicTacToe>) -> String {
+    let session = sessions.get_session("match").expect("Session exists");
+    let mark = session.game.to_move().expect("Game in progress");
+    session.player_with_mark(mark).expect("Seat filled").id.clone()
+}
+
+/// Returns the session's match.
+fn match_play(sessions: &SessionManager<TicTacToe>) -> Match {
+    sessions
+        .get_session("match")
+        .expect("Session exists")
+        .match_play
+        .expect("Match in progress")
+}
+
+#[test]
+fn test_seats_swap_between_match_games() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions { format: Some(MatchFormat::BestOf { games: 3 }), ..Default::default() },
+    );
+    assert_eq!(seats(&sessions), ("alice".to_string(), "bob".to_string()));
+
+    x_wins(&sessions);
+    sessions.restart_game("match").expect("Next game");
+    assert_eq!(seats(&sessions), ("bob".to_string(), "alice".to_string()));
+}
+
+#[test]
+fn test_best_of_three_ends_after_two_wins() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions { format: Some(MatchFormat::BestOf { games: 3 }), ..Default::default() },
+    );
+
+    // X wins every game, so the players trade wins as the seats swap
+    x_wins(&sessions);
+    sessions.restart_game("match").expect("Next game");
+    x_wins(&sessions);
+
+    let score = match_play(&sessions);
+    assert_eq!(score.score("alice").map(|s| s.wins), Some(1));
+    assert_eq!(score.score("bob").map(|s| s.wins), Some(1));
+    assert!(!score.is_over());
+
+    sessions.restart_game("match").expect("Deciding game");
+    x_wins(&sessions);
+
+    let score = match_play(&sessions);
+    assert_eq!(score.result(), Some(MatchResult::Won("alice".to_string())));
+    assert_eq!(score.games_played(), 3);
+    assert!(score.summary().contains("Alice wins the match"));
+
+    let err = sessions.restart_game("match").expect_err("Match is over");
+    assert!(err.contains("Match is over"));
+}
+
+#[test]
+fn test_best_of_five_ends_early_once_out_of_reach() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions { format: Some(MatchFormat::BestOf { games: 5 }), ..Default::default() },
+    );
+
+    // Alice is X in games 1, 3 and 5; bob resigns the games he plays as X
+    x_wins(&sessions);
+    sessions.restart_game("match").expect("Game 2");
+    let mut session = sessions.get_session("match").expect("Session exists");
+    session.resign("bob").expect("Bob resigns");
+    sessions.update_session(session).expect("Session unchanged since read");
+    sessions.restart_game("match").expect("Game 3");
+    x_wins(&sessions);
+
+    let score = match_play(&sessions);
+    assert_eq!(score.games_played(), 3);
+    assert_eq!(score.result(), Some(MatchResult::Won("alice".to_string())));
+}
+
+#[test]
+fn test_first_to_ignores_draws() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions { format: Some(MatchFormat::FirstTo { wins: 1 }), ..Default::default() },
+    );
+
+    draw(&sessions);
+    let score = match_play(&sessions);
+    assert_eq!(score.draws(), 1);
+    assert!(!score.is_over());
+
+    sessions.restart_game("match").expect("Next game");
+    x_wins(&sessions);
+    assert_eq!(match_play(&sessions).result(), Some(MatchResult::Won("bob".to_string())));
+}
+
+#[test]
+fn test_fixed_match_can_be_drawn() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions { format: Some(MatchFormat::Fixed { games: 2 }), ..Default::default() },
+    );
+
+    x_wins(&sessions);
+    sessions.restart_game("match").expect("Next game");
+    x_wins(&sessions);
+
+    let score = match_play(&sessions);
+    assert_eq!(score.result(), Some(MatchResult::Drawn));
+    assert!(score.summary().contains("Alice 1 - 1 Bob"));
+}
+
+#[test]
+fn test_alternate_first_move_takes_turns_across_a_match() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions {
+            turn_order: TurnOrder {
+                first_move: FirstMove::Alternate,
+                swap_seats: false,
+            },
+            format: Some(MatchFormat::Fixed { games: 3 }),
+            ..Default::default()
+        },
+    );
+
+    let mut openers = Vec::new();
+    for game in 0..3 {
+        if game > 0 {
+            sessions.restart_game("match").expect("Next game");
+        }
+        openers.push(player_to_move(&sessions));
+        draw(&sessions);
+    }
+    assert_eq!(openers, ["alice", "bob", "alice"]);
+}
+
+#[test]
+fn test_undone_game_leaves_the_score() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "match",
+        SessionOptions { format: Some(MatchFormat::BestOf { games: 3 }), ..Default::default() },
+    );
+    x_wins(&sessions);
+    assert_eq!(match_play(&sessions).games_played(), 1);
+
+    let mut session = sessions.get_session("match").expect("Session exists");
+    session.request_undo("alice").expect("Alice asks");
+    session.respond_undo("bob", true).expect("Bob allows it");
+    assert_eq!(session.game.to_move(), Some(Mark::X));
+
+    let score = session.match_play.expect("Match in progress");
+    assert_eq!(score.games_played(), 0);
+    assert_eq!(score.score("alice").map(|s| s.wins), Some(0));
+}
+
+#[test]
+fn test_match_format_wire_format() {
+    let format: MatchFormat =
+        serde_json::from_str(r#"{"kind":"best-of","games":5}"#).expect("Valid format");
+    assert_eq!(format, MatchFormat::BestOf { games: 5 });
+    assert_eq!(format.to_string(), "best of 5");
+    assert_eq!(
+        serde_json::to_string(&MatchFormat::FirstTo { wins: 3 }).expect("Serializes"),
+        r#"{"kind":"first-to","wins":3}"#
+    );
+}
@@ -0,0 +1,96 @@
+//! Tests for the generalized m,n,k typestate architecture.
+
+use strictly_games::mnk::{
+    AnyGame, Coord, GameInProgress, GameResult, GameSetup, MnkConfig, MnkConfigError, Move,
+    MoveError, Player,
+};
+use strictly_games::{Game, GameState, Gomoku, Mnk, Outcome};
+
+/// Plays the given cells alternately starting with X, expecting no finish.
+fn play(config: MnkConfig, cells: &[(usize, usize)]) -> GameInProgress {
+    let mut game = GameSetup::new(config).start(Player::X);
+    for &(row, column) in cells {
+        let action = Move::new(game.to_move(), Coord::new(row, column));
+        game = match game.make_move(action).expect("Valid move") {
+            GameResult::InProgress(g) => g,
+            GameResult::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+    game
+}
+
+#[test]
+fn test_config_validation() {
+    assert!(MnkConfig::new(15, 15, 5).is_ok());
+    assert_eq!(MnkConfig::new(0, 3, 3), Err(MnkConfigError::EmptyBoard));
+    assert_eq!(MnkConfig::new(3, 27, 3), Err(MnkConfigError::TooManyColumns));
+    assert_eq!(MnkConfig::new(3, 3, 4), Err(MnkConfigError::UnwinnableLength(4, 3, 3)));
+}
+
+#[test]
+fn test_gomoku_five_in_a_row_wins() {
+    // X builds a diagonal from the center; O plays along the top edge
+    let game = play(
+        MnkConfig::GOMOKU,
+        &[(7, 7), (0, 0), (8, 8), (0, 1), (9, 9), (0, 2), (10, 10), (0, 3)],
+    );
+
+    match game.make_move(Move::new(Player::X, Coord::new(11, 11))).expect("Valid move") {
+        GameResult::Finished(finished) => {
+            assert_eq!(*finished.outcome(), Outcome::Winner(Player::X));
+            assert_eq!(finished.history().len(), 9);
+        }
+        GameResult::InProgress(_) => panic!("X should have won"),
+    }
+}
+
+#[test]
+fn test_occupied_and_out_of_bounds_rejected() {
+    let game = play(MnkConfig::GOMOKU, &[(7, 7)]);
+
+    let result = game.clone().make_move(Move::new(Player::O, Coord::new(7, 7)));
+    assert!(matches!(result, Err(MoveError::SquareOccupied(_))));
+
+    let result = game.make_move(Move::new(Player::O, Coord::new(15, 0)));
+    assert!(matches!(result, Err(MoveError::OutOfBounds(_))));
+}
+
+#[test]
+fn test_valid_moves_exclude_occupied_cells() {
+    let game = play(MnkConfig::GOMOKU, &[(7, 7), (7, 8)]);
+    let moves = game.valid_moves();
+
+    assert_eq!(moves.len(), 15 * 15 - 2);
+    assert!(!moves.contains(&Coord::new(7, 7)));
+    assert!(!moves.contains(&Coord::new(7, 8)));
+}
+
+#[test]
+fn test_three_three_three_plays_like_tic_tac_toe() {
+    type TicTacToe3 = Mnk<3, 3, 3>;
+
+    // X: A1, B2, C3 diagonal; O: B1, C1
+    let mut state: AnyGame = TicTacToe3::new_game(Player::X).into();
+    for (player, row, column) in [
+        (Player::X, 0, 0),
+        (Player::O, 0, 1),
+        (Player::X, 1, 1),
+        (Player::O, 0, 2),
+        (Player::X, 2, 2),
+    ] {
+        state = GameState::<TicTacToe3>::apply(state, Move::new(player, Coord::new(row, column)))
+            .expect("Valid move");
+    }
+
+    assert!(state.is_over());
+    assert_eq!(state.winner(), Some(Player::X));
+}
+
+#[test]
+fn test_coord_labels_round_trip() {
+    let center = Coord::new(7, 7);
+    assert_eq!(center.to_string(), "H8");
+    assert_eq!(Coord::from_label("h8"), Some(center));
+    assert_eq!(Coord::from_label("A0"), None);
+    assert_eq!(Gomoku::CONFIG, MnkConfig::GOMOKU);
+}
@@ -0,0 +1,195 @@
+//! Tests for resignation and draw offers.
+
+mod common;
+
+use common::{SessionOptions, seated_session, two_humans};
+use strictly_games::connect_four::Column;
+use strictly_games::{
+    Action, ConnectFour, Difficulty, DrawError, GameInProgress, GameResult, GameSession,
+    GameSetup, GameState, Move, MoveError, Outcome, PlayerType, Position, TicTacToe,
+    TicTacToePlayer as Player, Verdict,
+};
+
+/// Unwraps a transition that should leave the game in progress.
+fn in_progress(result: GameResult) -> GameInProgress {
+    let GameResult::InProgress(game) = result else {
+        panic!("Game shouldn't finish yet");
+    };
+    game
+}
+
+#[test]
+fn test_resign_ends_game_for_opponent() {
+    let game = GameSetup::new().start(Player::X);
+    let GameResult::Finished(game) = game.apply(Action::Resign(Player::X)).expect("Can resign")
+    else {
+        panic!("Resigning should finish the game");
+    };
+
+    assert_eq!(*game.outcome(), Outcome::Resigned(Player::X));
+    assert_eq!(game.outcome().winner(), Some(Player::O));
+    assert_eq!(game.outcome().status(), "Player X resigned, Player O wins!");
+}
+
+#[test]
+fn test_accepted_draw_ends_game() {
+    let game = GameSetup::new().start(Player::X);
+    let game = in_progress(game.apply(Action::OfferDraw(Player::X)).expect("Can offer"));
+    assert_eq!(game.draw_offer(), Some(Player::X));
+
+    let GameResult::Finished(game) = game.apply(Action::AcceptDraw(Player::O)).expect("Can accept")
+    else {
+        panic!("Accepting should finish the game");
+    };
+    assert_eq!(*game.outcome(), Outcome::AgreedDraw);
+    assert!(game.outcome().is_draw());
+    assert_eq!(game.outcome().winner(), None);
+}
+
+#[test]
+fn test_declined_draw_clears_offer() {
+    let game = GameSetup::new().start(Player::X);
+    let game = in_progress(game.apply(Action::OfferDraw(Player::X)).expect("Can offer"));
+    let game = in_progress(game.apply(Action::DeclineDraw(Player::O)).expect("Can decline"));
+
+    assert_eq!(game.draw_offer(), None);
+    assert_eq!(game.to_move(), Player::X);
+}
+
+#[test]
+fn test_draw_contracts_reject_invalid_answers() {
+    let game = GameSetup::new().start(Player::X);
+    assert!(matches!(
+        game.clone().apply(Action::AcceptDraw(Player::O)),
+        Err(MoveError::Draw(DrawError::NotOffered(Player::O)))
+    ));
+
+    let game = in_progress(game.apply(Action::OfferDraw(Player::X)).expect("Can offer"));
+    assert!(matches!(
+        game.clone().apply(Action::OfferDraw(Player::O)),
+        Err(MoveError::Draw(DrawError::AlreadyOffered(Player::X)))
+    ));
+    // Nobody accepts their own offer
+    assert!(matches!(
+        game.apply(Action::AcceptDraw(Player::X)),
+        Err(MoveError::Draw(DrawError::NotOffered(Player::X)))
+    ));
+}
+
+#[test]
+fn test_playing_on_declines_draw() {
+    let game = GameSetup::new().start(Player::X);
+    let game = in_progress(game.apply(Action::OfferDraw(Player::X)).expect("Can offer"));
+
+    // The offerer's own move keeps the offer open
+    let game = in_progress(game.make_move(Move::new(Player::X, Position::Center)).expect("Valid"));
+    assert_eq!(game.draw_offer(), Some(Player::X));
+
+    // The opponent moving instead of answering declines it
+    let game = in_progress(game.make_move(Move::new(Player::O, Position::TopLeft)).expect("Valid"));
+    assert_eq!(game.draw_offer(), None);
+}
+
+#[test]
+fn test_session_resign_records_verdict() {
+    let mut session: GameSession<TicTacToe> = two_humans("resign");
+
+    // The empty board is a draw under best play
+    let verdict = session.resign("alice").expect("Alice resigns");
+    assert_eq!(verdict, Verdict::Draw);
+    assert_eq!(session.resignation, Some(Verdict::Draw));
+    assert_eq!(session.game.winner(), Some(Player::O));
+    assert!(session.resign("bob").is_err());
+
+    session.reset_game();
+    assert_eq!(session.resignation, None);
+}
+
+#[tokio::test]
+async fn test_manager_judges_resignation_off_the_lock() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("resign", SessionOptions::default());
+    sessions.make_move_atomic("resign", "alice", Position::Center).expect("Valid move");
+
+    let verdict = sessions.resign("resign", "bob").await.expect("Bob resigns");
+    assert_eq!(verdict, Verdict::Draw);
+    let session = sessions.get_session("resign").expect("Session exists");
+    assert_eq!(session.resignation, Some(Verdict::Draw));
+    assert_eq!(session.game.winner(), Some(Player::X));
+
+    assert!(sessions.resign("resign", "alice").await.is_err());
+    assert!(sessions.resign("resign", "carol").await.is_err());
+}
+
+#[test]
+fn test_session_draw_offer_waits_for_answer() {
+    let mut session: GameSession<TicTacToe> = two_humans("resign");
+    session.offer_draw("alice").expect("Alice offers");
+    assert_eq!(session.game.draw_offer(), Some(Player::X));
+    assert!(session.respond_draw("alice", true).is_err());
+
+    session.respond_draw("bob", true).expect("Bob accepts");
+    assert!(session.game.is_over());
+    assert_eq!(session.game.winner(), None);
+}
+
+#[test]
+fn test_bot_accepts_draw_in_drawn_position() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("bot_draw".to_string());
+    session
+        .register_player("alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    session
+        .register_bot("bot".to_string(), "Bot".to_string(), Difficulty::Perfect)
+        .expect("Bot joins");
+
+    session.offer_draw("alice").expect("Draw offered");
+    session.play_bot_turns().expect("Bot answers");
+    assert!(session.game.is_over());
+    assert!(session.game.status_string().contains("Draw agreed"));
+}
+
+#[test]
+fn test_bot_declines_draw_when_winning() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("bot_wins".to_string());
+    session
+        .register_player("alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    session
+        .register_bot("bot".to_string(), "Bot".to_string(), Difficulty::Perfect)
+        .expect("Bot joins");
+
+    // O threatens the diagonal; after X blocks, bottom-left forks
+    let moves = [
+        Move::new(Player::X, Position::TopCenter),
+        Move::new(Player::O, Position::Center),
+        Move::new(Player::X, Position::BottomCenter),
+        Move::new(Player::O, Position::TopLeft),
+    ];
+    session.game = GameInProgress::replay(&moves).expect("Valid moves").into();
+
+    session.offer_draw("alice").expect("Draw offered");
+    session.play_bot_turns().expect("Bot answers");
+    assert!(!session.game.is_over());
+    assert_eq!(session.game.draw_offer(), None);
+}
+
+#[test]
+fn test_resigned_game_cannot_be_undone() {
+    let mut session: GameSession<TicTacToe> = two_humans("resign");
+    session.make_move("alice", Position::Center).expect("X moves");
+    session.resign("bob").expect("Bob resigns");
+
+    session.request_undo("alice").expect("Alice asks");
+    assert!(session.respond_undo("bob", true).is_err());
+    assert!(session.game.is_over());
+}
+
+#[test]
+fn test_resign_works_for_connect_four() {
+    let mut session: GameSession<ConnectFour> = two_humans("resign");
+    session.make_move("alice", Column::Four).expect("X drops");
+    session.resign("bob").expect("Bob resigns");
+
+    assert_eq!(session.game.winner(), Some(Player::X));
+    assert!(session.make_move("alice", Column::Four).is_err());
+}
@@ -0,0 +1,130 @@
+//! Tests for restoring typestates directly from `AnyGame` snapshots.
+
+use strictly_games::connect_four::{self, Column};
+use strictly_games::mnk::{self, Coord, MnkConfig};
+use strictly_games::{
+    AnyGame, Board, GameFinished, GameInProgress, GameResult, GameSetup, GameState, Move,
+    MoveError, Outcome, Position, TicTacToe, TicTacToePlayer as Player,
+};
+
+#[test]
+fn test_in_progress_round_trips_without_replay() {
+    let game = GameSetup::new().start(Player::X);
+    let GameResult::InProgress(game) =
+        game.make_move(Move::new(Player::X, Position::Center)).expect("Valid move")
+    else {
+        panic!("Game shouldn't finish yet");
+    };
+
+    let restored = GameInProgress::try_from(AnyGame::from(game.clone())).expect("Valid snapshot");
+    assert_eq!(restored.board(), game.board());
+    assert_eq!(restored.to_move(), Player::O);
+    assert_eq!(restored.history(), game.history());
+}
+
+#[test]
+fn test_game_started_by_o_keeps_its_players() {
+    let game: AnyGame = GameSetup::new().start(Player::O).into();
+
+    let game = game
+        .make_move_action(Move::new(Player::O, Position::Center))
+        .expect("O moves first")
+        .make_move_action(Move::new(Player::X, Position::TopLeft))
+        .expect("X replies");
+
+    assert_eq!(game.to_move(), Some(Player::O));
+    assert_eq!(
+        GameState::<TicTacToe>::moves(&game),
+        vec![(Player::O, Position::Center), (Player::X, Position::TopLeft)]
+    );
+    let game = game.undo().expect("X's move comes back");
+    assert_eq!(game.to_move(), Some(Player::X));
+}
+
+#[test]
+fn test_inconsistent_snapshot_is_rejected() {
+    let snapshot = AnyGame::InProgress {
+        board: Board::new(),
+        to_move: Player::O,
+        history: vec![Move::new(Player::X, Position::Center)],
+        draw_offer: None,
+    };
+    assert!(matches!(
+        GameInProgress::try_from(snapshot.clone()),
+        Err(MoveError::InconsistentState(_))
+    ));
+    assert!(snapshot.make_move_action(Move::new(Player::O, Position::TopLeft)).is_err());
+}
+
+#[test]
+fn test_wrong_phase_is_rejected() {
+    let setup: AnyGame = GameSetup::new().into();
+    assert_eq!(GameInProgress::try_from(setup.clone()).err(), Some(MoveError::NotStarted));
+    assert_eq!(GameFinished::try_from(setup).err(), Some(MoveError::NotStarted));
+
+    let in_progress: AnyGame = GameSetup::new().start(Player::X).into();
+    assert!(matches!(
+        GameFinished::try_from(in_progress),
+        Err(MoveError::InconsistentState(_))
+    ));
+}
+
+#[test]
+fn test_finished_round_trips() {
+    let GameResult::Finished(finished) = GameInProgress::replay(&[
+        Move::new(Player::X, Position::TopLeft),
+        Move::new(Player::O, Position::MiddleLeft),
+        Move::new(Player::X, Position::TopCenter),
+        Move::new(Player::O, Position::Center),
+        Move::new(Player::X, Position::TopRight),
+    ])
+    .expect("Valid moves") else {
+        panic!("Game should be finished");
+    };
+
+    let snapshot = AnyGame::from(finished.clone());
+    let restored = GameFinished::try_from(snapshot.clone()).expect("Valid snapshot");
+    assert_eq!(*restored.outcome(), Outcome::Winner(Player::X));
+    assert_eq!(restored.history(), finished.history());
+    assert_eq!(GameInProgress::try_from(snapshot).err(), Some(MoveError::GameOver));
+}
+
+#[test]
+fn test_connect_four_started_by_o() {
+    let game: connect_four::AnyGame =
+        connect_four::GameSetup::new().start(Player::O).into();
+
+    let game = game
+        .make_move_action(connect_four::Move::new(Player::O, Column::Four))
+        .expect("O drops first");
+    let restored = connect_four::GameInProgress::try_from(game).expect("Valid snapshot");
+    assert_eq!(restored.to_move(), Player::X);
+    assert_eq!(restored.history().len(), 1);
+}
+
+#[test]
+fn test_gomoku_snapshot_round_trips() {
+    let game = mnk::GameSetup::new(MnkConfig::GOMOKU).start(Player::X);
+    let mnk::GameResult::InProgress(game) = game
+        .make_move(mnk::Move::new(Player::X, Coord::new(7, 7)))
+        .expect("Valid move")
+    else {
+        panic!("Game shouldn't finish yet");
+    };
+
+    let restored =
+        mnk::GameInProgress::try_from(mnk::AnyGame::from(game.clone())).expect("Valid snapshot");
+    assert_eq!(restored.board(), game.board());
+    assert_eq!(restored.to_move(), Player::O);
+
+    let corrupt = mnk::AnyGame::InProgress {
+        board: mnk::Board::new(MnkConfig::GOMOKU),
+        to_move: Player::O,
+        history: game.history().to_vec(),
+        draw_offer: None,
+    };
+    assert!(matches!(
+        mnk::GameInProgress::try_from(corrupt),
+        Err(mnk::MoveError::InconsistentState(_))
+    ));
+}
@@ -0,0 +1,157 @@
+//! Tests for spectators: joining, leaving and following a session live.
+
+mod common;
+
+use common::{SessionOptions, seated_session};
+use std::time::Duration;
+use strictly_games::{
+    Expiry, GameSession, PlayerType, Position, SessionEvent, SessionStatus, TicTacToe,
+};
+
+#[test]
+fn test_spectators_join_a_full_session() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    let err = sessions
+        .register_player_atomic("watched", "carol".to_string(), "Carol".to_string(), PlayerType::Human)
+        .expect_err("Seats are full");
+    assert!(err.contains("spectate"));
+
+    sessions
+        .add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string())
+        .expect("Carol watches");
+    let session = sessions.get_session("watched").expect("Session exists");
+    let spectators: Vec<_> =
+        session.spectators.iter().map(|s| (s.id.as_str(), s.name.as_str())).collect();
+    assert_eq!(spectators, vec![("carol", "Carol")]);
+    assert!(session.get_player("carol").is_none());
+    assert_eq!(session.status, SessionStatus::Active);
+
+    assert!(sessions.add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string()).is_err());
+}
+
+#[test]
+fn test_spectators_cannot_play() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    sessions
+        .add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string())
+        .expect("Carol watches");
+
+    assert!(sessions.make_move_atomic("watched", "carol", Position::Center).is_err());
+    let session = sessions.get_session("watched").expect("Session exists");
+    assert!(session.authorize("carol", Some("anything")).is_err());
+    assert_eq!(session.game.move_count(), 0);
+}
+
+#[test]
+fn test_spectators_leave_and_survive_replay() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    let carol = sessions
+        .add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string())
+        .expect("Carol watches");
+    sessions
+        .add_spectator_atomic("watched", "dave".to_string(), "Dave".to_string())
+        .expect("Dave watches");
+    sessions.remove_spectator_atomic("watched", "carol", Some(carol.as_str())).expect("Carol leaves");
+    assert!(sessions.remove_spectator_atomic("watched", "carol", Some(carol.as_str())).is_err());
+
+    let session = sessions.get_session("watched").expect("Session exists");
+    let replayed = GameSession::replay("watched".to_string(), session.clock.source(), session.events())
+        .expect("Log replays");
+    let names: Vec<_> = replayed.spectators.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Dave"]);
+    assert!(matches!(
+        session.events().last().map(|logged| &logged.event),
+        Some(SessionEvent::SpectatorLeft { id }) if id == "carol"
+    ));
+}
+
+#[test]
+fn test_only_the_spectator_can_stop_watching() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    let carol = sessions
+        .add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string())
+        .expect("Carol watches");
+    let dave = sessions
+        .add_spectator_atomic("watched", "dave".to_string(), "Dave".to_string())
+        .expect("Dave watches");
+    assert_ne!(carol, dave);
+
+    let err = sessions.remove_spectator_atomic("watched", "carol", None).expect_err("No token");
+    assert!(err.contains("Missing spectator token"));
+    assert!(sessions.remove_spectator_atomic("watched", "carol", Some(dave.as_str())).is_err());
+    assert_eq!(sessions.get_session("watched").expect("Session exists").spectators.len(), 2);
+
+    sessions.remove_spectator_atomic("watched", "carol", Some(carol.as_str())).expect("Carol leaves");
+}
+
+#[test]
+fn test_spectator_tokens_are_redacted_from_the_served_log() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    sessions
+        .add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string())
+        .expect("Carol watches");
+
+    let session = sessions.get_session("watched").expect("Session exists");
+    let joined = session.events().last().expect("Carol's event").redacted();
+    assert!(matches!(
+        &joined.event,
+        SessionEvent::SpectatorJoined { spectator } if spectator.name == "Carol" && spectator.token.is_none()
+    ));
+}
+
+#[test]
+fn test_watching_is_not_activity() {
+    let (sessions, clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    let expiry = Expiry {
+        idle_timeout: Duration::from_secs(60),
+        retention: Duration::from_secs(30),
+    };
+
+    clock.advance(Duration::from_secs(50));
+    sessions
+        .add_spectator_atomic("watched", "carol".to_string(), "Carol".to_string())
+        .expect("Carol watches");
+    clock.advance(Duration::from_secs(10));
+    sessions.reap(&expiry);
+
+    let session = sessions.get_session("watched").expect("Still listed");
+    assert_eq!(session.status, SessionStatus::Abandoned);
+}
+
+#[tokio::test]
+async fn test_wait_for_events_wakes_on_a_move() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    let seen = sessions.get_session("watched").expect("Session exists").events().len();
+
+    let mover = sessions.clone();
+    let waiter = tokio::spawn(async move {
+        sessions.wait_for_events("watched", seen, Duration::from_secs(10)).await
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    mover.make_move_atomic("watched", "alice", Position::Center).expect("Valid move");
+
+    let session = waiter.await.expect("Waiter finishes").expect("Session exists");
+    assert_eq!(session.events().len(), seen + 1);
+    assert_eq!(session.game.move_count(), 1);
+}
+
+#[tokio::test]
+async fn test_wait_for_events_times_out_without_news() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("watched", SessionOptions::default());
+    let seen = sessions.get_session("watched").expect("Session exists").events().len();
+
+    let session = sessions
+        .wait_for_events("watched", seen, Duration::from_millis(20))
+        .await
+        .expect("Session exists");
+    assert_eq!(session.events().len(), seen);
+
+    // Events already there come back at once
+    let session = sessions
+        .wait_for_events("watched", 0, Duration::from_secs(60))
+        .await
+        .expect("Session exists");
+    assert_eq!(session.events().len(), seen);
+
+    assert!(sessions.wait_for_events("missing", 0, Duration::from_millis(20)).await.is_none());
+}
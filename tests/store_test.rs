@@ -0,0 +1,237 @@
+//! Tests for session stores: reloading sessions after a restart.
+
+mod common;
+
+use common::seat_players;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use strictly_games::connect_four::Column;
+use strictly_games::{
+    GameSession, GameSessions, GameState, JsonlStore, LoggedEvent, ManualClock, MatchFormat,
+    MemoryStore, Position, SessionManager, SessionStore, TicTacToe,
+    TicTacToePlayer as Player, TimeControl,
+};
+
+/// Returns an empty scratch directory for one test.
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("strictly_games_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Memory store whose appends fail while `failing` is set.
+#[derive(Debug, Default)]
+struct FlakyStore {
+    inner: MemoryStore<TicTacToe>,
+    failing: AtomicBool,
+}
+
+impl SessionStore<TicTacToe> for FlakyStore {
+    fn load(&self) -> Result<Vec<(String, Vec<LoggedEvent<TicTacToe>>)>, String> {
+        self.inner.load()
+    }
+
+    fn append(&self, session_id: &str, events: &[LoggedEvent<TicTacToe>]) -> Result<(), String> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err("Disk full".to_string());
+        }
+        self.inner.append(session_id, events)
+    }
+
+    fn replace(&self, session_id: &str, events: &[LoggedEvent<TicTacToe>]) -> Result<(), String> {
+        self.inner.replace(session_id, events)
+    }
+
+    fn remove(&self, session_id: &str) -> Result<(), String> {
+        self.inner.remove(session_id)
+    }
+}
+
+#[test]
+fn test_memory_store_reloads_sessions() {
+    let clock = Arc::new(ManualClock::new());
+    let store: Arc<MemoryStore<TicTacToe>> = Arc::new(MemoryStore::new());
+
+    let sessions = SessionManager::with_store(clock.clone(), store.clone()).expect("Empty store");
+    sessions.create_session("kept".to_string()).expect("Session created");
+    seat_players(&sessions, "kept");
+    sessions.make_move_atomic("kept", "alice", Position::Center).expect("Valid move");
+
+    let restarted = SessionManager::with_store(clock, store).expect("Store loads");
+    let session = restarted.get_session("kept").expect("Session restored");
+    assert_eq!(session.game.moves(), vec![(Player::X, Position::Center)]);
+    assert_eq!(session.player_o.map(|p| p.name), Some("Bob".to_string()));
+    assert!(session.is_players_turn("bob"));
+}
+
+#[test]
+fn test_failed_append_is_caught_up_by_the_next_change() {
+    let clock = Arc::new(ManualClock::new());
+    let store = Arc::new(FlakyStore::default());
+
+    let sessions = SessionManager::with_store(clock.clone(), store.clone()).expect("Empty store");
+    sessions.create_session("flaky".to_string()).expect("Session created");
+    seat_players(&sessions, "flaky");
+
+    // The move is played, but never reaches the store
+    store.failing.store(true, Ordering::SeqCst);
+    sessions.make_move_atomic("flaky", "alice", Position::Center).expect("Valid move");
+    store.failing.store(false, Ordering::SeqCst);
+    let stored = store.load().expect("Store loads");
+    assert_eq!(stored[0].1.len(), 3);
+
+    // The next change rewrites the log without a gap
+    sessions.make_move_atomic("flaky", "bob", Position::TopLeft).expect("Valid move");
+    let restarted = SessionManager::with_store(clock, store).expect("Store loads");
+    let session = restarted.get_session("flaky").expect("Session restored");
+    assert_eq!(session.game.move_count(), 2);
+    assert_eq!(session.events().len(), 5);
+}
+
+#[test]
+fn test_replay_rejects_a_gap_in_the_log() {
+    let clock = Arc::new(ManualClock::new());
+    let store: Arc<MemoryStore<TicTacToe>> = Arc::new(MemoryStore::new());
+
+    let sessions = SessionManager::with_store(clock.clone(), store.clone()).expect("Empty store");
+    sessions.create_session("gap".to_string()).expect("Session created");
+    seat_players(&sessions, "gap");
+
+    let mut events = sessions.get_session("gap").expect("Session exists").events().to_vec();
+    events.remove(1);
+    let err = GameSession::<TicTacToe>::replay("gap".to_string(), clock.clone(), &events)
+        .expect_err("Replay fails");
+    assert!(err.contains("seq 1"));
+
+    // The session is skipped when the store is loaded
+    store.replace("gap", &events).expect("Log replaced");
+    let restarted = SessionManager::with_store(clock, store).expect("Store loads");
+    assert!(restarted.get_session("gap").is_none());
+}
+
+#[test]
+fn test_jsonl_store_survives_restart() {
+    let dir = scratch_dir("restart");
+    let clock = ManualClock::new();
+
+    let sessions = GameSessions::open(&dir, Arc::new(clock.clone())).expect("Store opens");
+    sessions
+        .tictactoe
+        .create_timed_session("ttt".to_string(), TimeControl::PerMove { secs: 30 })
+        .expect("Session created");
+    sessions.tictactoe.start_match_atomic("ttt", MatchFormat::BestOf { games: 3 }).expect("Match started");
+    seat_players(&sessions.tictactoe, "ttt");
+    clock.advance(Duration::from_secs(10));
+    sessions.tictactoe.make_move_atomic("ttt", "alice", Position::Center).expect("Valid move");
+    clock.advance(Duration::from_secs(12));
+
+    sessions.connect_four.create_session("c4".to_string()).expect("Session created");
+    seat_players(&sessions.connect_four, "c4");
+    sessions.connect_four.make_move_atomic("c4", "alice", Column::Four).expect("Valid drop");
+    drop(sessions);
+
+    let restarted = GameSessions::open(&dir, Arc::new(clock.clone())).expect("Store reopens");
+    let session = restarted.tictactoe.get_session("ttt").expect("Session restored");
+    assert_eq!(session.game.moves(), vec![(Player::X, Position::Center)]);
+    assert!(session.match_play.is_some());
+    // Bob's turn has been running for 12 of his 30 seconds
+    assert_eq!(session.clock.remaining(Player::O), Some(Duration::from_secs(18)));
+
+    let session = restarted.connect_four.get_session("c4").expect("Session restored");
+    assert_eq!(session.game.moves(), vec![(Player::X, Column::Four)]);
+    assert!(restarted.gomoku.list_sessions().is_empty());
+
+    // Play continues, and is kept, after the restart
+    restarted.tictactoe.make_move_atomic("ttt", "bob", Position::TopLeft).expect("Valid move");
+    let again = GameSessions::open(&dir, Arc::new(clock)).expect("Store reopens");
+    let session = again.tictactoe.get_session("ttt").expect("Session restored");
+    assert_eq!(session.game.move_count(), 2);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_torn_last_line_is_dropped() {
+    let dir = scratch_dir("torn");
+    let clock = Arc::new(ManualClock::new());
+    let store = Arc::new(JsonlStore::open(&dir).expect("Store opens"));
+
+    let sessions: SessionManager<TicTacToe> =
+        SessionManager::with_store(clock.clone(), store.clone()).expect("Empty store");
+    sessions.create_session("torn".to_string()).expect("Session created");
+    seat_players(&sessions, "torn");
+
+    // A crash in the middle of writing the next event
+    let path = dir.join("torn.jsonl");
+    let mut contents = fs::read_to_string(&path).expect("Log written");
+    contents.push_str(r#"{"seq":3,"at":{"secs":0,"na"#);
+    fs::write(&path, contents).expect("Log torn");
+
+    let restarted: SessionManager<TicTacToe> =
+        SessionManager::with_store(clock.clone(), store.clone()).expect("Store loads");
+    let session = restarted.get_session("torn").expect("Session restored");
+    assert_eq!(session.events().len(), 3);
+
+    // The torn line is gone, so new events append cleanly
+    restarted.make_move_atomic("torn", "alice", Position::Center).expect("Valid move");
+    let again: SessionManager<TicTacToe> =
+        SessionManager::with_store(clock, store).expect("Store loads");
+    assert_eq!(again.get_session("torn").expect("Session restored").game.move_count(), 1);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_corrupt_log_is_skipped_on_load() {
+    let dir = scratch_dir("corrupt");
+    let clock = Arc::new(ManualClock::new());
+    let store = Arc::new(JsonlStore::open(&dir).expect("Store opens"));
+
+    let sessions: SessionManager<TicTacToe> =
+        SessionManager::with_store(clock.clone(), store.clone()).expect("Empty store");
+    sessions.create_session("corrupt".to_string()).expect("Session created");
+    seat_players(&sessions, "corrupt");
+    sessions.create_session("intact".to_string()).expect("Session created");
+
+    // Damage the second of three lines; the ones after it are intact
+    let path = dir.join("corrupt.jsonl");
+    let contents = fs::read_to_string(&path).expect("Log written");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 3);
+    lines[1] = r#"{"seq":1,"at":"#;
+    let corrupt = format!("{}\n", lines.join("\n"));
+    fs::write(&path, &corrupt).expect("Log corrupted");
+
+    let restarted = SessionManager::<TicTacToe>::with_store(clock, store).expect("Store loads");
+    assert!(restarted.get_session("corrupt").is_none());
+    assert!(restarted.get_session("intact").is_some());
+    // Nothing was cut off the file
+    assert_eq!(fs::read_to_string(&path).expect("Log kept"), corrupt);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_session_ids_become_safe_file_names() {
+    let dir = scratch_dir("names");
+    let store = JsonlStore::open(&dir).expect("Store opens");
+    let sessions: SessionManager<TicTacToe> =
+        SessionManager::with_store(Arc::new(ManualClock::new()), Arc::new(store.clone()))
+            .expect("Empty store");
+    sessions.create_session("../evil game/1".to_string()).expect("Session created");
+
+    let names: Vec<_> = fs::read_dir(&dir)
+        .expect("Directory exists")
+        .map(|entry| entry.expect("Entry readable").file_name())
+        .collect();
+    assert_eq!(names.len(), 1);
+    assert!(!names[0].to_string_lossy().contains('/'));
+
+    let logs = SessionStore::<TicTacToe>::load(&store).expect("Store loads");
+    assert_eq!(logs[0].0, "../evil game/1");
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,110 @@
+//! Tests for tic-tac-toe board symmetries and the solver's transposition table.
+
+use std::collections::HashSet;
+use strictly_games::{
+    Board, Game, GameInProgress, Position, Solver, Square, Symmetry, TicTacToe,
+    TicTacToePlayer as Player, Transition,
+};
+
+/// Board with X on the given positions.
+fn board_with_x(positions: &[Position]) -> Board {
+    let mut board = Board::new();
+    for &position in positions {
+        board.set(position, Square::Occupied(Player::X));
+    }
+    board
+}
+
+#[test]
+fn test_position_mapping() {
+    assert_eq!(Position::TopLeft.transform(Symmetry::Rotate90), Position::TopRight);
+    assert_eq!(Position::TopLeft.transform(Symmetry::FlipVertical), Position::BottomLeft);
+    assert_eq!(Position::TopRight.transform(Symmetry::FlipDiagonal), Position::BottomLeft);
+    assert_eq!(Position::Center.transform(Symmetry::Rotate270), Position::Center);
+
+    for symmetry in Symmetry::ALL {
+        let images: HashSet<Position> =
+            Position::ALL.iter().map(|p| p.transform(symmetry)).collect();
+        assert_eq!(images.len(), 9, "{} is a bijection", symmetry);
+
+        for position in Position::ALL {
+            assert_eq!(position.transform(symmetry).transform(symmetry.inverse()), position);
+        }
+    }
+}
+
+#[test]
+fn test_corner_openings_share_canonical_form() {
+    let corners = [
+        Position::TopLeft,
+        Position::TopRight,
+        Position::BottomLeft,
+        Position::BottomRight,
+    ];
+    let keys: HashSet<u32> = corners
+        .iter()
+        .map(|&corner| board_with_x(&[corner]).canonical_key())
+        .collect();
+    assert_eq!(keys.len(), 1);
+
+    let center = board_with_x(&[Position::Center]).canonical_key();
+    let edge = board_with_x(&[Position::TopCenter]).canonical_key();
+    assert!(!keys.contains(&center));
+    assert_ne!(center, edge);
+}
+
+#[test]
+fn test_canonical_symmetry_maps_board_onto_representative() {
+    let board = board_with_x(&[Position::BottomRight, Position::MiddleRight]);
+    let (canonical, symmetry) = board.canonical();
+
+    assert_eq!(board.transform(symmetry), canonical);
+    assert_eq!(canonical.transform(symmetry.inverse()), board);
+    assert_eq!(canonical.key(), board.canonical_key());
+    assert!(board.symmetries().iter().all(|b| b.key() >= canonical.key()));
+}
+
+/// Collects the canonical key of every position reachable from `game`.
+///
+/// `explored` holds exact board keys already expanded, so transpositions
+/// are only walked once.
+fn collect_positions(game: GameInProgress, seen: &mut HashSet<u32>, explored: &mut HashSet<u32>) {
+    if !explored.insert(game.board().key()) {
+        return;
+    }
+    seen.insert(game.board().canonical_key());
+    for position in TicTacToe::legal_choices(&game) {
+        let action = TicTacToe::action(TicTacToe::to_move(&game), position);
+        match TicTacToe::make_move(game.clone(), action).expect("Legal move") {
+            Transition::InProgress(next) => collect_positions(next, seen, explored),
+            Transition::Finished(finished) => {
+                seen.insert(finished.board().canonical_key());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_reachable_positions_up_to_symmetry() {
+    let mut seen = HashSet::new();
+    collect_positions(TicTacToe::new_game(Player::X), &mut seen, &mut HashSet::new());
+    // The well-known count of essentially different tic-tac-toe positions
+    assert_eq!(seen.len(), 765);
+}
+
+#[test]
+fn test_transposition_table_keeps_exact_scores() {
+    let mut game = TicTacToe::new_game(Player::X);
+    for position in [Position::TopLeft, Position::MiddleLeft] {
+        let action = TicTacToe::action(TicTacToe::to_move(&game), position);
+        game = match TicTacToe::make_move(game, action).expect("Legal move") {
+            Transition::InProgress(next) => next,
+            Transition::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+
+    // A 9-ply horizon never cuts tic-tac-toe short, but bypasses the table
+    let with_table = Solver::perfect().score_choices::<TicTacToe>(&game);
+    let without_table = Solver::with_depth(9).score_choices::<TicTacToe>(&game);
+    assert_eq!(with_table, without_table);
+}
@@ -0,0 +1,100 @@
+//! Tests for the precomputed tic-tac-toe tablebase.
+
+use strictly_games::{
+    Board, Game, GameInProgress, Position, Solver, Square, Tablebase, TablebaseError, TicTacToe,
+    TicTacToePlayer as Player, Transition, WIN_SCORE,
+};
+
+/// Plays the given positions from a fresh game.
+fn play(first_player: Player, positions: &[Position]) -> GameInProgress {
+    let mut game = TicTacToe::new_game(first_player);
+    for &position in positions {
+        let action = TicTacToe::action(TicTacToe::to_move(&game), position);
+        game = match TicTacToe::make_move(game, action).expect("Legal move") {
+            Transition::InProgress(next) => next,
+            Transition::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+    game
+}
+
+#[test]
+fn test_shipped_tablebase_is_up_to_date() {
+    let generated = Tablebase::generate();
+    assert_eq!(Tablebase::shipped(), &generated);
+    assert_eq!(
+        Tablebase::from_bytes(&generated.to_bytes()).expect("Round trips"),
+        generated
+    );
+}
+
+#[test]
+fn test_empty_board_is_a_draw() {
+    let tablebase = Tablebase::shipped();
+    for first_player in [Player::X, Player::O] {
+        let scores = tablebase.scores(&Board::new(), first_player).expect("Empty board");
+        assert_eq!(scores.len(), 9);
+        assert!(scores.iter().all(|&(_, score)| score == 0));
+    }
+}
+
+#[test]
+fn test_best_moves_find_the_win() {
+    let game = play(
+        Player::X,
+        &[Position::TopLeft, Position::Center, Position::TopCenter, Position::MiddleLeft],
+    );
+    let tablebase = Tablebase::shipped();
+
+    assert_eq!(tablebase.value(game.board(), Player::X), Some(WIN_SCORE));
+    assert_eq!(
+        tablebase.best_moves(game.board(), Player::X),
+        Some(vec![Position::TopRight])
+    );
+}
+
+#[test]
+fn test_unreachable_positions_are_missing() {
+    let mut board = Board::new();
+    board.set(Position::TopLeft, Square::Occupied(Player::X));
+    board.set(Position::TopCenter, Square::Occupied(Player::X));
+    assert_eq!(Tablebase::shipped().scores(&board, Player::O), None);
+}
+
+#[test]
+fn test_scores_match_search() {
+    // A 9-ply horizon never cuts tic-tac-toe short, but bypasses the tablebase
+    let search = Solver::with_depth(9);
+    let tablebase = Tablebase::shipped();
+
+    for first in Position::ALL {
+        for second in Position::ALL.into_iter().filter(|&p| p != first) {
+            let game = play(Player::X, &[first, second]);
+            let expected = search.score_choices::<TicTacToe>(&game);
+            assert_eq!(tablebase.scores(game.board(), Player::X), Some(expected.clone()));
+            assert_eq!(Solver::perfect().score_choices::<TicTacToe>(&game), expected);
+        }
+    }
+
+    let game = play(Player::O, &[Position::Center]);
+    assert_eq!(
+        tablebase.scores(game.board(), Player::X),
+        Some(search.score_choices::<TicTacToe>(&game))
+    );
+}
+
+#[test]
+fn test_from_bytes_rejects_bad_files() {
+    let mut bytes = Tablebase::shipped().to_bytes();
+    assert!(matches!(Tablebase::from_bytes(b"nope"), Err(TablebaseError::BadMagic)));
+    assert!(matches!(
+        Tablebase::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(TablebaseError::BadLength)
+    ));
+
+    bytes[4] = 99;
+    assert!(matches!(
+        Tablebase::from_bytes(&bytes),
+        Err(TablebaseError::UnsupportedVersion(99))
+    ));
+}
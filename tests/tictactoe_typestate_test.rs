@@ -146,3 +146,56 @@ fn test_restart() {
         assert!(new_game.history().is_empty());
     }
 }
+
+#[test]
+fn test_postconditions_hold_for_legal_moves() {
+    use strictly_games::contracts::verify_transition;
+
+    let mut game = GameSetup::new().start(Player::O);
+    for position in [Position::Center, Position::TopLeft, Position::BottomRight] {
+        let action = Move::new(game.to_move(), position);
+        let result = game.clone().make_move(action).expect("Valid move");
+        verify_transition(&action, &game, &result).expect("Postconditions hold");
+        game = match result {
+            GameResult::InProgress(g) => g,
+            GameResult::Finished(_) => panic!("Game shouldn't finish yet"),
+        };
+    }
+}
+
+#[test]
+fn test_postconditions_reject_broken_transitions() {
+    use strictly_games::contracts::{
+        check_history_extended, check_marks_balanced, check_one_mark_added, check_turn_alternated,
+    };
+    use strictly_games::{Board, Square};
+
+    let action = Move::new(Player::X, Position::Center);
+    let before = Board::new();
+    let mut after = Board::new();
+    after.set(Position::Center, Square::Occupied(Player::X));
+    assert!(check_one_mark_added(&action, &before, &after).is_ok());
+
+    // An extra mark, or the wrong one
+    let mut extra = after.clone();
+    extra.set(Position::TopLeft, Square::Occupied(Player::O));
+    let mut wrong = Board::new();
+    wrong.set(Position::Center, Square::Occupied(Player::O));
+    for board in [&before, &extra, &wrong] {
+        assert!(matches!(
+            check_one_mark_added(&action, &before, board),
+            Err(MoveError::InvariantViolation(_))
+        ));
+    }
+
+    assert!(check_history_extended(&action, 0, &[action]).is_ok());
+    assert!(check_history_extended(&action, 1, &[action]).is_err());
+    assert!(check_history_extended(&action, 0, &[Move::new(Player::X, Position::TopLeft)]).is_err());
+
+    assert!(check_turn_alternated(&action, Player::O).is_ok());
+    assert!(check_turn_alternated(&action, Player::X).is_err());
+
+    assert!(check_marks_balanced(&after, Player::X).is_ok());
+    assert!(check_marks_balanced(&after, Player::O).is_err());
+    assert!(check_marks_balanced(&extra, Player::O).is_ok());
+}
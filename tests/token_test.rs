@@ -0,0 +1,137 @@
+//! Tests for player tokens: issuing them and checking them.
+
+mod common;
+
+use common::{SessionOptions, seated_session};
+use std::sync::Arc;
+use strictly_games::{
+    Difficulty, GameSession, ManualClock, PlayerToken, PlayerType, SessionManager, TicTacToe,
+};
+
+/// Returns the token issued to a seated player.
+fn token_of(session: &GameSession<TicTacToe>, player_id: &str) -> String {
+    let player = session.get_player(player_id).expect("Player seated");
+    player.token.as_ref().expect("Player has a token").as_str().to_string()
+}
+
+#[test]
+fn test_each_seat_gets_its_own_token() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("tok", SessionOptions::default());
+    let session = sessions.get_session("tok").expect("Session exists");
+    let alice = token_of(&session, "alice");
+    let bob = token_of(&session, "bob");
+
+    assert_ne!(alice, bob);
+    assert_eq!(alice.len(), 64);
+    assert!(alice.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn test_authorize_checks_the_seat() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("tok", SessionOptions::default());
+    let session = sessions.get_session("tok").expect("Session exists");
+    let alice = token_of(&session, "alice");
+    let bob = token_of(&session, "bob");
+
+    let player = session.authorize("alice", Some(&alice)).expect("Alice's own token");
+    assert_eq!(player.name, "Alice");
+
+    let err = session.authorize("alice", None).expect_err("No token");
+    assert!(err.contains("Missing player token"));
+    let err = session.authorize("alice", Some(&bob)).expect_err("Bob's token");
+    assert!(err.contains("does not match"));
+    let err = session.authorize("carol", Some(&alice)).expect_err("Not seated");
+    assert!(err.contains("not seated"));
+}
+
+#[test]
+fn test_authorize_any_accepts_either_seat() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("tok", SessionOptions::default());
+    let session = sessions.get_session("tok").expect("Session exists");
+
+    session.authorize_any(Some(&token_of(&session, "alice"))).expect("Alice's token");
+    session.authorize_any(Some(&token_of(&session, "bob"))).expect("Bob's token");
+    assert!(session.authorize_any(None).is_err());
+    assert!(session.authorize_any(Some("guess")).is_err());
+}
+
+#[test]
+fn test_sessions_without_tokens_need_none() {
+    let sessions: SessionManager<TicTacToe> = SessionManager::new();
+    sessions.create_session("bots".to_string()).expect("Session created");
+    let session = sessions.get_session("bots").expect("Session exists");
+    session.authorize_any(None).expect("Empty session");
+
+    sessions
+        .register_bot_atomic("bots", "b1".to_string(), "Bot 1".to_string(), Difficulty::Random)
+        .expect("Bot joins");
+    let session = sessions.get_session("bots").expect("Session exists");
+    assert!(session.get_player("b1").expect("Bot seated").token.is_none());
+    session.authorize_any(None).expect("Bot-only session");
+
+    let err = session.authorize("b1", Some("anything")).expect_err("Bots take no commands");
+    assert!(err.contains("bot"));
+}
+
+#[test]
+fn test_seated_player_cannot_register_again() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("tok", SessionOptions::default());
+    let err = sessions
+        .register_player_atomic("tok", "alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect_err("Seat already taken");
+    assert!(err.contains("already seated"));
+}
+
+#[test]
+fn test_tokens_survive_replay_but_not_redaction() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("tok", SessionOptions::default());
+    let session = sessions.get_session("tok").expect("Session exists");
+    let alice = token_of(&session, "alice");
+
+    let replayed = GameSession::replay("tok".to_string(), session.clock.source(), session.events())
+        .expect("Log replays");
+    replayed.authorize("alice", Some(&alice)).expect("Token restored");
+
+    let served = serde_json::to_string(
+        &session.events().iter().map(|e| e.redacted()).collect::<Vec<_>>(),
+    )
+    .expect("Serializes");
+    assert!(!served.contains(&alice));
+    assert!(serde_json::to_string(session.events()).expect("Serializes").contains(&alice));
+}
+
+#[test]
+fn test_token_debug_hides_the_secret() {
+    let token = PlayerToken::generate();
+    assert!(!format!("{:?}", token).contains(token.as_str()));
+    assert!(token.matches(token.as_str()));
+    assert!(!token.matches(&token.as_str()[1..]));
+}
+
+#[test]
+fn test_concurrent_joins_each_get_their_token() {
+    let sessions: SessionManager<TicTacToe> = SessionManager::with_clock(Arc::new(ManualClock::new()));
+    sessions.create_session("race".to_string()).expect("Session created");
+
+    let joins: Vec<_> = ["alice", "bob"]
+        .into_iter()
+        .map(|id| {
+            let sessions = sessions.clone();
+            std::thread::spawn(move || {
+                sessions.register_atomic("race", id.to_string(), id.to_string(), PlayerType::Agent, None)
+            })
+        })
+        .collect();
+    let seats: Vec<_> = joins
+        .into_iter()
+        .map(|join| join.join().expect("Join finishes").expect("Both players are seated"))
+        .collect();
+    assert_ne!(seats[0].0, seats[1].0);
+
+    let session = sessions.get_session("race").expect("Session exists");
+    for ((_, token), id) in seats.iter().zip(["alice", "bob"]) {
+        assert_eq!(token.as_ref().map(PlayerToken::as_str), Some(token_of(&session, id).as_str()));
+    }
+    // The session is full now
+    assert!(sessions.register_atomic("race", "carol".to_string(), "Carol".to_string(), PlayerType::Human, None).is_err());
+}
@@ -0,0 +1,170 @@
+//! Tests for choosing the first mover and swapping seats between games.
+
+mod common;
+
+use common::{SessionOptions, seated_session};
+use strictly_games::{
+    Difficulty, FirstMove, PlayerType, SessionManager, TicTacToe, TicTacToePlayer as Player,
+    TimeControl, TurnOrder,
+};
+
+/// Returns who is to move in the session.
+fn to_move(sessions: &SessionManager<TicTacToe>) -> Option<Player> {
+    sessions.get_session("ordered").expect("Session exists").game.to_move()
+}
+
+/// Returns the ID of the player to move in the session.
+fn player_to_move(sessions: &SessionManager<TicTacToe>) -> String {
+    let session = sessions.get_session("ordered").expect("Session exists");
+    let mark = session.game.to_move().expect("Game in progress");
+    session.player_with_mark(mark).expect("Seat filled").id.clone()
+}
+
+#[test]
+fn test_default_is_x_first_every_game() {
+    let (sessions, _clock) = seated_session::<TicTacToe>("ordered", SessionOptions::default());
+    assert_eq!(to_move(&sessions), Some(Player::X));
+
+    sessions.restart_game("ordered").expect("Restarts");
+    assert_eq!(to_move(&sessions), Some(Player::X));
+}
+
+#[test]
+fn test_fixed_o_opens() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "ordered",
+        SessionOptions {
+            turn_order: TurnOrder {
+                first_move: FirstMove::Fixed { mark: Player::O },
+                swap_seats: false,
+            },
+            ..Default::default()
+        },
+    );
+    assert_eq!(to_move(&sessions), Some(Player::O));
+
+    let session = sessions.get_session("ordered").expect("Session exists");
+    assert!(session.is_players_turn("bob"));
+    assert!(!session.is_players_turn("alice"));
+}
+
+#[test]
+fn test_alternate_flips_on_restart() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "ordered",
+        SessionOptions {
+            turn_order: TurnOrder {
+                first_move: FirstMove::Alternate,
+                swap_seats: false,
+            },
+            ..Default::default()
+        },
+    );
+    assert_eq!(to_move(&sessions), Some(Player::X));
+
+    sessions.restart_game("ordered").expect("Restarts");
+    assert_eq!(to_move(&sessions), Some(Player::O));
+    sessions.restart_game("ordered").expect("Restarts");
+    assert_eq!(to_move(&sessions), Some(Player::X));
+
+    let session = sessions.get_session("ordered").expect("Session exists");
+    assert_eq!(session.game_number, 2);
+}
+
+#[test]
+fn test_alternate_with_swapped_seats_alternates_the_opening_player() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "ordered",
+        SessionOptions {
+            turn_order: TurnOrder {
+                first_move: FirstMove::Alternate,
+                swap_seats: true,
+            },
+            ..Default::default()
+        },
+    );
+    assert_eq!(player_to_move(&sessions), "alice");
+
+    sessions.restart_game("ordered").expect("Restarts");
+    assert_eq!(player_to_move(&sessions), "bob");
+    sessions.restart_game("ordered").expect("Restarts");
+    assert_eq!(player_to_move(&sessions), "alice");
+}
+
+#[test]
+fn test_random_is_reproducible_from_seed() {
+    let first_move = FirstMove::Random { seed: 42 };
+    let marks: Vec<_> = (0..32).map(|game| first_move.mark_for_game(game)).collect();
+    let again: Vec<_> = (0..32).map(|game| first_move.mark_for_game(game)).collect();
+
+    assert_eq!(marks, again);
+    assert!(marks.contains(&Player::X));
+    assert!(marks.contains(&Player::O));
+}
+
+#[test]
+fn test_swap_seats_trades_marks_on_restart() {
+    let (sessions, _clock) = seated_session::<TicTacToe>(
+        "ordered",
+        SessionOptions {
+            turn_order: TurnOrder {
+                first_move: FirstMove::default(),
+                swap_seats: true,
+            },
+            ..Default::default()
+        },
+    );
+    let session = sessions.get_session("ordered").expect("Session exists");
+    assert!(session.is_players_turn("alice"));
+
+    sessions.restart_game("ordered").expect("Restarts");
+    let session = sessions.get_session("ordered").expect("Session exists");
+    assert_eq!(session.get_player("alice").map(|p| p.mark), Some(Player::O));
+    assert_eq!(session.get_player("bob").map(|p| p.mark), Some(Player::X));
+    assert_eq!(session.player_x.as_ref().map(|p| p.id.as_str()), Some("bob"));
+    assert!(session.is_players_turn("bob"));
+}
+
+#[tokio::test]
+async fn test_bot_opens_when_its_mark_moves_first() {
+    let sessions: SessionManager<TicTacToe> = SessionManager::new();
+    sessions
+        .create_ordered_session(
+            "bot".to_string(),
+            TimeControl::Unlimited,
+            TurnOrder {
+                first_move: FirstMove::Fixed { mark: Player::O },
+                swap_seats: false,
+            },
+        )
+        .expect("Session created");
+    sessions
+        .register_player_atomic("bot", "alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    sessions
+        .register_bot_atomic("bot", "bot".to_string(), "Bot".to_string(), Difficulty::Perfect)
+        .expect("Bot joins");
+    sessions.play_bot_turns("bot").await.expect("Bot opens");
+
+    let session = sessions.get_session("bot").expect("Session exists");
+    assert_eq!(session.game.move_count(), 1);
+    assert!(session.is_players_turn("alice"));
+}
+
+#[test]
+fn test_turn_order_wire_format() {
+    let turn_order: TurnOrder =
+        serde_json::from_str(r#"{"first_move":{"kind":"random","seed":7},"swap_seats":true}"#)
+            .expect("Valid turn order");
+    assert_eq!(
+        turn_order,
+        TurnOrder {
+            first_move: FirstMove::Random { seed: 7 },
+            swap_seats: true,
+        }
+    );
+
+    let defaulted: TurnOrder = serde_json::from_str("{}").expect("Fields are optional");
+    assert_eq!(defaulted, TurnOrder::default());
+    assert_eq!(defaulted.first_move, FirstMove::Fixed { mark: Player::X });
+}
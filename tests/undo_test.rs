@@ -0,0 +1,128 @@
+//! Tests for taking back moves.
+
+mod common;
+
+use common::two_humans;
+use strictly_games::connect_four::Column;
+use strictly_games::{
+    ConnectFour, Difficulty, GameInProgress, GameResult, GameSession, GameSetup, GameState, Move,
+    MoveError, Position, PlayerType, Square, TicTacToe, TicTacToePlayer as Player,
+};
+
+#[test]
+fn test_undo_in_progress_restores_board_and_turn() {
+    let game = GameSetup::new().start(Player::X);
+    let result = game.make_move(Move::new(Player::X, Position::Center)).expect("Valid move");
+    let GameResult::InProgress(game) = result else {
+        panic!("Game shouldn't finish yet");
+    };
+
+    let game = game.undo().expect("One move to undo");
+    assert_eq!(game.board().get(Position::Center), Square::Empty);
+    assert_eq!(game.to_move(), Player::X);
+    assert!(game.history().is_empty());
+
+    assert!(matches!(game.undo(), Err(MoveError::NothingToUndo)));
+}
+
+#[test]
+fn test_undo_finished_reopens_game() {
+    // X wins the top row
+    let moves = [
+        Move::new(Player::X, Position::TopLeft),
+        Move::new(Player::O, Position::MiddleLeft),
+        Move::new(Player::X, Position::TopCenter),
+        Move::new(Player::O, Position::Center),
+        Move::new(Player::X, Position::TopRight),
+    ];
+    let GameResult::Finished(game) = GameInProgress::replay(&moves).expect("Valid moves") else {
+        panic!("Game should be finished");
+    };
+
+    let game = game.undo().expect("Moves to undo");
+    assert_eq!(game.to_move(), Player::X);
+    assert_eq!(game.history(), &moves[..4]);
+    assert!(game.valid_moves().contains(&Position::TopRight));
+}
+
+#[test]
+fn test_accepted_undo_takes_back_requesters_move() {
+    let mut session: GameSession<TicTacToe> = two_humans("undo");
+    session.make_move("alice", Position::Center).expect("X moves");
+    session.make_move("bob", Position::TopLeft).expect("O moves");
+
+    // Alice's last move is behind Bob's, so both come off
+    session.request_undo("alice").expect("Alice asks");
+    assert_eq!(session.undo_request, Some(Player::X));
+    assert!(session.respond_undo("alice", true).is_err());
+    session.respond_undo("bob", true).expect("Bob accepts");
+
+    assert_eq!(session.undo_request, None);
+    assert_eq!(session.game.move_count(), 0);
+    assert!(session.is_players_turn("alice"));
+}
+
+#[test]
+fn test_declined_undo_keeps_the_game() {
+    let mut session: GameSession<TicTacToe> = two_humans("undo");
+    session.make_move("alice", Position::Center).expect("X moves");
+
+    session.request_undo("alice").expect("Alice asks");
+    session.respond_undo("bob", false).expect("Bob declines");
+
+    assert_eq!(session.undo_request, None);
+    assert_eq!(session.game.move_count(), 1);
+    assert!(session.is_players_turn("bob"));
+}
+
+#[test]
+fn test_undo_needs_a_move_of_ones_own() {
+    let mut session: GameSession<TicTacToe> = two_humans("undo");
+    session.make_move("alice", Position::Center).expect("X moves");
+
+    assert!(session.request_undo("bob").is_err());
+    assert!(session.respond_undo("alice", true).is_err());
+}
+
+#[test]
+fn test_moving_declines_pending_undo() {
+    let mut session: GameSession<TicTacToe> = two_humans("undo");
+    session.make_move("alice", Position::Center).expect("X moves");
+
+    session.request_undo("alice").expect("Alice asks");
+    session.make_move("bob", Position::TopLeft).expect("O plays on");
+
+    assert_eq!(session.undo_request, None);
+    assert!(session.respond_undo("bob", true).is_err());
+}
+
+#[test]
+fn test_bot_accepts_undo_immediately() {
+    let mut session: GameSession<TicTacToe> = GameSession::new("bot_undo".to_string());
+    session
+        .register_player("alice".to_string(), "Alice".to_string(), PlayerType::Human)
+        .expect("Alice joins");
+    session
+        .register_bot("bot".to_string(), "Bot".to_string(), Difficulty::Perfect)
+        .expect("Bot joins");
+    session.make_move("alice", Position::TopLeft).expect("X moves");
+    session.play_bot_turns().expect("Bot replies");
+    assert_eq!(session.game.move_count(), 2);
+
+    session.request_undo("alice").expect("Bot accepts");
+    assert_eq!(session.undo_request, None);
+    assert_eq!(session.game.move_count(), 0);
+    assert!(session.is_players_turn("alice"));
+}
+
+#[test]
+fn test_undo_works_for_connect_four() {
+    let mut session: GameSession<ConnectFour> = two_humans("undo");
+    session.make_move("alice", Column::Four).expect("X drops");
+
+    session.request_undo("alice").expect("Alice asks");
+    session.respond_undo("bob", true).expect("Bob accepts");
+
+    assert_eq!(session.game.move_count(), 0);
+    assert_eq!(session.game.legal_choices().len(), 7);
+}
@@ -0,0 +1,149 @@
+//! Tests for the versioned `AnyGame` wire format and legacy upgrades.
+
+use serde_json::json;
+use strictly_games::{
+    AnyGame, GameSetup, Move, Outcome, Position, SNAPSHOT_VERSION, TicTacToePlayer as Player,
+};
+
+/// Board JSON with X on the top row and O on the middle-left and center.
+fn won_board() -> serde_json::Value {
+    json!({
+        "squares": [
+            {"Occupied": "X"}, {"Occupied": "X"}, {"Occupied": "X"},
+            {"Occupied": "O"}, {"Occupied": "O"}, "Empty",
+            "Empty", "Empty", "Empty",
+        ]
+    })
+}
+
+#[test]
+fn test_serializes_with_version_and_phase() {
+    let game: AnyGame = GameSetup::new().start(Player::X).into();
+    let game = game
+        .make_move_action(Move::new(Player::X, Position::Center))
+        .expect("Valid move");
+
+    let value = serde_json::to_value(&game).expect("Serializes");
+    assert_eq!(value["version"], json!(SNAPSHOT_VERSION));
+    assert_eq!(value["phase"], json!("in-progress"));
+    assert_eq!(value["to_move"], json!("O"));
+    assert_eq!(value["history"], json!([{"player": "X", "position": "Center"}]));
+    assert!(value.get("draw_offer").is_none());
+
+    let back: AnyGame = serde_json::from_value(value).expect("Round trips");
+    assert_eq!(back.move_history(), game.move_history());
+    assert_eq!(back.board(), game.board());
+}
+
+#[test]
+fn test_legacy_won_upgrades_to_finished() {
+    let legacy = json!({
+        "Won": {
+            "board": won_board(),
+            "winner": "X",
+            "history": ["TopLeft", "MiddleLeft", "TopCenter", "Center", "TopRight"],
+        }
+    });
+
+    let game: AnyGame = serde_json::from_value(legacy).expect("Legacy snapshot reads");
+    assert!(game.is_over());
+    assert_eq!(game.winner(), Some(Player::X));
+    assert_eq!(
+        game.move_history()[..2],
+        [Move::new(Player::X, Position::TopLeft), Move::new(Player::O, Position::MiddleLeft)]
+    );
+
+    let value = serde_json::to_value(&game).expect("Serializes");
+    assert_eq!(value["version"], json!(SNAPSHOT_VERSION));
+    assert_eq!(value["phase"], json!("finished"));
+    assert_eq!(value["outcome"], json!({"Winner": "X"}));
+}
+
+#[test]
+fn test_legacy_draw_upgrades_to_finished() {
+    let legacy = json!({
+        "Draw": {
+            "board": {"squares": vec!["Empty"; 9]},
+            "history": [],
+        }
+    });
+
+    let game: AnyGame = serde_json::from_value(legacy).expect("Legacy snapshot reads");
+    assert!(game.is_over());
+    assert_eq!(game.winner(), None);
+    assert!(game.status_string().contains("Draw"));
+}
+
+#[test]
+fn test_legacy_positions_get_players_back() {
+    let legacy = json!({
+        "InProgress": {
+            "board": {"squares": [
+                "Empty", "Empty", "Empty",
+                "Empty", {"Occupied": "O"}, "Empty",
+                "Empty", "Empty", "Empty",
+            ]},
+            "to_move": "X",
+            "history": ["Center"],
+        }
+    });
+
+    let game: AnyGame = serde_json::from_value(legacy).expect("Legacy snapshot reads");
+    assert_eq!(game.move_history(), vec![Move::new(Player::O, Position::Center)]);
+    assert!(game.in_progress().is_some());
+}
+
+#[test]
+fn test_legacy_moves_are_kept() {
+    let legacy = json!({
+        "InProgress": {
+            "board": {"squares": [
+                "Empty", "Empty", "Empty",
+                "Empty", {"Occupied": "X"}, "Empty",
+                "Empty", "Empty", "Empty",
+            ]},
+            "to_move": "O",
+            "history": [{"player": "X", "position": "Center"}],
+            "draw_offer": "X",
+        }
+    });
+
+    let game: AnyGame = serde_json::from_value(legacy).expect("Legacy snapshot reads");
+    assert_eq!(game.draw_offer(), Some(Player::X));
+    assert_eq!(game.move_history(), vec![Move::new(Player::X, Position::Center)]);
+}
+
+#[test]
+fn test_unknown_version_is_rejected() {
+    let future = json!({
+        "version": SNAPSHOT_VERSION + 1,
+        "phase": "setup",
+        "board": {"squares": vec!["Empty"; 9]},
+    });
+    assert!(serde_json::from_value::<AnyGame>(future).is_err());
+}
+
+#[test]
+fn test_schema_describes_versioned_format() {
+    let schema = serde_json::to_value(schemars::schema_for!(AnyGame)).expect("Serializes");
+    let text = schema.to_string();
+    assert!(text.contains("\"version\""));
+    assert!(text.contains("in-progress"));
+    assert!(text.contains("finished"));
+    assert!(!text.contains("\"Won\""));
+}
+
+#[test]
+fn test_finished_outcome_survives_round_trip() {
+    let game: AnyGame = GameSetup::new().start(Player::X).into();
+    let game = game
+        .act(strictly_games::Action::Resign(Player::O))
+        .expect("O resigns");
+
+    let json = serde_json::to_string(&game).expect("Serializes");
+    let back: AnyGame = serde_json::from_str(&json).expect("Deserializes");
+    assert!(matches!(
+        back,
+        AnyGame::Finished { outcome: Outcome::Resigned(Player::O), .. }
+    ));
+}